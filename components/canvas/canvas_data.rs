@@ -169,29 +169,30 @@ impl<'a> CanvasData<'a> {
             return; // Paint nothing if gradient size is zero.
         }
 
+        let stroke_opts = self.state.stroke_options();
         if self.need_to_draw_shadow() {
             self.draw_with_shadow(&rect, |new_draw_target: &DrawTarget| {
                 new_draw_target.stroke_rect(rect, self.state.stroke_style.to_pattern_ref(),
-                                            &self.state.stroke_opts, &self.state.draw_options);
+                                            &stroke_opts, &self.state.draw_options);
             });
         } else if rect.size.width == 0. || rect.size.height == 0. {
-            let cap = match self.state.stroke_opts.line_join {
+            let cap = match stroke_opts.line_join {
                 JoinStyle::Round => CapStyle::Round,
                 _ => CapStyle::Butt
             };
 
             let stroke_opts =
-                StrokeOptions::new(self.state.stroke_opts.line_width,
-                                   self.state.stroke_opts.line_join,
+                StrokeOptions::new(stroke_opts.line_width,
+                                   stroke_opts.line_join,
                                    cap,
-                                   self.state.stroke_opts.miter_limit,
-                                   self.state.stroke_opts.mDashPattern);
+                                   stroke_opts.miter_limit,
+                                   stroke_opts.mDashPattern);
             self.drawtarget.stroke_line(rect.origin, rect.bottom_right(),
                                         self.state.stroke_style.to_pattern_ref(),
                                         &stroke_opts, &self.state.draw_options);
         } else {
             self.drawtarget.stroke_rect(rect, self.state.stroke_style.to_pattern_ref(),
-                                        &self.state.stroke_opts, &self.state.draw_options);
+                                        &stroke_opts, &self.state.draw_options);
         }
     }
 
@@ -220,7 +221,7 @@ impl<'a> CanvasData<'a> {
 
         self.drawtarget.stroke(&self.path_builder.finish(),
                                self.state.stroke_style.to_pattern_ref(),
-                               &self.state.stroke_opts,
+                               &self.state.stroke_options(),
                                &self.state.draw_options);
     }
 
@@ -384,6 +385,10 @@ impl<'a> CanvasData<'a> {
         self.state.stroke_opts.miter_limit = limit;
     }
 
+    pub fn set_line_dash(&mut self, segments: Vec<f32>) {
+        self.state.line_dash = segments;
+    }
+
     pub fn set_transform(&mut self, transform: &Transform2D<f32>) {
         self.state.transform = transform.clone();
         self.drawtarget.set_transform(transform)
@@ -679,6 +684,8 @@ struct CanvasPaintState<'a> {
     fill_style: Pattern,
     stroke_style: Pattern,
     stroke_opts: StrokeOptions<'a>,
+    /// The current dash list, always of even length.
+    line_dash: Vec<f32>,
     /// The current 2D transform matrix.
     transform: Transform2D<f32>,
     shadow_offset_x: f64,
@@ -694,6 +701,7 @@ impl<'a> CanvasPaintState<'a> {
             fill_style: Pattern::Color(ColorPattern::new(Color::black())),
             stroke_style: Pattern::Color(ColorPattern::new(Color::black())),
             stroke_opts: StrokeOptions::new(1.0, JoinStyle::MiterOrBevel, CapStyle::Butt, 10.0, &[]),
+            line_dash: vec![],
             transform: Transform2D::identity(),
             shadow_offset_x: 0.0,
             shadow_offset_y: 0.0,
@@ -701,6 +709,15 @@ impl<'a> CanvasPaintState<'a> {
             shadow_color: Color::transparent(),
         }
     }
+
+    /// The stroke options to use when stroking, with the current dash list applied.
+    fn stroke_options(&self) -> StrokeOptions {
+        StrokeOptions::new(self.stroke_opts.line_width,
+                           self.stroke_opts.line_join,
+                           self.stroke_opts.line_cap,
+                           self.stroke_opts.miter_limit,
+                           &self.line_dash)
+    }
 }
 
 fn is_zero_size_gradient(pattern: &Pattern) -> bool {
@@ -235,6 +235,9 @@ impl<'a> CanvasPaintThread <'a> {
             Canvas2dMsg::SetMiterLimit(limit) => {
                 self.canvas(canvas_id).set_miter_limit(limit)
             },
+            Canvas2dMsg::SetLineDash(segments) => {
+                self.canvas(canvas_id).set_line_dash(segments)
+            },
             Canvas2dMsg::SetTransform(ref matrix) => {
                 self.canvas(canvas_id).set_transform(matrix)
             },
@@ -622,11 +622,15 @@ impl<Window: WindowMethods> IOCompositor<Window> {
         self.embedder_coordinates = self.window.get_coordinates();
 
         // A size change could also mean a resolution change.
-        if self.embedder_coordinates.hidpi_factor != old_coords.hidpi_factor {
+        let hidpi_changed = self.embedder_coordinates.hidpi_factor != old_coords.hidpi_factor;
+        if hidpi_changed {
             self.update_zoom_transform();
         }
 
-        if self.embedder_coordinates.viewport == old_coords.viewport &&
+        // Moving the window to a display with a different scale factor may leave the
+        // viewport untouched, but script still needs to observe the new devicePixelRatio.
+        if !hidpi_changed &&
+           self.embedder_coordinates.viewport == old_coords.viewport &&
            self.embedder_coordinates.framebuffer == old_coords.framebuffer {
             return;
         }
@@ -720,6 +720,15 @@ impl<Message, LTF, STF> Constellation<Message, LTF, STF>
             .map(|pipeline| pipeline.visible)
             .or(parent_visibility);
 
+        // https://html.spec.whatwg.org/multipage/#resetBCName
+        // A top-level browsing context forgets its name when it navigates to a document
+        // that is not same origin with the previous one. The previous document may live
+        // in a different script thread, so this has to be decided here.
+        let reset_browsing_context_name = parent_info.is_none() &&
+            self.browsing_contexts.get(&browsing_context_id)
+                .and_then(|browsing_context| self.pipelines.get(&browsing_context.pipeline_id))
+                .map_or(false, |pipeline| pipeline.url.origin() != load_data.url.origin());
+
         let result = Pipeline::spawn::<Message, LTF, STF>(InitialPipelineState {
             id: pipeline_id,
             browsing_context_id,
@@ -745,6 +754,7 @@ impl<Message, LTF, STF> Constellation<Message, LTF, STF>
             device_pixel_ratio: self.window_size.device_pixel_ratio,
             pipeline_namespace_id: self.next_pipeline_namespace_id(),
             prev_visibility,
+            reset_browsing_context_name,
             webrender_api_sender: self.webrender_api_sender.clone(),
             webrender_document: self.webrender_document,
             is_private,
@@ -1102,9 +1112,9 @@ impl<Message, LTF, STF> Constellation<Message, LTF, STF>
                     warn!("constellation got set final url message for dead pipeline");
                 }
             }
-            FromScriptMsg::PostMessage(browsing_context_id, origin, data) => {
+            FromScriptMsg::PostMessage(browsing_context_id, origin, source_origin, data) => {
                 debug!("constellation got postMessage message");
-                self.handle_post_message_msg(browsing_context_id, origin, data);
+                self.handle_post_message_msg(browsing_context_id, origin, source_origin, data);
             }
             FromScriptMsg::Focus => {
                 debug!("constellation got focus message");
@@ -1244,6 +1254,9 @@ impl<Message, LTF, STF> Constellation<Message, LTF, STF>
             FromScriptMsg::BroadcastStorageEvent(storage, url, key, old_value, new_value) => {
                 self.handle_broadcast_storage_event(source_pipeline_id, storage, url, key, old_value, new_value);
             }
+            FromScriptMsg::BroadcastLanguagesChange(languages) => {
+                self.handle_broadcast_languages_change(languages);
+            }
             FromScriptMsg::SetFullscreenState(state) => {
                 self.embedder_proxy.send(EmbedderMsg::SetFullscreenState(source_top_ctx_id, state));
             }
@@ -1306,6 +1319,15 @@ impl<Message, LTF, STF> Constellation<Message, LTF, STF>
         }
     }
 
+    fn handle_broadcast_languages_change(&self, languages: String) {
+        for pipeline in self.pipelines.values() {
+            let msg = ConstellationControlMsg::DispatchLanguagesChange(pipeline.id, languages.clone());
+            if let Err(err) = pipeline.event_loop.send(msg) {
+                warn!("Failed to broadcast languages change to pipeline {} ({:?}).", pipeline.id, err);
+            }
+        }
+    }
+
     fn handle_exit(&mut self) {
         // TODO: add a timer, which forces shutdown if threads aren't responsive.
         if self.shutting_down { return; }
@@ -2178,13 +2200,14 @@ impl<Message, LTF, STF> Constellation<Message, LTF, STF>
     fn handle_post_message_msg(&mut self,
                                browsing_context_id: BrowsingContextId,
                                origin: Option<ImmutableOrigin>,
+                               source_origin: ImmutableOrigin,
                                data: Vec<u8>)
     {
         let pipeline_id = match self.browsing_contexts.get(&browsing_context_id) {
             None => return warn!("postMessage to closed browsing_context {}.", browsing_context_id),
             Some(browsing_context) => browsing_context.pipeline_id,
         };
-        let msg = ConstellationControlMsg::PostMessage(pipeline_id, origin, data);
+        let msg = ConstellationControlMsg::PostMessage(pipeline_id, origin, source_origin, data);
         let result = match self.pipelines.get(&pipeline_id) {
             Some(pipeline) => pipeline.event_loop.send(msg),
             None => return warn!("postMessage to closed pipeline {}.", pipeline_id),
@@ -170,6 +170,9 @@ pub struct InitialPipelineState {
     /// Pipeline visibility to be inherited
     pub prev_visibility: Option<bool>,
 
+    /// Whether the browsing context name must be reset when the new document becomes active.
+    pub reset_browsing_context_name: bool,
+
     /// Webrender api.
     pub webrender_api_sender: webrender_api::RenderApiSender,
 
@@ -223,6 +226,7 @@ impl Pipeline {
                     pipeline_port: pipeline_port,
                     content_process_shutdown_chan: Some(layout_content_process_shutdown_chan.clone()),
                     layout_threads: PREFS.get("layout.threads").as_u64().expect("count") as usize,
+                    reset_browsing_context_name: state.reset_browsing_context_name,
                 };
 
                 if let Err(e) = script_chan.send(ConstellationControlMsg::AttachLayout(new_layout_info)) {
@@ -45,7 +45,6 @@ static MONGOLIAN: [char; 10] = ['᠐', '᠑', '᠒', '᠓', '᠔', '᠕', '᠖',
 static MYANMAR: [char; 10] = ['၀', '၁', '၂', '၃', '၄', '၅', '၆', '၇', '၈', '၉'];
 static ORIYA: [char; 10] = ['୦', '୧', '୨', '୩', '୪', '୫', '୬', '୭', '୮', '୯'];
 static PERSIAN: [char; 10] = ['۰', '۱', '۲', '۳', '۴', '۵', '۶', '۷', '۸', '۹'];
-// TODO(pcwalton): `lower-roman`, `upper-roman`
 static TELUGU: [char; 10] = ['౦', '౧', '౨', '౩', '౪', '౫', '౬', '౭', '౮', '౯'];
 static THAI: [char; 10] = ['๐', '๑', '๒', '๓', '๔', '๕', '๖', '๗', '๘', '๙'];
 static TIBETAN: [char; 10] = ['༠', '༡', '༢', '༣', '༤', '༥', '༦', '༧', '༨', '༩'];
@@ -94,6 +93,19 @@ static KATAKANA_IROHA: [char; 47] = [
     'ヒ', 'モ', 'セ', 'ス'
 ];
 
+// Additive styles per CSS-COUNTER-STYLES § 6.1:
+static LOWER_ROMAN: [(usize, &'static str); 13] = [
+    (1000, "m"), (900, "cm"), (500, "d"), (400, "cd"), (100, "c"), (90, "xc"), (50, "l"),
+    (40, "xl"), (10, "x"), (9, "ix"), (5, "v"), (4, "iv"), (1, "i")
+];
+static UPPER_ROMAN: [(usize, &'static str); 13] = [
+    (1000, "M"), (900, "CM"), (500, "D"), (400, "CD"), (100, "C"), (90, "XC"), (50, "L"),
+    (40, "XL"), (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I")
+];
+
+/// The name of the counter that numbers list items (CSS Lists 3 § 4.4).
+static LIST_ITEM_COUNTER_NAME: &'static str = "list-item";
+
 /// The generated content resolution traversal.
 pub struct ResolveGeneratedContent<'a> {
     /// The layout context.
@@ -259,13 +271,6 @@ impl<'a, 'b> ResolveGeneratedContentFragmentMutator<'a, 'b> {
             list_style_type = ListStyleType::None
         }
 
-        match list_style_type {
-            ListStyleType::Disc | ListStyleType::None | ListStyleType::Circle |
-            ListStyleType::Square | ListStyleType::DisclosureOpen |
-            ListStyleType::DisclosureClosed => {}
-            _ => self.traversal.list_item.increment(self.level, 1),
-        }
-
         // Truncate down counters.
         for (_, counter) in &mut self.traversal.counters {
             counter.truncate_to_level(self.level);
@@ -274,6 +279,11 @@ impl<'a, 'b> ResolveGeneratedContentFragmentMutator<'a, 'b> {
 
         for &(ref counter_name, value) in &*fragment.style().get_counters().counter_reset {
             let counter_name = &*counter_name.0;
+            if counter_name == LIST_ITEM_COUNTER_NAME {
+                self.traversal.list_item.reset(self.level, value);
+                continue
+            }
+
             if let Some(ref mut counter) = self.traversal.counters.get_mut(counter_name) {
                  counter.reset(self.level, value);
                  continue
@@ -284,8 +294,17 @@ impl<'a, 'b> ResolveGeneratedContentFragmentMutator<'a, 'b> {
             self.traversal.counters.insert(counter_name.to_owned(), counter);
         }
 
+        // The list item counter is implicitly incremented by list items, unless the
+        // `counter-increment` property mentions it explicitly (CSS Lists 3 § 4.4).
+        let mut list_item_incremented = false;
         for &(ref counter_name, value) in &*fragment.style().get_counters().counter_increment {
             let counter_name = &*counter_name.0;
+            if counter_name == LIST_ITEM_COUNTER_NAME {
+                self.traversal.list_item.increment(self.level, value);
+                list_item_incremented = true;
+                continue
+            }
+
             if let Some(ref mut counter) = self.traversal.counters.get_mut(counter_name) {
                 counter.increment(self.level, value);
                 continue
@@ -296,6 +315,15 @@ impl<'a, 'b> ResolveGeneratedContentFragmentMutator<'a, 'b> {
             self.traversal.counters.insert(counter_name.to_owned(), counter);
         }
 
+        if !list_item_incremented {
+            match list_style_type {
+                ListStyleType::Disc | ListStyleType::None | ListStyleType::Circle |
+                ListStyleType::Square | ListStyleType::DisclosureOpen |
+                ListStyleType::DisclosureClosed => {}
+                _ => self.traversal.list_item.increment(self.level, 1),
+            }
+        }
+
         self.incremented = true
     }
 
@@ -527,6 +555,8 @@ fn push_representation(value: i32, list_style_type: ListStyleType, accumulator:
         ListStyleType::KatakanaIroha => {
             push_alphabetic_representation(value, &KATAKANA_IROHA, accumulator)
         }
+        ListStyleType::LowerRoman => push_roman_representation(value, &LOWER_ROMAN, accumulator),
+        ListStyleType::UpperRoman => push_roman_representation(value, &UPPER_ROMAN, accumulator),
     }
 }
 
@@ -585,6 +615,23 @@ fn push_numeric_representation(value: i32, system: &[char], accumulator: &mut St
     accumulator.extend(string.iter().cloned().rev())
 }
 
+/// Pushes the string that represents the value rendered using the given roman *additive system*
+/// onto the accumulator per CSS-COUNTER-STYLES § 3.1.8. Values outside the 1 to 3999 range of the
+/// roman styles fall back to `decimal`.
+fn push_roman_representation(value: i32, system: &[(usize, &'static str)], accumulator: &mut String) {
+    if value < 1 || value > 3999 {
+        return push_numeric_representation(value, &DECIMAL, accumulator)
+    }
+
+    let mut value = value as usize;
+    for &(weight, symbol) in system {
+        while value >= weight {
+            accumulator.push_str(symbol);
+            value -= weight;
+        }
+    }
+}
+
 /// If the system uses a negative sign, handle negative values per CSS-COUNTER-STYLES § 2.
 ///
 /// Returns the absolute value of the counter.
@@ -10,8 +10,8 @@ use net_traits::filemanager_thread::{FileManagerResult, FileManagerThreadMsg, Fi
 use net_traits::filemanager_thread::{FileManagerThreadError, ReadFileProgress, RelativePos, SelectedFile};
 use servo_config::prefs::PREFS;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{Read, Seek, SeekFrom};
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom};
 use std::ops::Index;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
@@ -113,6 +113,13 @@ impl FileManager {
                     store.select_files(filter, sender, origin, opt_test_paths, embedder);
                 }).expect("Thread spawning failed");
             }
+            FileManagerThreadMsg::SelectDirectory(sender, origin, opt_test_path) => {
+                let store = self.store.clone();
+                let embedder = self.embedder_proxy.clone();
+                thread::Builder::new().name("select directory".to_owned()).spawn(move || {
+                    store.select_directory(sender, origin, opt_test_path, embedder);
+                }).expect("Thread spawning failed");
+            }
             FileManagerThreadMsg::ReadFile(sender, id, check_url_validity, origin) => {
                 self.read_file(sender, id, check_url_validity, origin);
             }
@@ -232,6 +239,20 @@ impl FileManagerStore {
         }
     }
 
+    fn query_directory_from_embedder(&self, embedder_proxy: EmbedderProxy) -> Option<String> {
+        let (ipc_sender, ipc_receiver) = ipc::channel().expect("Failed to create IPC channel!");
+        let msg = EmbedderMsg::SelectDirectory(ipc_sender);
+
+        embedder_proxy.send(msg);
+        match ipc_receiver.recv() {
+            Ok(result) => result,
+            Err(e) => {
+                warn!("Failed to receive directory from embedder ({}).", e);
+                None
+            }
+        }
+    }
+
     fn select_file(&self,
                     patterns: Vec<FilterPattern>,
                     sender: IpcSender<FileManagerResult<SelectedFile>>,
@@ -304,6 +325,54 @@ impl FileManagerStore {
         }
     }
 
+    fn select_directory(&self,
+                        sender: IpcSender<FileManagerResult<Vec<SelectedFile>>>,
+                        origin: FileOrigin,
+                        opt_test_path: Option<String>,
+                        embedder_proxy: EmbedderProxy) {
+        // Check if the select_files preference is enabled
+        // to ensure process-level security against compromised script;
+        // Then try applying opt_test_path directly for testing convenience
+        let opt_s = if select_files_pref_enabled() {
+            opt_test_path
+        } else {
+            self.query_directory_from_embedder(embedder_proxy)
+        };
+
+        let dir_path = match opt_s {
+            Some(s) => PathBuf::from(s),
+            None => {
+                let _ = sender.send(Err(FileManagerThreadError::UserCancelled));
+                return;
+            }
+        };
+
+        // Relative paths are reported starting with the selected directory's own name.
+        let base = dir_path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+        let mut file_paths = vec![];
+        if let Err(e) = collect_files(&dir_path, &mut file_paths) {
+            let _ = sender.send(Err(FileManagerThreadError::FileSystemError(e.to_string())));
+            return;
+        }
+
+        let mut replies = vec![];
+        for path in file_paths {
+            match self.create_entry(&path, &origin) {
+                Ok(mut selected) => {
+                    selected.relative_path = path.strip_prefix(&base).ok().map(Path::to_path_buf);
+                    replies.push(selected);
+                }
+                Err(e) => {
+                    let _ = sender.send(Err(e));
+                    return;
+                }
+            }
+        }
+
+        let _ = sender.send(Ok(replies));
+    }
+
     fn create_entry(&self, file_path: &Path, origin: &str) -> Result<SelectedFile, FileManagerThreadError> {
         use net_traits::filemanager_thread::FileManagerThreadError::FileSystemError;
 
@@ -344,6 +413,7 @@ impl FileManagerStore {
             modified: modified_epoch,
             size: file_size,
             type_string: type_string,
+            relative_path: None,
         })
     }
 
@@ -519,6 +589,28 @@ fn select_files_pref_enabled() -> bool {
          .as_boolean().unwrap_or(false)
 }
 
+/// Appends the paths of all the files under `dir`, recursively and in
+/// name order, to `files`. Symlinks are skipped, so that a link to an
+/// ancestor directory can't make this recurse forever.
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|entry| entry.and_then(|e| Ok((e.path(), e.file_type()?))))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for (path, file_type) in entries {
+        if file_type.is_symlink() {
+            continue;
+        }
+        if file_type.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
 const CHUNK_SIZE: usize = 8192;
 
 fn chunked_read(sender: &IpcSender<FileManagerResult<ReadFileProgress>>,
@@ -5,6 +5,7 @@
 use ipc_channel::ipc::{self, IpcReceiver, IpcSender};
 use net_traits::storage_thread::{StorageThreadMsg, StorageType};
 use resource_thread;
+use servo_config::prefs::PREFS;
 use servo_url::ServoUrl;
 use std::borrow::ToOwned;
 use std::collections::BTreeMap;
@@ -14,6 +15,16 @@ use std::thread;
 
 const QUOTA_SIZE_LIMIT: usize = 5 * 1024 * 1024;
 
+/// The per-origin storage budget, shared between local and session storage.
+fn quota_size_limit() -> usize {
+    PREFS.get("dom.storage.quota_size_limit").as_u64().map_or(QUOTA_SIZE_LIMIT, |limit| limit as usize)
+}
+
+/// The amount of the quota used by a key or value, in UTF-16 code units.
+fn storage_size(s: &str) -> usize {
+    s.encode_utf16().count()
+}
+
 pub trait StorageThreadFactory {
     fn new(config_dir: Option<PathBuf>) -> Self;
 }
@@ -40,10 +51,15 @@ impl StorageManager {
     fn new(port: IpcReceiver<StorageThreadMsg>,
            config_dir: Option<PathBuf>)
            -> StorageManager {
-        let mut local_data = HashMap::new();
+        let mut local_data: HashMap<String, (usize, BTreeMap<String, String>)> = HashMap::new();
         if let Some(ref config_dir) = config_dir {
             resource_thread::read_json_from_file(&mut local_data, config_dir, "local_data.json");
         }
+        // Recompute the usage of persisted data, in case it was recorded with
+        // a different size metric.
+        for &mut (ref mut total, ref entry) in local_data.values_mut() {
+            *total = entry.iter().map(|(name, value)| storage_size(name) + storage_size(value)).sum();
+        }
         StorageManager {
             port: port,
             session_data: HashMap::new(),
@@ -165,14 +181,14 @@ impl StorageManager {
         }
 
         let message = data.get_mut(&origin).map(|&mut (ref mut total, ref mut entry)| {
-            let mut new_total_size = this_storage_size + value.as_bytes().len();
+            let mut new_total_size = this_storage_size + storage_size(&value);
             if let Some(old_value) = entry.get(&name) {
-                new_total_size -= old_value.as_bytes().len();
+                new_total_size -= storage_size(old_value);
             } else {
-                new_total_size += name.as_bytes().len();
+                new_total_size += storage_size(&name);
             }
 
-            if (new_total_size + other_storage_size) > QUOTA_SIZE_LIMIT {
+            if (new_total_size + other_storage_size) > quota_size_limit() {
                 return Err(());
             }
 
@@ -211,7 +227,7 @@ impl StorageManager {
         let data = self.select_data_mut(storage_type);
         let old_value = data.get_mut(&origin).and_then(|&mut (ref mut total, ref mut entry)| {
             entry.remove(&name).and_then(|old| {
-                *total -= name.as_bytes().len() + old.as_bytes().len();
+                *total -= storage_size(&name) + storage_size(&old);
                 Some(old)
             })
         });
@@ -108,6 +108,10 @@ pub struct SelectedFile {
     pub size: u64,
     // https://w3c.github.io/FileAPI/#dfn-type
     pub type_string: String,
+    /// Path of the file relative to the selected directory's parent, when
+    /// the file was picked as part of a directory.
+    // https://wicg.github.io/entries-api/#dom-file-webkitrelativepath
+    pub relative_path: Option<PathBuf>,
 }
 
 /// Filter for file selection;
@@ -123,6 +127,9 @@ pub enum FileManagerThreadMsg {
     /// Select multiple files. Last field is pre-selected file paths for testing
     SelectFiles(Vec<FilterPattern>, IpcSender<FileManagerResult<Vec<SelectedFile>>>, FileOrigin, Option<Vec<String>>),
 
+    /// Select a directory and every file under it. Last field is pre-selected directory path for testing
+    SelectDirectory(IpcSender<FileManagerResult<Vec<SelectedFile>>>, FileOrigin, Option<String>),
+
     /// Read FileID-indexed file in chunks, optionally check URL validity based on boolean flag
     ReadFile(IpcSender<FileManagerResult<ReadFileProgress>>, Uuid, bool, FileOrigin),
 
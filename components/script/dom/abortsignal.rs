@@ -0,0 +1,202 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use dom::bindings::cell::DomRefCell;
+use dom::bindings::codegen::Bindings::AbortSignalBinding::{self, AbortSignalMethods};
+use dom::bindings::inheritance::Castable;
+use dom::bindings::refcounted::Trusted;
+use dom::bindings::reflector::{DomObject, reflect_dom_object};
+use dom::bindings::root::{Dom, DomRoot};
+use dom::domexception::{DOMErrorName, DOMException};
+use dom::eventtarget::{EventListenerAbortAlgorithm, EventTarget};
+use dom::globalscope::GlobalScope;
+use dom_struct::dom_struct;
+use fetch::FetchAbortAlgorithm;
+use js::conversions::ToJSValConvertible;
+use js::jsapi::{Heap, JSAutoCompartment, JSContext};
+use js::jsval::{JSVal, UndefinedValue};
+use js::rust::HandleValue;
+use script_traits::MsDuration;
+use std::cell::Cell;
+use std::mem;
+use timers::OneshotTimerCallback;
+
+/// <https://dom.spec.whatwg.org/#abortsignal-abort-algorithms>
+#[derive(JSTraceable, MallocSizeOf)]
+#[must_root]
+pub enum AbortAlgorithm {
+    /// Signals abort on a signal that follows this one.
+    /// <https://dom.spec.whatwg.org/#abortsignal-follow>
+    Follow(Dom<AbortSignal>),
+    /// Aborts an ongoing `fetch()`.
+    Fetch(FetchAbortAlgorithm),
+    /// Removes an event listener added with this signal.
+    RemoveEventListener(EventListenerAbortAlgorithm),
+}
+
+impl AbortAlgorithm {
+    fn run(&self, reason: HandleValue) {
+        match *self {
+            AbortAlgorithm::Follow(ref signal) => signal.signal_abort(reason),
+            AbortAlgorithm::Fetch(ref fetch) => fetch.run(reason),
+            AbortAlgorithm::RemoveEventListener(ref removal) => removal.run(),
+        }
+    }
+}
+
+/// Identifies an algorithm added to an `AbortSignal`, so that it can be
+/// removed again.
+#[derive(Clone, Copy, JSTraceable, MallocSizeOf, PartialEq)]
+pub struct AbortAlgorithmId(u64);
+
+#[dom_struct]
+pub struct AbortSignal {
+    eventtarget: EventTarget,
+    /// <https://dom.spec.whatwg.org/#abortsignal-abort-reason>
+    reason: Heap<JSVal>,
+    abort_algorithms: DomRefCell<Vec<(AbortAlgorithmId, AbortAlgorithm)>>,
+    next_algorithm_id: Cell<u64>,
+}
+
+impl AbortSignal {
+    fn new_inherited() -> AbortSignal {
+        AbortSignal {
+            eventtarget: EventTarget::new_inherited(),
+            reason: Heap::default(),
+            abort_algorithms: DomRefCell::new(vec![]),
+            next_algorithm_id: Cell::new(0),
+        }
+    }
+
+    pub fn new(global: &GlobalScope) -> DomRoot<AbortSignal> {
+        reflect_dom_object(Box::new(AbortSignal::new_inherited()),
+                           global,
+                           AbortSignalBinding::Wrap)
+    }
+
+    /// <https://dom.spec.whatwg.org/#abortsignal-aborted>
+    pub fn aborted(&self) -> bool {
+        !self.reason.get().is_undefined()
+    }
+
+    /// <https://dom.spec.whatwg.org/#abortsignal-add>
+    pub fn add_algorithm(&self, algorithm: AbortAlgorithm) -> AbortAlgorithmId {
+        let id = AbortAlgorithmId(self.next_algorithm_id.get());
+        self.next_algorithm_id.set(id.0 + 1);
+        if !self.aborted() {
+            self.abort_algorithms.borrow_mut().push((id, algorithm));
+        }
+        id
+    }
+
+    /// <https://dom.spec.whatwg.org/#abortsignal-remove>
+    pub fn remove_algorithm(&self, id: AbortAlgorithmId) {
+        self.abort_algorithms.borrow_mut().retain(|&(algorithm_id, _)| algorithm_id != id);
+    }
+
+    /// <https://dom.spec.whatwg.org/#abortsignal-signal-abort>
+    ///
+    /// An undefined `reason` stands for a new "AbortError" DOMException.
+    #[allow(unrooted_must_root, unsafe_code)]
+    pub fn signal_abort(&self, reason: HandleValue) {
+        // Step 1.
+        if self.aborted() {
+            return;
+        }
+
+        // Step 2.
+        let global = self.global();
+        let cx = global.get_cx();
+        let _ac = JSAutoCompartment::new(cx, global.reflector().get_jsobject().get());
+        if reason.is_undefined() {
+            rooted!(in(cx) let mut error = UndefinedValue());
+            unsafe {
+                DOMException::new(&global, DOMErrorName::AbortError).to_jsval(cx, error.handle_mut());
+            }
+            self.reason.set(error.get());
+        } else {
+            self.reason.set(reason.get());
+        }
+
+        // Steps 3-4.
+        rooted!(in(cx) let reason = self.reason.get());
+        let algorithms = mem::replace(&mut *self.abort_algorithms.borrow_mut(), vec![]);
+        for (_, algorithm) in algorithms {
+            algorithm.run(reason.handle());
+        }
+
+        // Step 5.
+        self.upcast::<EventTarget>().fire_event(atom!("abort"));
+    }
+
+    /// <https://dom.spec.whatwg.org/#abortsignal-follow>
+    pub fn follow(&self, parent: &AbortSignal) {
+        // Step 1.
+        if self.aborted() {
+            return;
+        }
+        // Step 2.
+        if parent.aborted() {
+            let cx = self.global().get_cx();
+            rooted!(in(cx) let reason = parent.reason.get());
+            self.signal_abort(reason.handle());
+        // Step 3.
+        } else {
+            parent.add_algorithm(AbortAlgorithm::Follow(Dom::from_ref(self)));
+        }
+    }
+
+    // https://dom.spec.whatwg.org/#dom-abortsignal-timeout
+    pub fn Timeout(global: &GlobalScope, milliseconds: u64) -> DomRoot<AbortSignal> {
+        // Step 1.
+        let signal = AbortSignal::new(global);
+        // Steps 2-3.
+        let callback = AbortSignalTimeoutCallback {
+            signal: Trusted::new(&*signal),
+        };
+        global.schedule_callback(OneshotTimerCallback::AbortSignalTimeout(callback),
+                                 MsDuration::new(milliseconds));
+        // Step 4.
+        signal
+    }
+}
+
+impl AbortSignalMethods for AbortSignal {
+    // https://dom.spec.whatwg.org/#dom-abortsignal-aborted
+    fn Aborted(&self) -> bool {
+        self.aborted()
+    }
+
+    #[allow(unsafe_code)]
+    // https://dom.spec.whatwg.org/#dom-abortsignal-reason
+    unsafe fn Reason(&self, _cx: *mut JSContext) -> JSVal {
+        self.reason.get()
+    }
+
+    // https://dom.spec.whatwg.org/#dom-abortsignal-onabort
+    event_handler!(abort, GetOnabort, SetOnabort);
+}
+
+/// Signals abort with a "TimeoutError" DOMException once the delay passed to
+/// `AbortSignal.timeout()` has elapsed.
+#[derive(JSTraceable, MallocSizeOf)]
+pub struct AbortSignalTimeoutCallback {
+    #[ignore_malloc_size_of = "non-owning"]
+    signal: Trusted<AbortSignal>,
+}
+
+impl AbortSignalTimeoutCallback {
+    #[allow(unsafe_code)]
+    pub fn invoke(self) {
+        let signal = self.signal.root();
+        let global = signal.global();
+        let cx = global.get_cx();
+        let _ac = JSAutoCompartment::new(cx, global.reflector().get_jsobject().get());
+        rooted!(in(cx) let mut error = UndefinedValue());
+        unsafe {
+            DOMException::new(&global, DOMErrorName::TimeoutError).to_jsval(cx, error.handle_mut());
+        }
+        signal.signal_abort(error.handle());
+    }
+}
@@ -14,7 +14,9 @@ pub enum WorkerScriptMsg {
     /// Common variants associated with the script messages
     Common(CommonScriptMsg),
     /// Message sent through Worker.postMessage
-    DOMMessage(StructuredCloneData)
+    DOMMessage(StructuredCloneData),
+    /// Sent when the user's preferred languages have changed
+    LanguagesChange,
 }
 
 pub struct SimpleWorkerErrorHandler<T: DomObject> {
@@ -80,8 +80,8 @@ pub fn synthetic_click_activation(element: &Element,
     let target = element.upcast::<EventTarget>();
     let mouse = MouseEvent::new(&win,
                                 DOMString::from("click"),
-                                EventBubbles::DoesNotBubble,
-                                EventCancelable::NotCancelable,
+                                EventBubbles::Bubbles,
+                                EventCancelable::Cancelable,
                                 Some(&win),
                                 1,
                                 0,
@@ -1141,6 +1141,40 @@ def getJSToNativeConversionInfo(type, descriptorProvider, failureCode=None,
         return handleOptional(templateBody, declType,
                               handleDefaultNull(default))
 
+    if type.isDate():
+        assert not isEnforceRange and not isClamp
+        assert isMember not in ("Dictionary", "Union")
+
+        notADate = CGWrapper(
+            CGGeneric(
+                failureCode or
+                ('throw_type_error(cx, "%s is not a Date.");\n'
+                 '%s' % (firstCap(sourceDescription), exceptionCode))),
+            post="\n")
+        templateBody = (
+            "{\n"
+            "    rooted!(in(cx) let date = ${val}.get().to_object());\n"
+            "    let mut is_date = false;\n"
+            "    if !JS_ObjectIsDate(cx, date.handle(), &mut is_date) {\n"
+            "%s"
+            "    }\n"
+            "    if !is_date {\n"
+            "%s"
+            "    }\n"
+            "    date.get()\n"
+            "}" % (CGIndenter(CGGeneric(exceptionCode), 8).define(),
+                   CGIndenter(notADate, 8).define()))
+        default = "ptr::null_mut()"
+        # TODO: Need to root somehow
+        # https://github.com/servo/servo/issues/6382
+        declType = CGGeneric("*mut JSObject")
+
+        templateBody = wrapObjectTemplate(templateBody, default,
+                                          isDefinitelyObject, type, failureCode)
+
+        return handleOptional(templateBody, declType,
+                              handleDefaultNull(default))
+
     if type.isDictionary():
         # There are no nullable dictionaries
         assert not type.nullable()
@@ -1382,7 +1416,7 @@ def typeNeedsCx(type, retVal=False):
         return any(typeNeedsCx(t) for t in type.unroll().flatMemberTypes)
     if retVal and type.isSpiderMonkeyInterface():
         return True
-    return type.isAny() or type.isObject()
+    return type.isAny() or type.isObject() or type.isDate()
 
 
 # Returns a conversion behavior suitable for a type
@@ -1470,7 +1504,7 @@ def getRetvalDeclarationForType(returnType, descriptorProvider):
     # https://github.com/servo/servo/issues/6307
     if returnType.isAny():
         return CGGeneric("JSVal")
-    if returnType.isObject() or returnType.isSpiderMonkeyInterface():
+    if returnType.isObject() or returnType.isDate() or returnType.isSpiderMonkeyInterface():
         result = CGGeneric("NonNull<JSObject>")
         if returnType.nullable():
             result = CGWrapper(result, pre="Option<", post=">")
@@ -6475,7 +6509,7 @@ def type_needs_tracing(t):
         if t.isAny():
             return True
 
-        if t.isObject():
+        if t.isObject() or t.isDate():
             return True
 
         if t.isSequence():
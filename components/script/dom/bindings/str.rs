@@ -262,7 +262,7 @@ impl DOMString {
                 // Step 4.3.1 "."
                 State::MilliStop => next_state(c == '.', State::MilliHigh),
                 // Step 4.3.2 "SSS"
-                State::MilliHigh => next_state(c.is_digit(6), State::MilliMiddle),
+                State::MilliHigh => next_state(c.is_digit(10), State::MilliMiddle),
                 State::MilliMiddle => next_state(c.is_digit(10), State::MilliLow),
                 State::MilliLow => next_state(c.is_digit(10), State::Done),
 
@@ -458,7 +458,7 @@ impl Extend<char> for DOMString {
 }
 
 /// https://html.spec.whatwg.org/multipage/#parse-a-month-string
-fn parse_month_string(value: &str) -> Result<(u32, u32), ()> {
+pub fn parse_month_string(value: &str) -> Result<(u32, u32), ()> {
     // Step 1, 2, 3
     let (year_int, month_int) = parse_month_component(value)?;
 
@@ -471,7 +471,7 @@ fn parse_month_string(value: &str) -> Result<(u32, u32), ()> {
 }
 
 /// https://html.spec.whatwg.org/multipage/#parse-a-date-string
-fn parse_date_string(value: &str) -> Result<(u32, u32, u32), ()> {
+pub fn parse_date_string(value: &str) -> Result<(u32, u32, u32), ()> {
     // Step 1, 2, 3
     let (year_int, month_int, day_int) = parse_date_component(value)?;
 
@@ -485,7 +485,7 @@ fn parse_date_string(value: &str) -> Result<(u32, u32, u32), ()> {
 }
 
 /// https://html.spec.whatwg.org/multipage/#parse-a-week-string
-fn parse_week_string(value: &str) -> Result<(u32, u32), ()> {
+pub fn parse_week_string(value: &str) -> Result<(u32, u32), ()> {
     // Step 1, 2, 3
     let mut iterator = value.split('-');
     let year = iterator.next().ok_or(())?;
@@ -572,7 +572,7 @@ fn parse_date_component(value: &str) -> Result<(u32, u32, u32), ()> {
 }
 
 /// https://html.spec.whatwg.org/multipage/#parse-a-time-component
-fn parse_time_component(value: &str) -> Result<(u32, u32, f32), ()> {
+pub fn parse_time_component(value: &str) -> Result<(u32, u32, f32), ()> {
     // Step 1
     let mut iterator = value.split(':');
     let hour = iterator.next().ok_or(())?;
@@ -624,7 +624,7 @@ fn parse_time_component(value: &str) -> Result<(u32, u32, f32), ()> {
 }
 
 /// https://html.spec.whatwg.org/multipage/#parse-a-local-date-and-time-string
-fn parse_local_date_and_time_string(value: &str) ->  Result<((u32, u32, u32), (u32, u32, f32)), ()> {
+pub fn parse_local_date_and_time_string(value: &str) ->  Result<((u32, u32, u32), (u32, u32, f32)), ()> {
     // Step 1, 2, 4
     let mut iterator = if value.contains('T') {
         value.split('T')
@@ -649,7 +649,7 @@ fn parse_local_date_and_time_string(value: &str) ->  Result<((u32, u32, u32), (u
     Ok((date_tuple, time_tuple))
 }
 
-fn max_day_in_month(year_num: u32, month_num: u32) -> Result<u32, ()> {
+pub fn max_day_in_month(year_num: u32, month_num: u32) -> Result<u32, ()> {
     match month_num {
         1|3|5|7|8|10|12 => Ok(31),
         4|6|9|11 => Ok(30),
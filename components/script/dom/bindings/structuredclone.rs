@@ -11,6 +11,8 @@ use dom::bindings::reflector::DomObject;
 use dom::bindings::root::DomRoot;
 use dom::blob::{Blob, BlobImpl};
 use dom::globalscope::GlobalScope;
+use dom::offscreencanvas::{OffscreenCanvas, OffscreenCanvasTransferData};
+use js::conversions::ToJSValConvertible;
 use js::jsapi::{JSAutoCompartment, JSContext};
 use js::jsapi::{JSStructuredCloneCallbacks, JSStructuredCloneReader, JSStructuredCloneWriter};
 use js::jsapi::{JS_ClearPendingException, JSObject};
@@ -20,6 +22,7 @@ use js::jsapi::HandleObject as RawHandleObject;
 use js::jsapi::JS_STRUCTURED_CLONE_VERSION;
 use js::jsapi::MutableHandleObject as RawMutableHandleObject;
 use js::jsapi::TransferableOwnership;
+use js::jsval::UndefinedValue;
 use js::rust::{Handle, HandleValue, MutableHandleValue};
 use js::rust::wrappers::{JS_WriteStructuredClone, JS_ReadStructuredClone};
 use libc::size_t;
@@ -36,6 +39,7 @@ enum StructuredCloneTags {
     /// To support additional types, add new tags with values incremented from the last one before Max.
     Min = 0xFFFF8000,
     DomBlob = 0xFFFF8001,
+    OffscreenCanvas = 0xFFFF8002,
     Max = 0xFFFFFFFF,
 }
 
@@ -150,33 +154,54 @@ unsafe extern "C" fn write_callback(_cx: *mut JSContext,
     return false
 }
 
-unsafe extern "C" fn read_transfer_callback(_cx: *mut JSContext,
+unsafe extern "C" fn read_transfer_callback(cx: *mut JSContext,
                                             _r: *mut JSStructuredCloneReader,
-                                            _tag: u32,
-                                            _content: *mut raw::c_void,
+                                            tag: u32,
+                                            content: *mut raw::c_void,
                                             _extra_data: u64,
                                             _closure: *mut raw::c_void,
-                                            _return_object: RawMutableHandleObject)
+                                            return_object: RawMutableHandleObject)
                                             -> bool {
+    if tag == StructuredCloneTags::OffscreenCanvas as u32 {
+        let data = Box::from_raw(content as *mut OffscreenCanvasTransferData);
+        let target_global = GlobalScope::from_context(cx);
+        let canvas = OffscreenCanvas::from_transfer_data(&target_global, *data);
+        *return_object.ptr = canvas.reflector().get_jsobject().get();
+        return true
+    }
     false
 }
 
 unsafe extern "C" fn write_transfer_callback(_cx: *mut JSContext,
-                                             _obj: RawHandleObject,
+                                             obj: RawHandleObject,
                                              _closure: *mut raw::c_void,
-                                             _tag: *mut u32,
-                                             _ownership: *mut TransferableOwnership,
-                                             _content:  *mut *mut raw::c_void,
-                                             _extra_data: *mut u64)
+                                             tag: *mut u32,
+                                             ownership: *mut TransferableOwnership,
+                                             content:  *mut *mut raw::c_void,
+                                             extra_data: *mut u64)
                                              -> bool {
+    if let Ok(canvas) = root_from_handleobject::<OffscreenCanvas>(Handle::from_raw(obj)) {
+        if let Some(data) = canvas.transfer() {
+            *tag = StructuredCloneTags::OffscreenCanvas as u32;
+            *ownership = TransferableOwnership::SCTAG_TMO_CUSTOM;
+            *content = Box::into_raw(Box::new(data)) as *mut raw::c_void;
+            *extra_data = 0;
+            return true
+        }
+    }
     false
 }
 
-unsafe extern "C" fn free_transfer_callback(_tag: u32,
-                                            _ownership: TransferableOwnership,
-                                            _content: *mut raw::c_void,
+unsafe extern "C" fn free_transfer_callback(tag: u32,
+                                            ownership: TransferableOwnership,
+                                            content: *mut raw::c_void,
                                             _extra_data: u64,
                                             _closure: *mut raw::c_void) {
+    // The transferred object was never read, so drop the state it carried.
+    if tag == StructuredCloneTags::OffscreenCanvas as u32 &&
+       ownership == TransferableOwnership::SCTAG_TMO_CUSTOM {
+        drop(Box::from_raw(content as *mut OffscreenCanvasTransferData));
+    }
 }
 
 unsafe extern "C" fn report_error_callback(_cx: *mut JSContext, _errorid: u32) {
@@ -202,6 +227,20 @@ pub enum StructuredCloneData {
 impl StructuredCloneData {
     /// Writes a structured clone. Returns a `DataClone` error if that fails.
     pub fn write(cx: *mut JSContext, message: HandleValue) -> Fallible<StructuredCloneData> {
+        StructuredCloneData::write_with_transfer(cx, message, &[])
+    }
+
+    /// Writes a structured clone, transferring the objects in `transfer`
+    /// rather than copying them. Returns a `DataClone` error if that fails.
+    pub fn write_with_transfer(cx: *mut JSContext,
+                               message: HandleValue,
+                               transfer: &[*mut JSObject])
+                               -> Fallible<StructuredCloneData> {
+        rooted!(in(cx) let mut transfer_list = UndefinedValue());
+        if !transfer.is_empty() {
+            unsafe { transfer.to_jsval(cx, transfer_list.handle_mut()); }
+        }
+
         let mut data = ptr::null_mut();
         let mut nbytes = 0;
         let result = unsafe {
@@ -211,7 +250,7 @@ impl StructuredCloneData {
                                     &mut nbytes,
                                     &STRUCTURED_CLONE_CALLBACKS,
                                     ptr::null_mut(),
-                                    HandleValue::undefined())
+                                    transfer_list.handle())
         };
         if !result {
             unsafe {
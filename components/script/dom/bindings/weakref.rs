@@ -242,6 +242,12 @@ impl<T: WeakReferenceable> WeakRefVec<T> {
     }
 }
 
+unsafe impl<T: WeakReferenceable> JSTraceable for WeakRefVec<T> {
+    unsafe fn trace(&self, _: *mut JSTracer) {
+        // Do nothing.
+    }
+}
+
 impl<T: WeakReferenceable> Deref for WeakRefVec<T> {
     type Target = Vec<WeakRef<T>>;
 
@@ -11,14 +11,7 @@ use html5ever::{Prefix, LocalName, Namespace};
 /// Validate a qualified name. See https://dom.spec.whatwg.org/#validate for details.
 pub fn validate_qualified_name(qualified_name: &str) -> ErrorResult {
     match xml_name_type(qualified_name) {
-        XMLName::InvalidXMLName => {
-            // Step 1.
-            Err(Error::InvalidCharacter)
-        },
-        XMLName::Name => {
-            // Step 2.
-            Err(Error::Namespace)
-        },
+        XMLName::InvalidXMLName | XMLName::Name => Err(Error::InvalidCharacter),
         XMLName::QName => Ok(()),
     }
 }
@@ -153,6 +146,9 @@ pub fn xml_name_type(name: &str) -> XMLName {
             } else {
                 seen_colon = true;
             }
+        } else if last == ':' && !is_valid_start(c) {
+            // The local part of a QName must itself start with a name start character.
+            non_qname_colons = true;
         }
         last = c
     }
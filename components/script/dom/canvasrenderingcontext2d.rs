@@ -66,10 +66,13 @@ pub struct CanvasRenderingContext2D {
     #[ignore_malloc_size_of = "Defined in ipc-channel"]
     ipc_renderer: IpcSender<CanvasMsg>,
     /// For rendering contexts created by an HTML canvas element, this is Some,
-    /// for ones created by a paint worklet, this is None.
+    /// for ones created by a paint worklet or an OffscreenCanvas, this is None.
     canvas: Option<Dom<HTMLCanvasElement>>,
+    /// The image cache used to draw image elements. This is None for the
+    /// contexts of OffscreenCanvas objects living in workers, which have no
+    /// image elements to draw.
     #[ignore_malloc_size_of = "Arc"]
-    image_cache: Arc<ImageCache>,
+    image_cache: Option<Arc<ImageCache>>,
     /// Any missing image URLs.
     missing_image_urls: DomRefCell<Vec<ServoUrl>>,
     /// The base URL for resolving CSS image URL values.
@@ -93,6 +96,8 @@ struct CanvasContextState {
     line_cap: LineCapStyle,
     line_join: LineJoinStyle,
     miter_limit: f64,
+    line_dash: Vec<f64>,
+    line_dash_offset: f64,
     transform: Transform2D<f32>,
     shadow_offset_x: f64,
     shadow_offset_y: f64,
@@ -113,6 +118,8 @@ impl CanvasContextState {
             line_cap: LineCapStyle::Butt,
             line_join: LineJoinStyle::Miter,
             miter_limit: 10.0,
+            line_dash: vec![],
+            line_dash_offset: 0.0,
             transform: Transform2D::identity(),
             shadow_offset_x: 0.0,
             shadow_offset_y: 0.0,
@@ -125,7 +132,7 @@ impl CanvasContextState {
 impl CanvasRenderingContext2D {
     pub fn new_inherited(global: &GlobalScope,
                          canvas: Option<&HTMLCanvasElement>,
-                         image_cache: Arc<ImageCache>,
+                         image_cache: Option<Arc<ImageCache>>,
                          base_url: ServoUrl,
                          size: Size2D<i32>)
                          -> CanvasRenderingContext2D {
@@ -158,7 +165,7 @@ impl CanvasRenderingContext2D {
         let image_cache = window.image_cache();
         let base_url = window.get_url();
         let boxed = Box::new(CanvasRenderingContext2D::new_inherited(
-            global, Some(canvas), image_cache, base_url, size
+            global, Some(canvas), Some(image_cache), base_url, size
         ));
         reflect_dom_object(boxed, global, CanvasRenderingContext2DBinding::Wrap)
     }
@@ -487,7 +494,11 @@ impl CanvasRenderingContext2D {
 
     #[inline]
     fn request_image_from_cache(&self, url: ServoUrl) -> ImageResponse {
-        let response = self.image_cache
+        let image_cache = match self.image_cache {
+            Some(ref image_cache) => image_cache,
+            None => return ImageResponse::None,
+        };
+        let response = image_cache
             .find_image_or_metadata(url.clone(),
                                     UsePlaceholder::No,
                                     CanRequestImages::No);
@@ -569,6 +580,25 @@ impl CanvasRenderingContext2D {
         self.ipc_renderer.clone()
     }
 
+    /// Reads back the pixels of `rect` from a bitmap of size `canvas_size`,
+    /// as unpremultiplied RGBA.
+    pub fn get_rect_pixels(&self, rect: Rect<i32>, canvas_size: Size2D<i32>) -> Vec<u8> {
+        let (sender, receiver) = ipc::channel(self.global().time_profiler_chan().clone()).unwrap();
+        let canvas_size = Size2D::new(canvas_size.width as f64, canvas_size.height as f64);
+        self.send_canvas_2d_msg(Canvas2dMsg::GetImageData(rect, canvas_size, sender));
+        let mut data = receiver.recv().unwrap();
+
+        // Un-premultiply alpha
+        for chunk in data.chunks_mut(4) {
+            let alpha = chunk[3] as usize;
+            chunk[0] = UNPREMULTIPLY_TABLE[256 * alpha + chunk[0] as usize];
+            chunk[1] = UNPREMULTIPLY_TABLE[256 * alpha + chunk[1] as usize];
+            chunk[2] = UNPREMULTIPLY_TABLE[256 * alpha + chunk[2] as usize];
+        }
+
+        data.to_vec()
+    }
+
     pub fn origin_is_clean(&self) -> bool {
         self.origin_clean.get()
     }
@@ -1107,23 +1137,12 @@ impl CanvasRenderingContext2DMethods for CanvasRenderingContext2D {
         let sh = cmp::max(1, sh.to_u32().unwrap());
         let sw = cmp::max(1, sw.to_u32().unwrap());
 
-        let (sender, receiver) = ipc::channel(self.global().time_profiler_chan().clone()).unwrap();
         let dest_rect = Rect::new(Point2D::new(sx.to_i32().unwrap(), sy.to_i32().unwrap()),
                                   Size2D::new(sw as i32, sh as i32));
         let canvas_size = self.canvas.as_ref().map(|c| c.get_size()).unwrap_or(Size2D::zero());
-        let canvas_size = Size2D::new(canvas_size.width as f64, canvas_size.height as f64);
-        self.send_canvas_2d_msg(Canvas2dMsg::GetImageData(dest_rect, canvas_size, sender));
-        let mut data = receiver.recv().unwrap();
-
-        // Un-premultiply alpha
-        for chunk in data.chunks_mut(4) {
-            let alpha = chunk[3] as usize;
-            chunk[0] = UNPREMULTIPLY_TABLE[256 * alpha + chunk[0] as usize];
-            chunk[1] = UNPREMULTIPLY_TABLE[256 * alpha + chunk[1] as usize];
-            chunk[2] = UNPREMULTIPLY_TABLE[256 * alpha + chunk[2] as usize];
-        }
+        let data = self.get_rect_pixels(dest_rect, canvas_size);
 
-        ImageData::new(&self.global(), sw, sh, Some(data.to_vec()))
+        ImageData::new(&self.global(), sw, sh, Some(data))
     }
 
     // https://html.spec.whatwg.org/multipage/#dom-context-2d-putimagedata
@@ -1316,6 +1335,46 @@ impl CanvasRenderingContext2DMethods for CanvasRenderingContext2D {
         self.send_canvas_2d_msg(Canvas2dMsg::SetMiterLimit(limit as f32))
     }
 
+    // https://html.spec.whatwg.org/multipage/#dom-context-2d-setlinedash
+    fn SetLineDash(&self, mut segments: Vec<f64>) {
+        // Step 1.
+        if segments.iter().any(|segment| !segment.is_finite() || *segment < 0.0) {
+            return;
+        }
+
+        // Step 2.
+        if segments.len() % 2 == 1 {
+            let copy = segments.clone();
+            segments.extend(copy);
+        }
+
+        // Step 3.
+        let dash = segments.iter().map(|segment| *segment as f32).collect();
+        self.state.borrow_mut().line_dash = segments;
+        self.send_canvas_2d_msg(Canvas2dMsg::SetLineDash(dash))
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-context-2d-getlinedash
+    fn GetLineDash(&self) -> Vec<f64> {
+        self.state.borrow().line_dash.clone()
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-context-2d-linedashoffset
+    fn LineDashOffset(&self) -> f64 {
+        self.state.borrow().line_dash_offset
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-context-2d-linedashoffset
+    fn SetLineDashOffset(&self, offset: f64) {
+        if !offset.is_finite() {
+            return;
+        }
+
+        // TODO: Azure always strokes with a zero dash offset, so this is
+        // only reflected for now.
+        self.state.borrow_mut().line_dash_offset = offset;
+    }
+
     // https://html.spec.whatwg.org/multipage/#dom-context-2d-shadowoffsetx
     fn ShadowOffsetX(&self) -> f64 {
         self.state.borrow().shadow_offset_x
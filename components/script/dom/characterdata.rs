@@ -24,6 +24,7 @@ use dom::virtualmethods::vtable_for;
 use dom_struct::dom_struct;
 use servo_config::opts;
 use std::cell::Ref;
+use std::cmp;
 
 // https://dom.spec.whatwg.org/#characterdata
 #[dom_struct]
@@ -158,6 +159,8 @@ impl CharacterDataMethods for CharacterData {
 
     // https://dom.spec.whatwg.org/#dom-characterdata-replacedata
     fn ReplaceData(&self, offset: u32, count: u32, arg: DOMString) -> ErrorResult {
+        // Step 1.
+        let length = self.Length();
         let mut new_data;
         {
             let data = self.data.borrow();
@@ -179,7 +182,7 @@ impl CharacterDataMethods for CharacterData {
             let replacement_after;
             let suffix;
             match split_at_utf16_code_unit_offset(remaining, count) {
-                // Steps 3.
+                // Step 3.
                 Err(()) => {
                     replacement_after = "";
                     suffix = "";
@@ -208,6 +211,9 @@ impl CharacterDataMethods for CharacterData {
         }
         *self.data.borrow_mut() = DOMString::from(new_data);
         self.content_changed();
+        // Step 3. The range offsets below must not be computed from a count
+        // that reaches past the end of the data, e.g. deleteData(offset, 0xFFFFFFFF).
+        let count = cmp::min(count, length - offset);
         // Steps 8-11.
         let node = self.upcast::<Node>();
         node.ranges().replace_code_units(
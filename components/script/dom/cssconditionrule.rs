@@ -3,7 +3,9 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use dom::bindings::codegen::Bindings::CSSConditionRuleBinding::CSSConditionRuleMethods;
+use dom::bindings::codegen::Bindings::WindowBinding::WindowBinding::WindowMethods;
 use dom::bindings::inheritance::Castable;
+use dom::bindings::reflector::DomObject;
 use dom::bindings::str::DOMString;
 use dom::cssgroupingrule::CSSGroupingRule;
 use dom::cssmediarule::CSSMediaRule;
@@ -57,5 +59,8 @@ impl CSSConditionRuleMethods for CSSConditionRule {
         } else {
             unreachable!()
         }
+
+        // The new condition may change which of the nested rules apply.
+        self.global().as_window().Document().invalidate_stylesheets();
     }
 }
@@ -16,7 +16,7 @@ use dom::medialist::MediaList;
 use dom::window::Window;
 use dom_struct::dom_struct;
 use servo_arc::Arc;
-use style::media_queries::parse_media_query_list;
+use style::media_queries::{MediaList as StyleMediaList, MediaQuery};
 use style::parser::ParserContext;
 use style::shared_lock::{Locked, ToCssWithGuard};
 use style::stylesheets::{CssRuleType, MediaRule};
@@ -79,8 +79,19 @@ impl CSSMediaRule {
                                                    ParsingMode::DEFAULT,
                                                    quirks_mode);
 
-        let new_medialist = parse_media_query_list(&context, &mut input,
-                                                   window.css_error_reporter());
+        // Unlike setting `mediaText`, an invalid condition is ignored rather
+        // than replaced with "not all".
+        let media_queries = if input.is_exhausted() {
+            Ok(vec![])
+        } else {
+            input.parse_entirely(|input| {
+                input.parse_comma_separated(|input| MediaQuery::parse(&context, input))
+            })
+        };
+        let new_medialist = match media_queries {
+            Ok(media_queries) => StyleMediaList { media_queries: media_queries },
+            Err(_) => return,
+        };
         let mut guard = self.cssconditionrule.shared_lock().write();
 
         // Clone an Arc because we can’t borrow `guard` twice at the same time.
@@ -5,6 +5,7 @@
 use dom::bindings::cell::DomRefCell;
 use dom::bindings::codegen::Bindings::CSSRuleListBinding;
 use dom::bindings::codegen::Bindings::CSSRuleListBinding::CSSRuleListMethods;
+use dom::bindings::codegen::Bindings::WindowBinding::WindowBinding::WindowMethods;
 use dom::bindings::error::{Error, ErrorResult, Fallible};
 use dom::bindings::reflector::{DomObject, Reflector, reflect_dom_object};
 use dom::bindings::root::{Dom, DomRoot, MutNullableDom};
@@ -103,31 +104,36 @@ impl CSSRuleList {
         let parent_stylesheet = &*self.parent_stylesheet;
         let dom_rule = CSSRule::new_specific(&window, parent_stylesheet, new_rule);
         self.dom_rules.borrow_mut().insert(index, MutNullableDom::new(Some(&*dom_rule)));
+        window.Document().invalidate_stylesheets();
         Ok(idx)
     }
 
     // In case of a keyframe rule, index must be valid.
     pub fn remove_rule(&self, index: u32) -> ErrorResult {
         let index = index as usize;
-        let mut guard = self.parent_stylesheet.shared_lock().write();
-
-        match self.rules {
-            RulesSource::Rules(ref css_rules) => {
-                css_rules.write_with(&mut guard).remove_rule(index)?;
-                let mut dom_rules = self.dom_rules.borrow_mut();
-                dom_rules[index].get().map(|r| r.detach());
-                dom_rules.remove(index);
-                Ok(())
-            }
-            RulesSource::Keyframes(ref kf) => {
-                // https://drafts.csswg.org/css-animations/#dom-csskeyframesrule-deleterule
-                let mut dom_rules = self.dom_rules.borrow_mut();
-                dom_rules[index].get().map(|r| r.detach());
-                dom_rules.remove(index);
-                kf.write_with(&mut guard).keyframes.remove(index);
-                Ok(())
+
+        {
+            let mut guard = self.parent_stylesheet.shared_lock().write();
+
+            match self.rules {
+                RulesSource::Rules(ref css_rules) => {
+                    css_rules.write_with(&mut guard).remove_rule(index)?;
+                    let mut dom_rules = self.dom_rules.borrow_mut();
+                    dom_rules[index].get().map(|r| r.detach());
+                    dom_rules.remove(index);
+                }
+                RulesSource::Keyframes(ref kf) => {
+                    // https://drafts.csswg.org/css-animations/#dom-csskeyframesrule-deleterule
+                    let mut dom_rules = self.dom_rules.borrow_mut();
+                    dom_rules[index].get().map(|r| r.detach());
+                    dom_rules.remove(index);
+                    kf.write_with(&mut guard).keyframes.remove(index);
+                }
             }
         }
+
+        self.global().as_window().Document().invalidate_stylesheets();
+        Ok(())
     }
 
     // Remove parent stylesheets from all children
@@ -137,6 +143,27 @@ impl CSSRuleList {
         }
     }
 
+    /// Detaches all DOM rules and resizes the list to match its rules after
+    /// they were replaced wholesale, as by `CSSStyleSheet.replaceSync`.
+    ///
+    /// Should only be called for CssRules-backed rules.
+    pub fn reset_dom_rules(&self) {
+        let len = match self.rules {
+            RulesSource::Rules(ref rules) => {
+                let guard = self.parent_stylesheet.shared_lock().read();
+                rules.read_with(&guard).0.len()
+            }
+            RulesSource::Keyframes(..) => {
+                panic!("Called reset_dom_rules on non-CssRule-backed CSSRuleList");
+            }
+        };
+        let mut dom_rules = self.dom_rules.borrow_mut();
+        for rule in dom_rules.iter() {
+            rule.get().map(|r| r.detach());
+        }
+        *dom_rules = (0..len).map(|_| MutNullableDom::new(None)).collect();
+    }
+
     pub fn item(&self, idx: u32) -> Option<DomRoot<CSSRule>> {
         self.dom_rules.borrow().get(idx as usize).map(|rule| {
             rule.or_init(|| {
@@ -39,6 +39,10 @@ pub enum CSSStyleOwner {
     CSSRule(Dom<CSSRule>,
             #[ignore_malloc_size_of = "Arc"]
             Arc<Locked<PropertyDeclarationBlock>>),
+    /// A computed style declaration without an owner node, such as the one
+    /// returned by getComputedStyle for an unsupported pseudo-element. Its
+    /// declarations are always empty.
+    Null(Dom<Window>),
 }
 
 impl CSSStyleOwner {
@@ -110,6 +114,10 @@ impl CSSStyleOwner {
                 }
                 result
             }
+            CSSStyleOwner::Null(_) => {
+                let mut pdb = PropertyDeclarationBlock::new();
+                f(&mut pdb, &mut changed)
+            }
         }
     }
 
@@ -134,6 +142,10 @@ impl CSSStyleOwner {
                 let guard = rule.shared_lock().read();
                 f(pdb.read_with(&guard))
             }
+            CSSStyleOwner::Null(_) => {
+                let pdb = PropertyDeclarationBlock::new();
+                f(&pdb)
+            }
         }
     }
 
@@ -141,6 +153,7 @@ impl CSSStyleOwner {
         match *self {
             CSSStyleOwner::Element(ref el) => window_from_node(&**el),
             CSSStyleOwner::CSSRule(ref rule, _) => DomRoot::from_ref(rule.global().as_window()),
+            CSSStyleOwner::Null(ref window) => DomRoot::from_ref(&**window),
         }
     }
 
@@ -150,6 +163,7 @@ impl CSSStyleOwner {
             CSSStyleOwner::CSSRule(ref rule, _) => {
                 (*rule.parent_stylesheet().style_stylesheet().contents.url_data.read()).clone()
             }
+            CSSStyleOwner::Null(ref window) => window.Document().base_url(),
         }
     }
 }
@@ -222,6 +236,7 @@ impl CSSStyleDeclaration {
                 let addr = node.to_trusted_node_address();
                 window_from_node(node).resolved_style_query(addr, self.pseudo.clone(), property)
             }
+            CSSStyleOwner::Null(_) => DOMString::new(),
         }
     }
 
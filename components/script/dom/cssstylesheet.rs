@@ -2,8 +2,9 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use cssparser::{Parser as CssParser, ParserInput};
 use dom::bindings::codegen::Bindings::CSSStyleSheetBinding;
-use dom::bindings::codegen::Bindings::CSSStyleSheetBinding::CSSStyleSheetMethods;
+use dom::bindings::codegen::Bindings::CSSStyleSheetBinding::{CSSStyleSheetInit, CSSStyleSheetMethods};
 use dom::bindings::codegen::Bindings::WindowBinding::WindowBinding::WindowMethods;
 use dom::bindings::error::{Error, ErrorResult, Fallible};
 use dom::bindings::reflector::{reflect_dom_object, DomObject};
@@ -11,36 +12,47 @@ use dom::bindings::root::{Dom, DomRoot, MutNullableDom};
 use dom::bindings::str::DOMString;
 use dom::cssrulelist::{CSSRuleList, RulesSource};
 use dom::element::Element;
+use dom::promise::Promise;
 use dom::stylesheet::StyleSheet;
 use dom::window::Window;
 use dom_struct::dom_struct;
 use servo_arc::Arc;
+use servo_config::prefs::PREFS;
 use std::cell::Cell;
+use std::mem;
+use std::rc::Rc;
+use style::media_queries::parse_media_query_list;
+use style::parser::ParserContext as CssParserContext;
 use style::shared_lock::SharedRwLock;
+use style::stylesheets::{CssRule, CssRuleType, Origin, StylesheetContents};
 use style::stylesheets::Stylesheet as StyleStyleSheet;
+use style_traits::ParsingMode;
 
 #[dom_struct]
 pub struct CSSStyleSheet {
     stylesheet: StyleSheet,
-    owner: Dom<Element>,
+    owner: Option<Dom<Element>>,
     rulelist: MutNullableDom<CSSRuleList>,
     #[ignore_malloc_size_of = "Arc"]
     style_stylesheet: Arc<StyleStyleSheet>,
     origin_clean: Cell<bool>,
+    /// <https://wicg.github.io/construct-stylesheets/#constructed-flag>
+    constructed: bool,
 }
 
 impl CSSStyleSheet {
-    fn new_inherited(owner: &Element,
+    fn new_inherited(owner: Option<&Element>,
                      type_: DOMString,
                      href: Option<DOMString>,
                      title: Option<DOMString>,
                      stylesheet: Arc<StyleStyleSheet>) -> CSSStyleSheet {
         CSSStyleSheet {
             stylesheet: StyleSheet::new_inherited(type_, href, title),
-            owner: Dom::from_ref(owner),
+            owner: owner.map(Dom::from_ref),
             rulelist: MutNullableDom::new(None),
             style_stylesheet: stylesheet,
             origin_clean: Cell::new(true),
+            constructed: owner.is_none(),
         }
     }
 
@@ -51,11 +63,84 @@ impl CSSStyleSheet {
                href: Option<DOMString>,
                title: Option<DOMString>,
                stylesheet: Arc<StyleStyleSheet>) -> DomRoot<CSSStyleSheet> {
-        reflect_dom_object(Box::new(CSSStyleSheet::new_inherited(owner, type_, href, title, stylesheet)),
+        reflect_dom_object(Box::new(CSSStyleSheet::new_inherited(Some(owner), type_, href, title, stylesheet)),
                            window,
                            CSSStyleSheetBinding::Wrap)
     }
 
+    // https://wicg.github.io/construct-stylesheets/#dom-cssstylesheet-cssstylesheet
+    #[allow(unrooted_must_root)]
+    pub fn Constructor(window: &Window, options: &CSSStyleSheetInit) -> Fallible<DomRoot<CSSStyleSheet>> {
+        if !PREFS.get("dom.constructable_stylesheets.enabled").as_boolean().unwrap_or(false) {
+            return Err(Error::Type("Illegal constructor.".to_owned()));
+        }
+
+        let document = window.Document();
+        let url = window.get_url();
+        let quirks_mode = document.quirks_mode();
+        let shared_lock = document.style_shared_lock().clone();
+        let context = CssParserContext::new_for_cssom(&url,
+                                                      Some(CssRuleType::Media),
+                                                      ParsingMode::DEFAULT,
+                                                      quirks_mode);
+        let mut input = ParserInput::new(&options.media);
+        let media = Arc::new(shared_lock.wrap(parse_media_query_list(&context,
+                                                                     &mut CssParser::new(&mut input),
+                                                                     window.css_error_reporter())));
+        let stylesheet = StyleStyleSheet::from_str("", url, Origin::Author, media, shared_lock,
+                                                   None, window.css_error_reporter(), quirks_mode, 0);
+        stylesheet.set_disabled(options.disabled);
+        Ok(reflect_dom_object(Box::new(CSSStyleSheet::new_inherited(None,
+                                                                    "text/css".into(),
+                                                                    None,
+                                                                    None,
+                                                                    Arc::new(stylesheet))),
+                              window,
+                              CSSStyleSheetBinding::Wrap))
+    }
+
+    /// Replaces the rules of a constructed stylesheet with the ones parsed
+    /// from `text`, shared by `replace` and `replaceSync`.
+    fn replace_rules(&self, text: &str) -> ErrorResult {
+        if !self.constructed {
+            return Err(Error::NotAllowed);
+        }
+
+        let window = self.global();
+        let window = window.as_window();
+        let url = self.style_stylesheet.contents.url_data.read().clone();
+        let contents = StylesheetContents::from_str(text,
+                                                    url,
+                                                    Origin::Author,
+                                                    self.shared_lock(),
+                                                    None,
+                                                    window.css_error_reporter(),
+                                                    self.style_stylesheet.contents.quirks_mode,
+                                                    0);
+
+        let mut guard = self.shared_lock().write();
+        let has_import_rule = contents.rules.read_with(&guard).0.iter().any(|rule| {
+            match *rule {
+                CssRule::Import(..) => true,
+                _ => false,
+            }
+        });
+        if has_import_rule {
+            return Err(Error::NotAllowed);
+        }
+
+        let rules = mem::replace(&mut contents.rules.write_with(&mut guard).0, vec![]);
+        self.style_stylesheet.contents.rules.write_with(&mut guard).0 = rules;
+        mem::swap(&mut *self.style_stylesheet.contents.namespaces.write(),
+                  &mut *contents.namespaces.write());
+        drop(guard);
+
+        if let Some(rulelist) = self.rulelist.get() {
+            rulelist.reset_dom_rules();
+        }
+        Ok(())
+    }
+
     fn rulelist(&self) -> DomRoot<CSSRuleList> {
         self.rulelist.or_init(|| {
             let rules = self.style_stylesheet.contents.rules.clone();
@@ -114,5 +199,21 @@ impl CSSStyleSheetMethods for CSSStyleSheet {
         }
         self.rulelist().remove_rule(index)
     }
+
+    // https://wicg.github.io/construct-stylesheets/#dom-cssstylesheet-replace
+    #[allow(unrooted_must_root)]
+    fn Replace(&self, text: DOMString) -> Rc<Promise> {
+        let promise = Promise::new(&self.global());
+        match self.replace_rules(&text) {
+            Ok(()) => promise.resolve_native(self),
+            Err(error) => promise.reject_error(error),
+        }
+        promise
+    }
+
+    // https://wicg.github.io/construct-stylesheets/#dom-cssstylesheet-replacesync
+    fn ReplaceSync(&self, text: DOMString) -> ErrorResult {
+        self.replace_rules(&text)
+    }
 }
 
@@ -142,9 +142,9 @@ impl DissimilarOriginWindowMethods for DissimilarOriginWindow {
         // Step 3-5.
         let origin = match &origin[..] {
             "*" => None,
-            "/" => {
-                // TODO: Should be the origin of the incumbent settings object.
-                None
+            "/" => match GlobalScope::incumbent() {
+                Some(incumbent) => Some(incumbent.origin().immutable().clone()),
+                None => return Err(Error::InvalidState),
             },
             url => match ServoUrl::parse(&url) {
                 Ok(url) => Some(url.origin()),
@@ -197,8 +197,9 @@ impl DissimilarOriginWindow {
             Some(incumbent) => incumbent,
         };
         let msg = ScriptMsg::PostMessage(self.window_proxy.browsing_context_id(),
-                                                origin,
-                                                data.move_to_arraybuffer());
+                                         origin,
+                                         incumbent.origin().immutable().clone(),
+                                         data.move_to_arraybuffer());
         let _ = incumbent.script_to_constellation_chan().send(msg);
     }
 }
@@ -12,7 +12,7 @@ use dom::bindings::callback::ExceptionHandling;
 use dom::bindings::cell::DomRefCell;
 use dom::bindings::codegen::Bindings::DocumentBinding;
 use dom::bindings::codegen::Bindings::DocumentBinding::{DocumentMethods, DocumentReadyState, ElementCreationOptions};
-use dom::bindings::codegen::Bindings::HTMLIFrameElementBinding::HTMLIFrameElementBinding::HTMLIFrameElementMethods;
+use dom::bindings::codegen::Bindings::DocumentBinding::VisibilityState;
 use dom::bindings::codegen::Bindings::NodeBinding::NodeMethods;
 use dom::bindings::codegen::Bindings::NodeFilterBinding::NodeFilter;
 use dom::bindings::codegen::Bindings::PerformanceBinding::PerformanceMethods;
@@ -54,7 +54,7 @@ use dom::htmlembedelement::HTMLEmbedElement;
 use dom::htmlformelement::{FormControl, FormControlElementHelpers, HTMLFormElement};
 use dom::htmlheadelement::HTMLHeadElement;
 use dom::htmlhtmlelement::HTMLHtmlElement;
-use dom::htmliframeelement::HTMLIFrameElement;
+use dom::htmliframeelement::BrowsingContextContainerRoot;
 use dom::htmlimageelement::HTMLImageElement;
 use dom::htmlmetaelement::HTMLMetaElement;
 use dom::htmlscriptelement::{HTMLScriptElement, ScriptResult};
@@ -88,7 +88,7 @@ use dom::window::{ReflowReason, Window};
 use dom::windowproxy::WindowProxy;
 use dom_struct::dom_struct;
 use encoding_rs::{Encoding, UTF_8};
-use euclid::Point2D;
+use euclid::{Point2D, Vector2D};
 use fetch::FetchCanceller;
 use html5ever::{LocalName, Namespace, QualName};
 use hyper::header::{Header, SetCookie};
@@ -272,6 +272,8 @@ pub struct Document {
     stylesheets: DomRefCell<DocumentStylesheetSet<StyleSheetInDocument>>,
     stylesheet_list: MutNullableDom<StyleSheetList>,
     ready_state: Cell<DocumentReadyState>,
+    /// <https://w3c.github.io/page-visibility/#dom-document-visibilitystate>
+    visibility_state: Cell<VisibilityState>,
     /// Whether the DOMContentLoaded event has already been dispatched.
     domcontentloaded_dispatched: Cell<bool>,
     /// The element that has most recently requested focus for itself.
@@ -346,6 +348,9 @@ pub struct Document {
     /// <https://w3c.github.io/uievents/#event-type-dblclick>
     #[ignore_malloc_size_of = "Defined in std"]
     last_click_info: DomRefCell<Option<(Instant, Point2D<f32>)>>,
+    /// The position of the last `mousemove` event, for `movementX` and `movementY`.
+    /// <https://w3c.github.io/pointerlock/#dom-mouseevent-movementx>
+    last_mousemove_point: Cell<Option<Point2D<f32>>>,
     /// <https://html.spec.whatwg.org/multipage/#ignore-destructive-writes-counter>
     ignore_destructive_writes_counter: Cell<u32>,
     /// The number of spurious `requestAnimationFrame()` requests we've received.
@@ -777,6 +782,24 @@ impl Document {
         self.upcast::<EventTarget>().fire_event(atom!("readystatechange"));
     }
 
+    /// Sets the visibility state of a newly created document, without
+    /// firing `visibilitychange`.
+    pub fn set_initial_visibility_state(&self, visible: bool) {
+        let state = if visible { VisibilityState::Visible } else { VisibilityState::Hidden };
+        self.visibility_state.set(state);
+    }
+
+    /// <https://w3c.github.io/page-visibility/#dfn-now-visible-algorithm>
+    /// <https://w3c.github.io/page-visibility/#dfn-now-hidden-algorithm>
+    pub fn update_visibility_state(&self, visible: bool) {
+        let state = if visible { VisibilityState::Visible } else { VisibilityState::Hidden };
+        if self.visibility_state.get() == state {
+            return;
+        }
+        self.visibility_state.set(state);
+        self.upcast::<EventTarget>().fire_bubbling_event(atom!("visibilitychange"));
+    }
+
     /// Return whether scripting is enabled or not
     pub fn is_scripting_enabled(&self) -> bool {
         self.scripting_enabled
@@ -807,11 +830,15 @@ impl Document {
         if self.focused == self.possibly_focused.get().r() {
             return
         }
-        if let Some(ref elem) = self.focused.get() {
+        let old_focused = self.focused.get();
+        let new_focused = self.possibly_focused.get();
+
+        if let Some(ref elem) = old_focused {
             let node = elem.upcast::<Node>();
+            let related_target = new_focused.r().map(|elem| elem.upcast::<EventTarget>());
             elem.set_focus_state(false);
-            // FIXME: pass appropriate relatedTarget
-            self.fire_focus_event(FocusEventType::Blur, node, None);
+            self.fire_focus_event(FocusEventType::Blur, node, related_target);
+            self.fire_focus_event(FocusEventType::Focusout, node, related_target);
 
             // Notify the embedder to hide the input method.
             if elem.input_method_type().is_some() {
@@ -819,13 +846,14 @@ impl Document {
             }
         }
 
-        self.focused.set(self.possibly_focused.get().r());
+        self.focused.set(new_focused.r());
 
-        if let Some(ref elem) = self.focused.get() {
+        if let Some(ref elem) = new_focused {
             elem.set_focus_state(true);
             let node = elem.upcast::<Node>();
-            // FIXME: pass appropriate relatedTarget
-            self.fire_focus_event(FocusEventType::Focus, node, None);
+            let related_target = old_focused.r().map(|elem| elem.upcast::<EventTarget>());
+            self.fire_focus_event(FocusEventType::Focus, node, related_target);
+            self.fire_focus_event(FocusEventType::Focusin, node, related_target);
             // Update the focus state for all elements in the focus chain.
             // https://html.spec.whatwg.org/multipage/#focus-chain
             if focus_type == FocusType::Element {
@@ -1031,6 +1059,13 @@ impl Document {
             None,
             None
         );
+        if let FireMouseEventType::Move = event_name {
+            let last_point = self.last_mousemove_point.get().unwrap_or(client_point);
+            let movement = client_point - last_point;
+            mouse_event.set_movement(Vector2D::new(movement.x.to_i32().unwrap_or(0),
+                                                   movement.y.to_i32().unwrap_or(0)));
+            self.last_mousemove_point.set(Some(client_point));
+        }
         let event = mouse_event.upcast::<Event>();
         event.fire(target);
     }
@@ -1454,6 +1489,11 @@ impl Document {
         ident
     }
 
+    /// Whether a rendering update is pending to run animation frame callbacks.
+    pub fn has_pending_animation_frame_callbacks(&self) -> bool {
+        self.animation_frame_list.borrow().iter().any(|&(_, ref callback)| callback.is_some())
+    }
+
     /// <https://html.spec.whatwg.org/multipage/#dom-window-cancelanimationframe>
     pub fn cancel_animation_frame(&self, ident: u32) {
         let mut list = self.animation_frame_list.borrow_mut();
@@ -1836,8 +1876,8 @@ impl Document {
         self.loader.borrow_mut().inhibit_events();
 
         // Step 1.
-        for iframe in self.iter_iframes() {
-            if let Some(document) = iframe.GetContentDocument() {
+        for container in self.iter_browsing_context_containers() {
+            if let Some(document) = container.content_document() {
                 // TODO: abort the active documents of every child browsing context.
                 document.abort();
                 // TODO: salvageable flag.
@@ -1885,17 +1925,18 @@ impl Document {
         }
     }
 
-    /// Iterate over all iframes in the document.
-    pub fn iter_iframes(&self) -> impl Iterator<Item=DomRoot<HTMLIFrameElement>> {
+    /// Iterate over all iframes and frames in the document.
+    pub fn iter_browsing_context_containers(&self) -> impl Iterator<Item=BrowsingContextContainerRoot> {
         self.upcast::<Node>()
             .traverse_preorder()
-            .filter_map(DomRoot::downcast::<HTMLIFrameElement>)
+            .filter_map(|node| node.downcast::<HTMLElement>().and_then(BrowsingContextContainerRoot::from_element))
     }
 
-    /// Find an iframe element in the document.
-    pub fn find_iframe(&self, browsing_context_id: BrowsingContextId) -> Option<DomRoot<HTMLIFrameElement>> {
-        self.iter_iframes()
-            .find(|node| node.browsing_context_id() == Some(browsing_context_id))
+    /// Find an iframe or frame element in the document.
+    pub fn find_browsing_context_container(&self, browsing_context_id: BrowsingContextId)
+                                           -> Option<BrowsingContextContainerRoot> {
+        self.iter_browsing_context_containers()
+            .find(|container| container.browsing_context_id() == Some(browsing_context_id))
     }
 
     pub fn get_dom_loading(&self) -> u64 {
@@ -1964,6 +2005,8 @@ impl Document {
         let (event_name, does_bubble) = match focus_event_type {
             FocusEventType::Focus => (DOMString::from("focus"), EventBubbles::DoesNotBubble),
             FocusEventType::Blur => (DOMString::from("blur"), EventBubbles::DoesNotBubble),
+            FocusEventType::Focusin => (DOMString::from("focusin"), EventBubbles::Bubbles),
+            FocusEventType::Focusout => (DOMString::from("focusout"), EventBubbles::Bubbles),
         };
         let event = FocusEvent::new(&self.window,
                                     event_name,
@@ -2231,6 +2274,7 @@ impl Document {
             stylesheets: DomRefCell::new(DocumentStylesheetSet::new()),
             stylesheet_list: MutNullableDom::new(None),
             ready_state: Cell::new(ready_state),
+            visibility_state: Cell::new(VisibilityState::Visible),
             domcontentloaded_dispatched: Cell::new(domcontentloaded_dispatched),
             possibly_focused: Default::default(),
             focused: Default::default(),
@@ -2266,6 +2310,7 @@ impl Document {
             referrer_policy: Cell::new(referrer_policy),
             target_element: MutNullableDom::new(None),
             last_click_info: DomRefCell::new(None),
+            last_mousemove_point: Cell::new(None),
             ignore_destructive_writes_counter: Default::default(),
             spurious_animation_frames: Cell::new(0),
             dom_count: Cell::new(1),
@@ -3429,6 +3474,11 @@ impl DocumentMethods for Document {
         self.upcast::<Node>().append(nodes)
     }
 
+    // https://dom.spec.whatwg.org/#dom-parentnode-replacechildren
+    fn ReplaceChildren(&self, nodes: Vec<NodeOrString>) -> ErrorResult {
+        self.upcast::<Node>().replace_children(nodes)
+    }
+
     // https://dom.spec.whatwg.org/#dom-parentnode-queryselector
     fn QuerySelector(&self, selectors: DOMString) -> Fallible<Option<DomRoot<Element>>> {
         let root = self.upcast::<Node>();
@@ -3448,11 +3498,9 @@ impl DocumentMethods for Document {
 
     // https://html.spec.whatwg.org/multipage/#dom-document-defaultview
     fn GetDefaultView(&self) -> Option<DomRoot<Window>> {
-        if self.has_browsing_context {
-            Some(DomRoot::from_ref(&*self.window))
-        } else {
-            None
-        }
+        // A document whose browsing context has been discarded, such as the
+        // document of a removed iframe, no longer has a default view.
+        self.browsing_context().map(|_| DomRoot::from_ref(&*self.window))
     }
 
     // https://html.spec.whatwg.org/multipage/#dom-document-cookie
@@ -3624,12 +3672,16 @@ impl DocumentMethods for Document {
         let y = *y as f32;
         let point = &Point2D::new(x, y);
         let window = window_from_node(self);
-        let viewport = window.window_size().unwrap().initial_viewport;
 
         if self.browsing_context().is_none() {
             return None;
         }
 
+        let viewport = match window.window_size() {
+            Some(window_size) => window_size.initial_viewport,
+            None => return None,
+        };
+
         if x < 0.0 || y < 0.0 || x > viewport.width || y > viewport.height {
             return None;
         }
@@ -3658,12 +3710,16 @@ impl DocumentMethods for Document {
         let y = *y as f32;
         let point = &Point2D::new(x, y);
         let window = window_from_node(self);
-        let viewport = window.window_size().unwrap().initial_viewport;
 
         if self.browsing_context().is_none() {
             return vec!();
         }
 
+        let viewport = match window.window_size() {
+            Some(window_size) => window_size.initial_viewport,
+            None => return vec!(),
+        };
+
         // Step 2
         if x < 0.0 || y < 0.0 || x > viewport.width || y > viewport.height {
             return vec!();
@@ -3938,6 +3994,19 @@ impl DocumentMethods for Document {
     fn ExitFullscreen(&self) -> Rc<Promise> {
         self.exit_fullscreen()
     }
+
+    // https://w3c.github.io/page-visibility/#dom-document-hidden
+    fn Hidden(&self) -> bool {
+        self.visibility_state.get() == VisibilityState::Hidden
+    }
+
+    // https://w3c.github.io/page-visibility/#dom-document-visibilitystate
+    fn VisibilityState(&self) -> VisibilityState {
+        self.visibility_state.get()
+    }
+
+    // https://w3c.github.io/page-visibility/#dom-document-onvisibilitychange
+    event_handler!(visibilitychange, GetOnvisibilitychange, SetOnvisibilitychange);
 }
 
 fn update_with_current_time_ms(marker: &Cell<u64>) {
@@ -3975,6 +4044,8 @@ pub enum FocusType {
 pub enum FocusEventType {
     Focus,      // Element gained focus. Doesn't bubble.
     Blur,       // Element lost focus. Doesn't bubble.
+    Focusin,    // Element gained focus. Bubbles.
+    Focusout,   // Element lost focus. Bubbles.
 }
 
 /// A fake `requestAnimationFrame()` callback—"fake" because it is not triggered by the video
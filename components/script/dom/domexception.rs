@@ -35,6 +35,8 @@ pub enum DOMErrorName {
     TimeoutError = DOMExceptionConstants::TIMEOUT_ERR,
     InvalidNodeTypeError = DOMExceptionConstants::INVALID_NODE_TYPE_ERR,
     DataCloneError = DOMExceptionConstants::DATA_CLONE_ERR,
+    NotReadableError,
+    NotAllowedError,
 }
 
 #[dom_struct]
@@ -61,7 +63,12 @@ impl DOMException {
 impl DOMExceptionMethods for DOMException {
     // https://heycam.github.io/webidl/#dfn-DOMException
     fn Code(&self) -> u16 {
-        self.code as u16
+        match self.code {
+            // Names without a legacy code constant have a code of 0.
+            DOMErrorName::NotReadableError |
+            DOMErrorName::NotAllowedError => 0,
+            code => code as u16,
+        }
     }
 
     // https://heycam.github.io/webidl/#idl-DOMException-error-names
@@ -94,6 +101,8 @@ impl DOMExceptionMethods for DOMException {
             DOMErrorName::InvalidNodeTypeError =>
                 "The supplied node is incorrect or has an incorrect ancestor for this operation.",
             DOMErrorName::DataCloneError => "The object can not be cloned.",
+            DOMErrorName::NotReadableError => "The I/O read operation failed.",
+            DOMErrorName::NotAllowedError => "The operation is not allowed in the current context.",
         };
 
         DOMString::from(message)
@@ -21,20 +21,33 @@ pub struct DOMTokenList {
     reflector_: Reflector,
     element: Dom<Element>,
     local_name: LocalName,
+    supported_tokens: Option<Vec<Atom>>,
 }
 
 impl DOMTokenList {
-    pub fn new_inherited(element: &Element, local_name: LocalName) -> DOMTokenList {
+    pub fn new_inherited(element: &Element,
+                         local_name: LocalName,
+                         supported_tokens: Option<Vec<Atom>>)
+                         -> DOMTokenList {
         DOMTokenList {
             reflector_: Reflector::new(),
             element: Dom::from_ref(element),
             local_name: local_name,
+            supported_tokens: supported_tokens,
         }
     }
 
-    pub fn new(element: &Element, local_name: &LocalName) -> DomRoot<DOMTokenList> {
+    /// Creates a token list for the `local_name` attribute of `element`.
+    /// `supported_tokens` is `None` when the attribute does not define a set
+    /// of supported tokens, in which case `supports()` throws.
+    pub fn new(element: &Element,
+               local_name: &LocalName,
+               supported_tokens: Option<Vec<Atom>>)
+               -> DomRoot<DOMTokenList> {
         let window = window_from_node(element);
-        reflect_dom_object(Box::new(DOMTokenList::new_inherited(element, local_name.clone())),
+        reflect_dom_object(Box::new(DOMTokenList::new_inherited(element,
+                                                                local_name.clone(),
+                                                                supported_tokens)),
                            &*window,
                            DOMTokenListBinding::Wrap)
     }
@@ -139,7 +152,7 @@ impl DOMTokenListMethods for DOMTokenList {
     }
 
     // https://dom.spec.whatwg.org/#dom-domtokenlist-replace
-    fn Replace(&self, token: DOMString, new_token: DOMString) -> ErrorResult {
+    fn Replace(&self, token: DOMString, new_token: DOMString) -> Fallible<bool> {
         if token.is_empty() || new_token.is_empty() {
             // Step 1.
             return Err(Error::Syntax);
@@ -148,20 +161,46 @@ impl DOMTokenListMethods for DOMTokenList {
             // Step 2.
             return Err(Error::InvalidCharacter);
         }
-        // Steps 3-4.
         let token = Atom::from(token);
         let new_token = Atom::from(new_token);
-        let mut atoms = self.element.get_tokenlist_attribute(&self.local_name);
-        if let Some(pos) = atoms.iter().position(|atom| *atom == token) {
-            if !atoms.contains(&new_token) {
-                atoms[pos] = new_token;
+        let atoms = self.element.get_tokenlist_attribute(&self.local_name);
+        // Step 3.
+        if !atoms.contains(&token) {
+            return Ok(false);
+        }
+        // Step 4.
+        let mut replaced = false;
+        let atoms = atoms.into_iter().filter_map(|atom| {
+            if atom != token && atom != new_token {
+                Some(atom)
+            } else if !replaced {
+                replaced = true;
+                Some(new_token.clone())
             } else {
-                atoms.remove(pos);
+                None
             }
-            // Step 5.
-            self.element.set_atomic_tokenlist_attribute(&self.local_name, atoms);
-        }
-        Ok(())
+        }).collect();
+        // Step 5.
+        self.element.set_atomic_tokenlist_attribute(&self.local_name, atoms);
+        // Step 6.
+        Ok(true)
+    }
+
+    // https://dom.spec.whatwg.org/#dom-domtokenlist-supports
+    fn Supports(&self, token: DOMString) -> Fallible<bool> {
+        // https://dom.spec.whatwg.org/#concept-domtokenlist-validation
+        // Step 1.
+        let supported_tokens = match self.supported_tokens {
+            Some(ref supported_tokens) => supported_tokens,
+            None => {
+                return Err(Error::Type(format!("The {} attribute has no supported tokens",
+                                               self.local_name)));
+            },
+        };
+        // Step 2.
+        let token = Atom::from(token.to_ascii_lowercase());
+        // Steps 3-4.
+        Ok(supported_tokens.contains(&token))
     }
 
     // https://dom.spec.whatwg.org/#concept-dtl-serialize
@@ -12,12 +12,14 @@ use dom::bindings::codegen::Bindings::AttrBinding::AttrMethods;
 use dom::bindings::codegen::Bindings::DocumentBinding::DocumentMethods;
 use dom::bindings::codegen::Bindings::ElementBinding;
 use dom::bindings::codegen::Bindings::ElementBinding::ElementMethods;
+use dom::bindings::codegen::Bindings::ElementBinding::ScrollLogicalPosition;
 use dom::bindings::codegen::Bindings::EventBinding::EventMethods;
 use dom::bindings::codegen::Bindings::FunctionBinding::Function;
 use dom::bindings::codegen::Bindings::HTMLTemplateElementBinding::HTMLTemplateElementMethods;
 use dom::bindings::codegen::Bindings::NodeBinding::NodeMethods;
 use dom::bindings::codegen::Bindings::WindowBinding::{ScrollBehavior, ScrollToOptions};
 use dom::bindings::codegen::Bindings::WindowBinding::WindowMethods;
+use dom::bindings::codegen::UnionTypes::BooleanOrScrollIntoViewOptions;
 use dom::bindings::codegen::UnionTypes::NodeOrString;
 use dom::bindings::conversions::DerivedFrom;
 use dom::bindings::error::{Error, ErrorResult, Fallible};
@@ -46,14 +48,18 @@ use dom::htmlelement::HTMLElement;
 use dom::htmlfieldsetelement::HTMLFieldSetElement;
 use dom::htmlfontelement::{HTMLFontElement, HTMLFontElementLayoutHelpers};
 use dom::htmlformelement::FormControlElementHelpers;
+use dom::htmlframeelement::HTMLFrameElement;
+use dom::htmlframesetelement::{FramesetCell, HTMLFrameSetElement, frameset_cell_for_layout};
 use dom::htmlhrelement::{HTMLHRElement, HTMLHRLayoutHelpers};
 use dom::htmliframeelement::{HTMLIFrameElement, HTMLIFrameElementLayoutMethods};
 use dom::htmlimageelement::{HTMLImageElement, LayoutHTMLImageElementHelpers};
 use dom::htmlinputelement::{HTMLInputElement, LayoutHTMLInputElementHelpers};
 use dom::htmllabelelement::HTMLLabelElement;
 use dom::htmllegendelement::HTMLLegendElement;
+use dom::htmllielement::{HTMLLIElement, HTMLLIElementLayoutHelpers};
 use dom::htmllinkelement::HTMLLinkElement;
 use dom::htmlobjectelement::HTMLObjectElement;
+use dom::htmlolistelement::{HTMLOListElement, HTMLOListElementLayoutHelpers};
 use dom::htmloptgroupelement::HTMLOptGroupElement;
 use dom::htmlselectelement::HTMLSelectElement;
 use dom::htmlstyleelement::HTMLStyleElement;
@@ -117,7 +123,7 @@ use style::selector_parser::{NonTSPseudoClass, PseudoElement, RestyleDamage, Sel
 use style::selector_parser::extended_filtering;
 use style::shared_lock::{SharedRwLock, Locked};
 use style::thread_state;
-use style::values::{CSSFloat, Either};
+use style::values::{CSSFloat, CustomIdent, Either};
 use style::values::{specified, computed};
 use stylesheet_loader::StylesheetOwner;
 use task::TaskOnce;
@@ -783,6 +789,59 @@ impl LayoutElementHelpers for LayoutDom<Element> {
                 shared_lock,
                 PropertyDeclaration::BorderRightWidth(width_value)));
         }
+
+        // https://html.spec.whatwg.org/multipage/#frames-and-framesets
+        let frameset_cell = if self.downcast::<HTMLFrameElement>().is_some() ||
+                               self.downcast::<HTMLFrameSetElement>().is_some() {
+            frameset_cell_for_layout(self.upcast::<Node>())
+        } else {
+            None
+        };
+
+        match frameset_cell {
+            Some(FramesetCell::Cell(width, height)) => {
+                hints.push(from_declaration(
+                    shared_lock,
+                    PropertyDeclaration::Width(width.to_specified_value())));
+                hints.push(from_declaration(
+                    shared_lock,
+                    PropertyDeclaration::Height(height.to_specified_value())));
+            }
+            Some(FramesetCell::Hidden) => {
+                hints.push(from_declaration(
+                    shared_lock,
+                    PropertyDeclaration::Display(specified::Display::None)));
+            }
+            None => {}
+        }
+
+        // https://html.spec.whatwg.org/multipage/#ordinal-value
+        let list_item_counter_reset = if let Some(this) = self.downcast::<HTMLOListElement>() {
+            Some(this.get_list_item_counter_reset())
+        } else if let Some(this) = self.downcast::<HTMLLIElement>() {
+            this.get_list_item_counter_reset()
+        } else {
+            None
+        };
+
+        if let Some(value) = list_item_counter_reset {
+            hints.push(from_declaration(
+                shared_lock,
+                PropertyDeclaration::CounterReset(specified::CounterReset::new(vec![
+                    (CustomIdent(Atom::from("list-item")), specified::Integer::new(value))
+                ]))));
+        }
+
+        let list_item_counter_increment =
+            self.downcast::<HTMLLIElement>().and_then(|this| this.get_list_item_counter_increment());
+
+        if let Some(value) = list_item_counter_increment {
+            hints.push(from_declaration(
+                shared_lock,
+                PropertyDeclaration::CounterIncrement(specified::CounterIncrement::new(vec![
+                    (CustomIdent(Atom::from("list-item")), specified::Integer::new(value))
+                ]))));
+        }
     }
 
     #[allow(unsafe_code)]
@@ -1528,7 +1587,101 @@ impl Element {
         }
 
         // Step 11
-        win.scroll_node(node, x, y, behavior);
+        self.scroll_element(x, y, behavior);
+    }
+
+    // https://drafts.csswg.org/cssom-view/#scroll-an-element
+    fn scroll_element(&self, x: f64, y: f64, behavior: ScrollBehavior) {
+        // Steps 1-7: clamp to the scrollable overflow area.
+        // TODO: take the overflow direction into account.
+        let max_x = (self.ScrollWidth() - self.ClientWidth()).max(0) as f64;
+        let max_y = (self.ScrollHeight() - self.ClientHeight()).max(0) as f64;
+        let x = x.max(0.0).min(max_x);
+        let y = y.max(0.0).min(max_y);
+
+        // Steps 8-10
+        let node = self.upcast::<Node>();
+        window_from_node(self).scroll_node(node, x, y, behavior);
+    }
+
+    // https://drafts.csswg.org/cssom-view/#scroll-an-element-into-view
+    fn scroll_into_view(&self,
+                        block: ScrollLogicalPosition,
+                        inline: ScrollLogicalPosition,
+                        behavior: ScrollBehavior) {
+        let node = self.upcast::<Node>();
+        let doc = node.owner_doc();
+        let win = match doc.GetDefaultView() {
+            None => return,
+            Some(win) => win,
+        };
+
+        // The element's border box, in the coordinates of the scrolling box
+        // being handled. Layout reports it without any scroll offsets applied.
+        let rect = node.bounding_content_box_or_zero();
+        let mut x = rect.origin.x.to_f64_px();
+        let mut y = rect.origin.y.to_f64_px();
+        let width = rect.size.width.to_f64_px();
+        let height = rect.size.height.to_f64_px();
+
+        // Step 1
+        let root = self.root_element();
+        for ancestor in node.ancestors().filter_map(DomRoot::downcast::<Element>) {
+            if *ancestor == *root {
+                break;
+            }
+            if !ancestor.has_css_layout_box() ||
+               !ancestor.has_scrolling_box() ||
+               !ancestor.has_overflow()
+            {
+                continue;
+            }
+
+            let ancestor_node = ancestor.upcast::<Node>();
+            let box_rect = ancestor_node.bounding_content_box_or_zero();
+            let element_x = x - box_rect.origin.x.to_f64_px() - ancestor.ClientLeft() as f64;
+            let element_y = y - box_rect.origin.y.to_f64_px() - ancestor.ClientTop() as f64;
+            let scroll_left = ancestor.ScrollLeft();
+            let scroll_top = ancestor.ScrollTop();
+            let max_left = (ancestor.ScrollWidth() - ancestor.ClientWidth()).max(0) as f64;
+            let max_top = (ancestor.ScrollHeight() - ancestor.ClientHeight()).max(0) as f64;
+
+            // Steps 1.1-1.12
+            let left = aligned_scroll_position(inline,
+                                               element_x,
+                                               width,
+                                               scroll_left,
+                                               ancestor.ClientWidth() as f64);
+            let top = aligned_scroll_position(block,
+                                              element_y,
+                                              height,
+                                              scroll_top,
+                                              ancestor.ClientHeight() as f64);
+            let left = left.max(0.0).min(max_left);
+            let top = top.max(0.0).min(max_top);
+
+            // Step 1.13
+            if left != scroll_left || top != scroll_top {
+                ancestor.scroll_element(left, top, behavior);
+            }
+
+            // Scrolling moves the element by as much in the outer scrolling boxes.
+            x -= left;
+            y -= top;
+        }
+
+        // Finally, the viewport.
+        let left = aligned_scroll_position(inline,
+                                           x,
+                                           width,
+                                           win.ScrollX() as f64,
+                                           win.InnerWidth() as f64);
+        let top = aligned_scroll_position(block,
+                                          y,
+                                          height,
+                                          win.ScrollY() as f64,
+                                          win.InnerHeight() as f64);
+        win.scroll(left, top, behavior);
     }
 
     // https://w3c.github.io/DOM-Parsing/#parsing
@@ -1633,7 +1786,7 @@ impl ElementMethods for Element {
 
     // https://dom.spec.whatwg.org/#dom-element-classlist
     fn ClassList(&self) -> DomRoot<DOMTokenList> {
-        self.class_list.or_init(|| DOMTokenList::new(self, &local_name!("class")))
+        self.class_list.or_init(|| DOMTokenList::new(self, &local_name!("class"), None))
     }
 
     // https://dom.spec.whatwg.org/#dom-element-attributes
@@ -1680,6 +1833,39 @@ impl ElementMethods for Element {
         self.get_attribute(namespace, &LocalName::from(local_name))
     }
 
+    // https://dom.spec.whatwg.org/#dom-element-toggleattribute
+    fn ToggleAttribute(&self, name: DOMString, force: Option<bool>) -> Fallible<bool> {
+        // Step 1.
+        if xml_name_type(&name) == InvalidXMLName {
+            return Err(Error::InvalidCharacter);
+        }
+
+        // Step 2.
+        let name = self.parsed_name(name);
+
+        // Step 3.
+        let present = self.attrs.borrow().iter().any(|attr| *attr.name() == name);
+
+        match (present, force) {
+            // Step 4.
+            (false, Some(false)) => Ok(false),
+            (false, _) => {
+                let value = self.parse_attribute(&ns!(), &name, DOMString::new());
+                self.set_first_matching_attribute(
+                    name.clone(), value, name.clone(), ns!(), None,
+                    |attr| *attr.name() == name);
+                Ok(true)
+            },
+            // Step 5.
+            (true, None) | (true, Some(false)) => {
+                self.remove_attribute_by_name(&name);
+                Ok(false)
+            },
+            // Step 6.
+            (true, Some(true)) => Ok(true),
+        }
+    }
+
     // https://dom.spec.whatwg.org/#dom-element-setattribute
     fn SetAttribute(&self, name: DOMString, value: DOMString) -> ErrorResult {
         // Step 1.
@@ -1745,11 +1931,19 @@ impl ElementMethods for Element {
             }
 
             // Step 4.
+            let old_name = old_attr.local_name().clone();
+            let old_value = DOMString::from(&**old_attr.value());
+            let namespace = old_attr.namespace().clone();
+            let mutation = Mutation::Attribute {
+                name: old_name.clone(),
+                namespace: namespace.clone(),
+                old_value: old_value.clone(),
+            };
+
+            MutationObserver::queue_a_mutation_record(&self.node, mutation);
+
             if self.get_custom_element_definition().is_some() {
-                let old_name = old_attr.local_name().clone();
-                let old_value = DOMString::from(&**old_attr.value());
                 let new_value = DOMString::from(&**attr.value());
-                let namespace = old_attr.namespace().clone();
                 let reaction = CallbackReaction::AttributeChanged(old_name, Some(old_value),
                     Some(new_value), namespace);
                 ScriptThread::enqueue_callback_reaction(self, reaction, None);
@@ -1854,6 +2048,36 @@ impl ElementMethods for Element {
                      rect.size.height.to_f64_px())
     }
 
+    // https://drafts.csswg.org/cssom-view/#dom-element-scrollintoview
+    fn ScrollIntoView(&self, arg: Option<BooleanOrScrollIntoViewOptions>) {
+        // Step 1
+        let mut behavior = ScrollBehavior::Auto;
+        let mut block = ScrollLogicalPosition::Start;
+        let mut inline = ScrollLogicalPosition::Nearest;
+
+        match arg {
+            // Step 2
+            Some(BooleanOrScrollIntoViewOptions::ScrollIntoViewOptions(options)) => {
+                behavior = options.parent.behavior;
+                block = options.block;
+                inline = options.inline;
+            },
+            // Step 3
+            Some(BooleanOrScrollIntoViewOptions::Boolean(false)) => {
+                block = ScrollLogicalPosition::End;
+            },
+            Some(BooleanOrScrollIntoViewOptions::Boolean(true)) | None => {},
+        }
+
+        // Step 4
+        if !self.has_css_layout_box() {
+            return;
+        }
+
+        // Step 5
+        self.scroll_into_view(block, inline, behavior);
+    }
+
     // https://drafts.csswg.org/cssom-view/#dom-element-scroll
     fn Scroll(&self, options: &ScrollToOptions) {
         // Step 1
@@ -1990,7 +2214,7 @@ impl ElementMethods for Element {
         }
 
         // Step 11
-        win.scroll_node(node, self.ScrollLeft(), y, behavior);
+        self.scroll_element(self.ScrollLeft(), y, behavior);
     }
 
     // https://drafts.csswg.org/cssom-view/#dom-element-scrolltop
@@ -2089,7 +2313,7 @@ impl ElementMethods for Element {
         }
 
         // Step 11
-        win.scroll_node(node, x, self.ScrollTop(), behavior);
+        self.scroll_element(x, self.ScrollTop(), behavior);
     }
 
     // https://drafts.csswg.org/cssom-view/#dom-element-scrollwidth
@@ -2235,6 +2459,11 @@ impl ElementMethods for Element {
         self.upcast::<Node>().append(nodes)
     }
 
+    // https://dom.spec.whatwg.org/#dom-parentnode-replacechildren
+    fn ReplaceChildren(&self, nodes: Vec<NodeOrString>) -> ErrorResult {
+        self.upcast::<Node>().replace_children(nodes)
+    }
+
     // https://dom.spec.whatwg.org/#dom-parentnode-queryselector
     fn QuerySelector(&self, selectors: DOMString) -> Fallible<Option<DomRoot<Element>>> {
         let root = self.upcast::<Node>();
@@ -3009,9 +3238,7 @@ impl Element {
 
     /// <https://dom.spec.whatwg.org/#connected>
     pub fn is_connected(&self) -> bool {
-        let node = self.upcast::<Node>();
-        let root = node.GetRootNode();
-        root.is::<Document>()
+        self.upcast::<Node>().is_in_doc()
     }
 }
 
@@ -3238,3 +3465,35 @@ pub fn cors_setting_for_element(element: &Element) -> Option<CorsSettings> {
         }
     })
 }
+
+/// The scroll position along one axis that aligns a box at `position` of
+/// length `size` as requested, in a scrolling box of length `scrollport_size`
+/// currently scrolled to `scroll_position`.
+/// <https://drafts.csswg.org/cssom-view/#scroll-an-element-into-view>
+fn aligned_scroll_position(alignment: ScrollLogicalPosition,
+                           position: f64,
+                           size: f64,
+                           scroll_position: f64,
+                           scrollport_size: f64) -> f64 {
+    let align_start = position;
+    let align_end = position + size - scrollport_size;
+    match alignment {
+        ScrollLogicalPosition::Start => align_start,
+        ScrollLogicalPosition::End => align_end,
+        ScrollLogicalPosition::Center => position + (size - scrollport_size) / 2.0,
+        ScrollLogicalPosition::Nearest => {
+            let start_outside = position < scroll_position;
+            let end_outside = position + size > scroll_position + scrollport_size;
+            let fits = size <= scrollport_size;
+            match (start_outside, end_outside) {
+                (true, false) if fits => align_start,
+                (true, false) => align_end,
+                (false, true) if fits => align_end,
+                (false, true) => align_start,
+                // Either the box is entirely visible, or it overflows the
+                // scrolling box on both sides.
+                _ => scroll_position,
+            }
+        }
+    }
+}
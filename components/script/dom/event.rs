@@ -14,7 +14,7 @@ use dom::bindings::reflector::{DomObject, Reflector, reflect_dom_object};
 use dom::bindings::root::{Dom, DomRoot, MutNullableDom, RootedReference};
 use dom::bindings::str::DOMString;
 use dom::document::Document;
-use dom::eventtarget::{CompiledEventListener, EventTarget, ListenerPhase};
+use dom::eventtarget::{CompiledEventListener, CompiledEventListenerEntry, EventTarget, ListenerPhase};
 use dom::globalscope::GlobalScope;
 use dom::node::Node;
 use dom::virtualmethods::vtable_for;
@@ -41,7 +41,14 @@ pub struct Event {
     trusted: Cell<bool>,
     dispatching: Cell<bool>,
     initialized: Cell<bool>,
+    /// <https://dom.spec.whatwg.org/#in-passive-listener-flag>
+    in_passive_listener: Cell<bool>,
+    /// Whether ignoring `preventDefault()` in a passive listener was already
+    /// reported for this event.
+    warned_passive_prevent_default: Cell<bool>,
     timestamp: u64,
+    /// <https://dom.spec.whatwg.org/#event-path>
+    path: DomRefCell<Vec<Dom<EventTarget>>>,
 }
 
 impl Event {
@@ -60,7 +67,10 @@ impl Event {
             trusted: Cell::new(false),
             dispatching: Cell::new(false),
             initialized: Cell::new(false),
+            in_passive_listener: Cell::new(false),
+            warned_passive_prevent_default: Cell::new(false),
             timestamp: time::get_time().sec as u64,
+            path: DomRefCell::new(vec![]),
         }
     }
 
@@ -148,6 +158,14 @@ impl Event {
             }
         }
 
+        {
+            let mut path = self.path.borrow_mut();
+            path.push(Dom::from_ref(target));
+            for ancestor in event_path.r() {
+                path.push(Dom::from_ref(*ancestor));
+            }
+        }
+
         // Steps 5-9. In a separate function to short-circuit various things easily.
         dispatch_to_listeners(self, target, event_path.r());
 
@@ -188,6 +206,7 @@ impl Event {
         self.stop_immediate.set(false);
         self.phase.set(EventPhase::None);
         self.current_target.set(None);
+        self.path.borrow_mut().clear();
     }
 
     #[inline]
@@ -222,6 +241,15 @@ impl Event {
 }
 
 impl EventMethods for Event {
+    // https://dom.spec.whatwg.org/#dom-event-composedpath
+    fn ComposedPath(&self) -> Vec<DomRoot<EventTarget>> {
+        if self.current_target.get().is_none() {
+            return vec![];
+        }
+        // Without shadow trees, every item of the path is visible from the current target.
+        self.path.borrow().iter().map(|target| DomRoot::from_ref(&**target)).collect()
+    }
+
     // https://dom.spec.whatwg.org/#dom-event-eventphase
     fn EventPhase(&self) -> u16 {
         self.phase.get() as u16
@@ -249,9 +277,18 @@ impl EventMethods for Event {
 
     // https://dom.spec.whatwg.org/#dom-event-preventdefault
     fn PreventDefault(&self) {
-        if self.cancelable.get() {
-            self.canceled.set(EventDefault::Prevented)
+        if !self.cancelable.get() {
+            return;
         }
+        if self.in_passive_listener.get() {
+            if !self.warned_passive_prevent_default.get() {
+                self.warned_passive_prevent_default.set(true);
+                warn!("Ignoring preventDefault() on a {} event inside a passive event listener.",
+                      self.type_());
+            }
+            return;
+        }
+        self.canceled.set(EventDefault::Prevented)
     }
 
     // https://dom.spec.whatwg.org/#dom-event-stoppropagation
@@ -491,7 +528,7 @@ fn invoke(window: Option<&Window>,
 fn inner_invoke(window: Option<&Window>,
                 object: &EventTarget,
                 event: &Event,
-                listeners: &[CompiledEventListener])
+                listeners: &[CompiledEventListenerEntry])
                 -> bool {
     // Step 1.
     let mut found = false;
@@ -504,19 +541,31 @@ fn inner_invoke(window: Option<&Window>,
         // Step 2.2.
         found = true;
 
-        // TODO: step 2.5.
+        // Step 2.5.
+        if listener.once {
+            if let CompiledEventListener::Listener(ref event_listener) = listener.listener {
+                object.remove_listener_if_once(&event.type_(), event_listener, listener.phase);
+            }
+        }
 
         // Step 2.6.
+        if listener.passive {
+            event.in_passive_listener.set(true);
+        }
+
+        // Step 2.7.
         let marker = TimelineMarker::start("DOMEvent".to_owned());
-        listener.call_or_handle_event(object, event, ExceptionHandling::Report);
+        listener.listener.call_or_handle_event(object, event, ExceptionHandling::Report);
         if let Some(window) = window {
             window.emit_timeline_marker(marker.end());
         }
+
+        // Step 2.8.
+        event.in_passive_listener.set(false);
+
         if event.stop_immediate.get() {
             return found;
         }
-
-        // TODO: step 2.7.
     }
 
     // Step 3.
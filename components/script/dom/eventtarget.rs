@@ -2,6 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use dom::abortsignal::AbortAlgorithm;
 use dom::beforeunloadevent::BeforeUnloadEvent;
 use dom::bindings::callback::{CallbackContainer, ExceptionHandling, CallbackFunction};
 use dom::bindings::cell::DomRefCell;
@@ -23,7 +24,7 @@ use dom::bindings::codegen::UnionTypes::EventOrString;
 use dom::bindings::error::{Error, Fallible, report_pending_exception};
 use dom::bindings::inheritance::Castable;
 use dom::bindings::reflector::{DomObject, Reflector, reflect_dom_object};
-use dom::bindings::root::DomRoot;
+use dom::bindings::root::{Dom, DomRoot};
 use dom::bindings::str::DOMString;
 use dom::element::Element;
 use dom::errorevent::ErrorEvent;
@@ -146,6 +147,16 @@ pub enum CompiledEventListener {
     Handler(CommonEventHandler),
 }
 
+/// A compiled listener along with the flags it was added with.
+pub struct CompiledEventListenerEntry {
+    pub listener: CompiledEventListener,
+    pub phase: ListenerPhase,
+    /// <https://dom.spec.whatwg.org/#event-listener-once>
+    pub once: bool,
+    /// <https://dom.spec.whatwg.org/#event-listener-passive>
+    pub passive: bool,
+}
+
 impl CompiledEventListener {
     #[allow(unsafe_code)]
     // https://html.spec.whatwg.org/multipage/#the-event-handler-processing-algorithm
@@ -232,7 +243,9 @@ impl CompiledEventListener {
 /// A listener in a collection of event listeners.
 struct EventListenerEntry {
     phase: ListenerPhase,
-    listener: EventListenerType
+    listener: EventListenerType,
+    once: bool,
+    passive: bool,
 }
 
 #[derive(JSTraceable, MallocSizeOf)]
@@ -268,11 +281,14 @@ impl EventListeners {
 
     // https://html.spec.whatwg.org/multipage/#getting-the-current-value-of-the-event-handler
     fn get_listeners(&mut self, phase: Option<ListenerPhase>, owner: &EventTarget, ty: &Atom)
-                     -> Vec<CompiledEventListener> {
+                     -> Vec<CompiledEventListenerEntry> {
         self.0.iter_mut().filter_map(|entry| {
             if phase.is_none() || Some(entry.phase) == phase {
                 // Step 1.1-1.8, 2
-                entry.listener.get_compiled_listener(owner, ty)
+                let (phase, once, passive) = (entry.phase, entry.once, entry.passive);
+                entry.listener.get_compiled_listener(owner, ty).map(|listener| {
+                    CompiledEventListenerEntry { listener, phase, once, passive }
+                })
             } else {
                 None
             }
@@ -307,7 +323,7 @@ impl EventTarget {
     pub fn get_listeners_for(&self,
                              type_: &Atom,
                              specific_phase: Option<ListenerPhase>)
-                             -> Vec<CompiledEventListener> {
+                             -> Vec<CompiledEventListenerEntry> {
         self.handlers.borrow_mut().get_mut(type_).map_or(vec![], |listeners| {
             listeners.get_listeners(specific_phase, self, type_)
         })
@@ -365,6 +381,8 @@ impl EventTarget {
                     entries.push(EventListenerEntry {
                         phase: ListenerPhase::Bubbling,
                         listener: EventListenerType::Inline(listener),
+                        once: false,
+                        passive: false,
                     });
                 }
             }
@@ -597,34 +615,59 @@ impl EventTarget {
         event.fire(self);
         event
     }
-    // https://dom.spec.whatwg.org/#dom-eventtarget-addeventlistener
+    // https://dom.spec.whatwg.org/#add-an-event-listener
     pub fn add_event_listener(
         &self,
         ty: DOMString,
         listener: Option<Rc<EventListener>>,
         options: AddEventListenerOptions,
     ) {
+        // Step 2.
+        if let Some(ref signal) = options.signal {
+            if signal.aborted() {
+                return;
+            }
+        }
+
+        // Step 3.
         let listener = match listener {
             Some(l) => l,
             None => return,
         };
-        let mut handlers = self.handlers.borrow_mut();
-        let entry = match handlers.entry(Atom::from(ty)) {
-            Occupied(entry) => entry.into_mut(),
-            Vacant(entry) => entry.insert(EventListeners(vec!())),
-        };
-
+        let ty = Atom::from(ty);
         let phase = if options.parent.capture {
             ListenerPhase::Capturing
         } else {
             ListenerPhase::Bubbling
         };
-        let new_entry = EventListenerEntry {
-            phase: phase,
-            listener: EventListenerType::Additive(listener)
-        };
-        if !entry.contains(&new_entry) {
-            entry.push(new_entry);
+
+        // Step 4.
+        {
+            let mut handlers = self.handlers.borrow_mut();
+            let entry = match handlers.entry(ty.clone()) {
+                Occupied(entry) => entry.into_mut(),
+                Vacant(entry) => entry.insert(EventListeners(vec!())),
+            };
+
+            let listener = EventListenerType::Additive(listener.clone());
+            if !entry.iter().any(|e| e.phase == phase && e.listener == listener) {
+                entry.push(EventListenerEntry {
+                    phase: phase,
+                    listener: listener,
+                    once: options.once,
+                    passive: options.passive,
+                });
+            }
+        }
+
+        // Step 5.
+        if let Some(ref signal) = options.signal {
+            signal.add_algorithm(AbortAlgorithm::RemoveEventListener(EventListenerAbortAlgorithm {
+                target: Dom::from_ref(self),
+                ty: ty,
+                listener: listener,
+                phase: phase,
+            }));
         }
     }
 
@@ -639,23 +682,35 @@ impl EventTarget {
             Some(l) => l,
             None => return,
         };
+        let phase = if options.capture {
+            ListenerPhase::Capturing
+        } else {
+            ListenerPhase::Bubbling
+        };
+        self.remove_listener(&Atom::from(ty), listener, phase);
+    }
+
+    /// <https://dom.spec.whatwg.org/#remove-an-event-listener>
+    fn remove_listener(&self, ty: &Atom, listener: &Rc<EventListener>, phase: ListenerPhase) {
         let mut handlers = self.handlers.borrow_mut();
-        let entry = handlers.get_mut(&Atom::from(ty));
+        let entry = handlers.get_mut(ty);
         for entry in entry {
-            let phase = if options.capture {
-                ListenerPhase::Capturing
-            } else {
-                ListenerPhase::Bubbling
-            };
-            let old_entry = EventListenerEntry {
-                phase: phase,
-                listener: EventListenerType::Additive(listener.clone())
-            };
-            if let Some(position) = entry.iter().position(|e| *e == old_entry) {
+            let listener = EventListenerType::Additive(listener.clone());
+            if let Some(position) = entry.iter().position(|e| e.phase == phase && e.listener == listener) {
                 entry.remove(position);
             }
         }
     }
+
+    /// Removes `listener` from the listeners for `ty` in `phase` if it was added
+    /// with `once`.
+    pub fn remove_listener_if_once(&self, ty: &Atom, listener: &Rc<EventListener>, phase: ListenerPhase) {
+        let mut handlers = self.handlers.borrow_mut();
+        let listener = EventListenerType::Additive(listener.clone());
+        for entries in handlers.get_mut(ty) {
+            entries.retain(|e| !(e.once && e.phase == phase && e.listener == listener));
+        }
+    }
 }
 
 impl EventTargetMethods for EventTarget {
@@ -705,7 +760,12 @@ impl From<AddEventListenerOptionsOrBoolean> for AddEventListenerOptions {
                 options
             },
             AddEventListenerOptionsOrBoolean::Boolean(capture) => {
-                Self { parent: EventListenerOptions { capture } }
+                Self {
+                    parent: EventListenerOptions { capture },
+                    passive: false,
+                    once: false,
+                    signal: None,
+                }
             },
         }
     }
@@ -723,3 +783,20 @@ impl From<EventListenerOptionsOrBoolean> for EventListenerOptions {
         }
     }
 }
+
+/// Removes an event listener once the `AbortSignal` it was added with aborts.
+#[derive(JSTraceable, MallocSizeOf)]
+#[must_root]
+pub struct EventListenerAbortAlgorithm {
+    target: Dom<EventTarget>,
+    ty: Atom,
+    #[ignore_malloc_size_of = "Rc"]
+    listener: Rc<EventListener>,
+    phase: ListenerPhase,
+}
+
+impl EventListenerAbortAlgorithm {
+    pub fn run(&self) {
+        self.target.remove_listener(&self.ty, &self.listener, self.phase);
+    }
+}
@@ -9,7 +9,7 @@ use dom::bindings::error::{Error, Fallible};
 use dom::bindings::inheritance::Castable;
 use dom::bindings::reflector::reflect_dom_object;
 use dom::bindings::root::DomRoot;
-use dom::bindings::str::DOMString;
+use dom::bindings::str::{DOMString, USVString};
 use dom::blob::{Blob, BlobImpl, blob_parts_to_bytes};
 use dom::globalscope::GlobalScope;
 use dom::window::Window;
@@ -22,12 +22,14 @@ pub struct File {
     blob: Blob,
     name: DOMString,
     modified: i64,
+    relative_path: DOMString,
 }
 
 impl File {
     #[allow(unrooted_must_root)]
     fn new_inherited(blob_impl: BlobImpl, name: DOMString,
-                     modified: Option<i64>, type_string: &str) -> File {
+                     modified: Option<i64>, type_string: &str,
+                     relative_path: DOMString) -> File {
         File {
             blob: Blob::new_inherited(blob_impl, type_string.to_owned()),
             name: name,
@@ -39,23 +41,34 @@ impl File {
                     time.sec * 1000 + (time.nsec / 1000000) as i64
                 }
             },
+            relative_path: relative_path,
         }
     }
 
     #[allow(unrooted_must_root)]
     pub fn new(global: &GlobalScope, blob_impl: BlobImpl,
                name: DOMString, modified: Option<i64>, typeString: &str) -> DomRoot<File> {
-        reflect_dom_object(Box::new(File::new_inherited(blob_impl, name, modified, typeString)),
+        reflect_dom_object(Box::new(File::new_inherited(blob_impl, name, modified, typeString,
+                                                     DOMString::new())),
                            global,
                            FileBinding::Wrap)
     }
 
     // Construct from selected file message from file manager thread
+    #[allow(unrooted_must_root)]
     pub fn new_from_selected(window: &Window, selected: SelectedFile) -> DomRoot<File> {
         let name = DOMString::from(selected.filename.to_str().expect("File name encoding error"));
+        let relative_path = selected.relative_path.as_ref().map_or(DOMString::new(), |path| {
+            // Relative paths always use "/" as the separator, whatever the platform.
+            let components: Vec<_> = path.iter().map(|c| c.to_string_lossy()).collect();
+            DOMString::from(components.join("/"))
+        });
 
-        File::new(window.upcast(), BlobImpl::new_from_file(selected.id, selected.filename, selected.size),
-                  name, Some(selected.modified as i64), &selected.type_string)
+        let blob_impl = BlobImpl::new_from_file(selected.id, selected.filename, selected.size);
+        reflect_dom_object(Box::new(File::new_inherited(blob_impl, name, Some(selected.modified as i64),
+                                                        &selected.type_string, relative_path)),
+                           window.upcast::<GlobalScope>(),
+                           FileBinding::Wrap)
     }
 
     // https://w3c.github.io/FileAPI/#file-constructor
@@ -98,4 +111,9 @@ impl FileMethods for File {
     fn LastModified(&self) -> i64 {
         self.modified
     }
+
+    // https://wicg.github.io/entries-api/#dom-file-webkitrelativepath
+    fn WebkitRelativePath(&self) -> USVString {
+        USVString(self.relative_path.to_string())
+    }
 }
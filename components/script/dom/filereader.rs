@@ -192,10 +192,14 @@ impl FileReader {
         // Step 8.2
 
         match data.function {
-            FileReaderFunction::ReadAsDataUrl =>
-                FileReader::perform_readasdataurl(&fr.result, data, &blob_contents),
-            FileReaderFunction::ReadAsText =>
-                FileReader::perform_readastext(&fr.result, data, &blob_contents),
+            FileReaderFunction::ReadAsDataUrl => {
+                let output = FileReader::perform_readasdataurl(&data.blobtype, &blob_contents);
+                *fr.result.borrow_mut() = Some(FileReaderResult::String(DOMString::from(output)));
+            },
+            FileReaderFunction::ReadAsText => {
+                let output = FileReader::perform_readastext(&data.blobtype, &data.label, &blob_contents);
+                *fr.result.borrow_mut() = Some(FileReaderResult::String(DOMString::from(output)));
+            },
             FileReaderFunction::ReadAsArrayBuffer => {
                 let _ac = JSAutoCompartment::new(fr.global().get_cx(), *fr.reflector().get_jsobject());
                 FileReader::perform_readasarraybuffer(&fr.result, fr.global().get_cx(), data, &blob_contents)
@@ -215,10 +219,7 @@ impl FileReader {
     }
 
     // https://w3c.github.io/FileAPI/#dfn-readAsText
-    fn perform_readastext(result: &DomRefCell<Option<FileReaderResult>>, data: ReadMetaData, blob_bytes: &[u8]) {
-        let blob_label = &data.label;
-        let blob_type = &data.blobtype;
-
+    pub fn perform_readastext(blob_type: &str, blob_label: &Option<String>, blob_bytes: &[u8]) -> String {
         //https://w3c.github.io/FileAPI/#encoding-determination
         // Steps 1 & 2 & 3
         let mut encoding = blob_label.as_ref()
@@ -241,20 +242,18 @@ impl FileReader {
         let convert = blob_bytes;
         // Step 7
         let (output, _, _) = enc.decode(convert);
-        *result.borrow_mut() = Some(FileReaderResult::String(DOMString::from(output)));
+        output.into_owned()
     }
 
     //https://w3c.github.io/FileAPI/#dfn-readAsDataURL
-    fn perform_readasdataurl(result: &DomRefCell<Option<FileReaderResult>>, data: ReadMetaData, bytes: &[u8]) {
+    pub fn perform_readasdataurl(blob_type: &str, bytes: &[u8]) -> String {
         let base64 = base64::encode(bytes);
 
-        let output = if data.blobtype.is_empty() {
+        if blob_type.is_empty() {
             format!("data:base64,{}", base64)
         } else {
-            format!("data:{};base64,{}", data.blobtype, base64)
-        };
-
-        *result.borrow_mut() = Some(FileReaderResult::String(DOMString::from(output)));
+            format!("data:{};base64,{}", blob_type, base64)
+        }
     }
 
     // https://w3c.github.io/FileAPI/#dfn-readAsArrayBuffer
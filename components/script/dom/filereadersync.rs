@@ -2,13 +2,21 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use dom::bindings::codegen::Bindings::BlobBinding::BlobMethods;
 use dom::bindings::codegen::Bindings::FileReaderSyncBinding;
-use dom::bindings::error::Fallible;
+use dom::bindings::codegen::Bindings::FileReaderSyncBinding::FileReaderSyncMethods;
+use dom::bindings::error::{Error, Fallible};
 use dom::bindings::reflector::reflect_dom_object;
 use dom::bindings::root::DomRoot;
+use dom::bindings::str::DOMString;
+use dom::blob::Blob;
 use dom::eventtarget::EventTarget;
+use dom::filereader::FileReader;
 use dom::globalscope::GlobalScope;
 use dom_struct::dom_struct;
+use js::jsapi::{JSContext, JSObject};
+use js::typedarray::{ArrayBuffer, CreateWith};
+use std::ptr::{self, NonNull};
 
 #[dom_struct]
 pub struct FileReaderSync {
@@ -30,4 +38,55 @@ impl FileReaderSync {
     pub fn Constructor(global: &GlobalScope) -> Fallible<DomRoot<FileReaderSync>> {
         Ok(FileReaderSync::new(global))
     }
+
+    /// Reads the whole blob, blocking until it is done.
+    /// https://w3c.github.io/FileAPI/#readOperationSync
+    fn get_blob_bytes(blob: &Blob) -> Fallible<Vec<u8>> {
+        blob.get_bytes().map_err(|_| Error::NotReadable)
+    }
+}
+
+impl FileReaderSyncMethods for FileReaderSync {
+    #[allow(unsafe_code)]
+    // https://w3c.github.io/FileAPI/#readAsArrayBufferSyncSection
+    unsafe fn ReadAsArrayBuffer(&self, cx: *mut JSContext, blob: &Blob) -> Fallible<NonNull<JSObject>> {
+        // Step 1.
+        let blob_contents = FileReaderSync::get_blob_bytes(blob)?;
+
+        // Step 2.
+        rooted!(in(cx) let mut array_buffer = ptr::null_mut::<JSObject>());
+        assert!(ArrayBuffer::create(cx, CreateWith::Slice(&blob_contents), array_buffer.handle_mut()).is_ok());
+
+        Ok(NonNull::new_unchecked(array_buffer.get()))
+    }
+
+    // https://w3c.github.io/FileAPI/#readAsBinaryStringSyncSection
+    fn ReadAsBinaryString(&self, blob: &Blob) -> Fallible<DOMString> {
+        // Step 1.
+        let blob_contents = FileReaderSync::get_blob_bytes(blob)?;
+
+        // Step 2.
+        Ok(DOMString::from(blob_contents.iter().map(|&byte| byte as char).collect::<String>()))
+    }
+
+    // https://w3c.github.io/FileAPI/#readAsTextSync
+    fn ReadAsText(&self, blob: &Blob, label: Option<DOMString>) -> Fallible<DOMString> {
+        // Step 1.
+        let blob_contents = FileReaderSync::get_blob_bytes(blob)?;
+
+        // Step 2.
+        let blob_label = label.map(String::from);
+        let output = FileReader::perform_readastext(&blob.Type(), &blob_label, &blob_contents);
+        Ok(DOMString::from(output))
+    }
+
+    // https://w3c.github.io/FileAPI/#readAsDataURLSync-section
+    fn ReadAsDataURL(&self, blob: &Blob) -> Fallible<DOMString> {
+        // Step 1.
+        let blob_contents = FileReaderSync::get_blob_bytes(blob)?;
+
+        // Step 2.
+        let output = FileReader::perform_readasdataurl(&blob.Type(), &blob_contents);
+        Ok(DOMString::from(output))
+    }
 }
@@ -13,6 +13,7 @@ use dom::bindings::reflector::DomObject;
 use dom::bindings::root::{DomRoot, MutNullableDom};
 use dom::bindings::settings_stack::{AutoEntryScript, entry_global, incumbent_global};
 use dom::bindings::str::DOMString;
+use dom::bindings::weakref::{WeakRef, WeakRefVec};
 use dom::crypto::Crypto;
 use dom::dedicatedworkerglobalscope::DedicatedWorkerGlobalScope;
 use dom::errorevent::ErrorEvent;
@@ -20,6 +21,7 @@ use dom::event::{Event, EventBubbles, EventCancelable, EventStatus};
 use dom::eventtarget::EventTarget;
 use dom::performance::Performance;
 use dom::window::Window;
+use dom::worker::Worker;
 use dom::workerglobalscope::WorkerGlobalScope;
 use dom::workletglobalscope::WorkletGlobalScope;
 use dom_struct::dom_struct;
@@ -129,6 +131,9 @@ pub struct GlobalScope {
     /// Vector storing closing references of all workers
     #[ignore_malloc_size_of = "Arc"]
     list_auto_close_worker: DomRefCell<Vec<AutoCloseWorker>>,
+
+    /// Weak references to the dedicated workers created by this global.
+    workers: DomRefCell<WeakRefVec<Worker>>,
 }
 
 impl GlobalScope {
@@ -162,11 +167,21 @@ impl GlobalScope {
             origin,
             microtask_queue,
             list_auto_close_worker: Default::default(),
+            workers: DomRefCell::new(WeakRefVec::new()),
         }
     }
 
-    pub fn track_worker(&self, closing_worker: Arc<AtomicBool>) {
-       self.list_auto_close_worker.borrow_mut().push(AutoCloseWorker(closing_worker));
+    pub fn track_worker(&self, closing_worker: Arc<AtomicBool>, worker: &Worker) {
+        self.list_auto_close_worker.borrow_mut().push(AutoCloseWorker(closing_worker));
+        self.workers.borrow_mut().push(WeakRef::new(worker));
+    }
+
+    /// Tells the dedicated workers created by this global that the user's
+    /// preferred languages have changed.
+    pub fn notify_workers_of_languages_change(&self) {
+        self.workers.borrow_mut().update(|worker| {
+            worker.root().unwrap().languages_changed();
+        });
     }
 
     /// Returns the global scope of the realm that the given DOM object's reflector
@@ -461,6 +476,10 @@ impl GlobalScope {
         self.timers.unschedule_callback(handle);
     }
 
+    pub fn time_until_next_timer(&self) -> Option<MsDuration> {
+        self.timers.time_until_next_timer()
+    }
+
     pub fn set_timeout_or_interval(
             &self,
             callback: TimerCallback,
@@ -2,10 +2,12 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use dom::bindings::cell::DomRefCell;
 use dom::bindings::codegen::Bindings::HistoryBinding;
 use dom::bindings::codegen::Bindings::HistoryBinding::HistoryMethods;
+use dom::bindings::codegen::Bindings::HistoryBinding::ScrollRestoration;
 use dom::bindings::codegen::Bindings::LocationBinding::LocationBinding::LocationMethods;
-use dom::bindings::codegen::Bindings::WindowBinding::WindowMethods;
+use dom::bindings::codegen::Bindings::WindowBinding::{ScrollBehavior, WindowMethods};
 use dom::bindings::error::{Error, ErrorResult, Fallible};
 use dom::bindings::inheritance::Castable;
 use dom::bindings::reflector::{DomObject, Reflector, reflect_dom_object};
@@ -27,12 +29,31 @@ use profile_traits::ipc::channel;
 use script_traits::ScriptMsg;
 use servo_url::ServoUrl;
 use std::cell::Cell;
+use std::collections::HashMap;
 
 enum PushOrReplace {
     Push,
     Replace,
 }
 
+/// The parts of a session history entry of this document that script keeps track of.
+#[derive(Clone, Copy, JSTraceable, MallocSizeOf)]
+struct EntryState {
+    /// <https://html.spec.whatwg.org/multipage/#scroll-restoration-mode>
+    scroll_restoration: ScrollRestoration,
+    /// The scroll position persisted when the entry was last left.
+    scroll_position: Option<(f64, f64)>,
+}
+
+impl Default for EntryState {
+    fn default() -> EntryState {
+        EntryState {
+            scroll_restoration: ScrollRestoration::Auto,
+            scroll_position: None,
+        }
+    }
+}
+
 // https://html.spec.whatwg.org/multipage/#the-history-interface
 #[dom_struct]
 pub struct History {
@@ -40,6 +61,9 @@ pub struct History {
     window: Dom<Window>,
     state: Heap<JSVal>,
     state_id: Cell<Option<HistoryStateId>>,
+    /// The state of each session history entry of this document, keyed by its state id.
+    /// The entry the document was loaded into has no state id.
+    entries: DomRefCell<HashMap<Option<HistoryStateId>, EntryState>>,
 }
 
 impl History {
@@ -51,6 +75,7 @@ impl History {
             window: Dom::from_ref(&window),
             state: state,
             state_id: Cell::new(None),
+            entries: DomRefCell::new(HashMap::new()),
         }
     }
 
@@ -73,6 +98,7 @@ impl History {
 
     #[allow(unsafe_code)]
     pub fn activate_state(&self, state_id: Option<HistoryStateId>) {
+        self.persist_scroll_position();
         self.state_id.set(state_id);
         let serialized_data = match state_id {
             Some(state_id) => {
@@ -98,12 +124,39 @@ impl History {
             }
         }
 
+        // https://html.spec.whatwg.org/multipage/#restore-persisted-user-state
+        let entry = self.current_entry();
+        if entry.scroll_restoration == ScrollRestoration::Auto {
+            if let Some((x, y)) = entry.scroll_position {
+                self.window.scroll(x, y, ScrollBehavior::Auto);
+            }
+        }
+
         unsafe {
             PopStateEvent::dispatch_jsval(self.window.upcast::<EventTarget>(), &*self.window, self.state.handle());
         }
     }
 
+    fn current_entry(&self) -> EntryState {
+        self.entries.borrow().get(&self.state_id.get()).cloned().unwrap_or_default()
+    }
+
+    /// <https://html.spec.whatwg.org/multipage/#persisted-user-state>
+    fn persist_scroll_position(&self) {
+        let position = (self.window.ScrollX() as f64, self.window.ScrollY() as f64);
+        self.entries.borrow_mut()
+            .entry(self.state_id.get())
+            .or_insert_with(EntryState::default)
+            .scroll_position = Some(position);
+    }
+
     pub fn remove_states(&self, states: Vec<HistoryStateId>) {
+        {
+            let mut entries = self.entries.borrow_mut();
+            for state_id in &states {
+                entries.remove(&Some(*state_id));
+            }
+        }
         let _ = self.window
             .upcast::<GlobalScope>()
             .resource_threads()
@@ -173,8 +226,15 @@ impl History {
         // Step 8
         let state_id = match push_or_replace {
             PushOrReplace::Push => {
+                // The new entry inherits the scroll restoration mode of the current one.
+                let scroll_restoration = self.current_entry().scroll_restoration;
+                self.persist_scroll_position();
                 let state_id = HistoryStateId::new();
                 self.state_id.set(Some(state_id));
+                self.entries.borrow_mut().insert(Some(state_id), EntryState {
+                    scroll_restoration: scroll_restoration,
+                    scroll_position: None,
+                });
                 let msg = ScriptMsg::PushHistoryState(state_id);
                 let _ = self.window.upcast::<GlobalScope>().script_to_constellation_chan().send(msg);
                 state_id
@@ -185,6 +245,10 @@ impl History {
                     None => {
                         let state_id = HistoryStateId::new();
                         self.state_id.set(Some(state_id));
+                        let mut entries = self.entries.borrow_mut();
+                        if let Some(entry) = entries.remove(&None) {
+                            entries.insert(Some(state_id), entry);
+                        }
                         state_id
                     },
                 };
@@ -231,6 +295,26 @@ impl HistoryMethods for History {
         Ok(self.state.get())
     }
 
+    // https://html.spec.whatwg.org/multipage/#dom-history-scroll-restoration
+    fn GetScrollRestoration(&self) -> Fallible<ScrollRestoration> {
+        if !self.window.Document().is_fully_active() {
+            return Err(Error::Security);
+        }
+        Ok(self.current_entry().scroll_restoration)
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-history-scroll-restoration
+    fn SetScrollRestoration(&self, value: ScrollRestoration) -> ErrorResult {
+        if !self.window.Document().is_fully_active() {
+            return Err(Error::Security);
+        }
+        self.entries.borrow_mut()
+            .entry(self.state_id.get())
+            .or_insert_with(EntryState::default)
+            .scroll_restoration = value;
+        Ok(())
+    }
+
     // https://html.spec.whatwg.org/multipage/#dom-history-length
     fn GetLength(&self) -> Fallible<u32> {
         if !self.window.Document().is_fully_active() {
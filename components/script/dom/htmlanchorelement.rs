@@ -28,6 +28,7 @@ use dom_struct::dom_struct;
 use html5ever::{LocalName, Prefix};
 use net_traits::ReferrerPolicy;
 use num_traits::ToPrimitive;
+use servo_atoms::Atom;
 use servo_url::ServoUrl;
 use std::default::Default;
 use style::attr::AttrValue;
@@ -121,7 +122,14 @@ impl HTMLAnchorElementMethods for HTMLAnchorElement {
 
     // https://html.spec.whatwg.org/multipage/#dom-a-rellist
     fn RelList(&self) -> DomRoot<DOMTokenList> {
-        self.rel_list.or_init(|| DOMTokenList::new(self.upcast(), &local_name!("rel")))
+        self.rel_list.or_init(|| {
+            // Following a hyperlink never gives the target an opener, so
+            // `noopener` is always honoured.
+            DOMTokenList::new(self.upcast(), &local_name!("rel"), Some(vec![
+                Atom::from("noopener"),
+                Atom::from("noreferrer"),
+            ]))
+        })
     }
 
     // https://html.spec.whatwg.org/multipage/#dom-a-coords
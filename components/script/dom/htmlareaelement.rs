@@ -22,6 +22,7 @@ use dom_struct::dom_struct;
 use euclid::Point2D;
 use html5ever::{LocalName, Prefix};
 use net_traits::ReferrerPolicy;
+use servo_atoms::Atom;
 use std::default::Default;
 use std::f32;
 use std::str;
@@ -275,7 +276,11 @@ impl HTMLAreaElementMethods for HTMLAreaElement {
     // https://html.spec.whatwg.org/multipage/#dom-area-rellist
     fn RelList(&self) -> DomRoot<DOMTokenList> {
         self.rel_list.or_init(|| {
-            DOMTokenList::new(self.upcast(), &local_name!("rel"))
+            // Same supported tokens as HTMLAnchorElement::RelList.
+            DOMTokenList::new(self.upcast(), &local_name!("rel"), Some(vec![
+                Atom::from("noopener"),
+                Atom::from("noreferrer"),
+            ]))
         })
     }
 }
@@ -170,33 +170,9 @@ impl VirtualMethods for HTMLBodyElement {
     }
 
     fn attribute_mutated(&self, attr: &Attr, mutation: AttributeMutation) {
-        let do_super_mutate = match (attr.local_name(), mutation) {
-            (name, AttributeMutation::Set(_)) if name.starts_with("on") => {
-                let window = window_from_node(self);
-                // https://html.spec.whatwg.org/multipage/
-                // #event-handlers-on-elements,-document-objects,-and-window-objects:event-handlers-3
-                match name {
-                    &local_name!("onfocus") | &local_name!("onload") | &local_name!("onscroll") |
-                    &local_name!("onafterprint") | &local_name!("onbeforeprint") |
-                    &local_name!("onbeforeunload") | &local_name!("onhashchange") |
-                    &local_name!("onlanguagechange") | &local_name!("onmessage") |
-                    &local_name!("onoffline") | &local_name!("ononline") |
-                    &local_name!("onpagehide") | &local_name!("onpageshow") |
-                    &local_name!("onpopstate") | &local_name!("onstorage") |
-                    &local_name!("onresize") | &local_name!("onunload") | &local_name!("onerror")
-                      => {
-                          let evtarget = window.upcast::<EventTarget>(); // forwarded event
-                          let source_line = 1; //TODO(#9604) obtain current JS execution line
-                          evtarget.set_event_handler_uncompiled(window.get_url(),
-                                                                source_line,
-                                                                &name[2..],
-                                                                DOMString::from((**attr.value()).to_owned()));
-                          false
-                    }
-                    _ => true, // HTMLElement::attribute_mutated will take care of this.
-                }
-            },
-            _ => true,
+        let do_super_mutate = match mutation {
+            AttributeMutation::Set(_) => !forward_event_handler_attribute_to_window(self.upcast(), attr),
+            AttributeMutation::Removed => true,
         };
 
         if do_super_mutate {
@@ -204,3 +180,33 @@ impl VirtualMethods for HTMLBodyElement {
         }
     }
 }
+
+/// Registers the event handler content attributes of `<body>` and `<frameset>`
+/// that are forwarded to the window as handlers on that window. Returns whether
+/// the attribute was forwarded; all other event handlers stay on the element.
+///
+/// <https://html.spec.whatwg.org/multipage/
+/// #event-handlers-on-elements,-document-objects,-and-window-objects:event-handlers-3>
+pub fn forward_event_handler_attribute_to_window(element: &HTMLElement, attr: &Attr) -> bool {
+    match *attr.local_name() {
+        local_name!("onblur") | local_name!("onerror") | local_name!("onfocus") |
+        local_name!("onload") | local_name!("onresize") | local_name!("onscroll") |
+        local_name!("onafterprint") | local_name!("onbeforeprint") |
+        local_name!("onbeforeunload") | local_name!("onhashchange") |
+        local_name!("onlanguagechange") | local_name!("onmessage") |
+        local_name!("onoffline") | local_name!("ononline") |
+        local_name!("onpagehide") | local_name!("onpageshow") |
+        local_name!("onpopstate") | local_name!("onstorage") |
+        local_name!("onunload") => {
+            let window = window_from_node(element);
+            let evtarget = window.upcast::<EventTarget>(); // forwarded event
+            let source_line = 1; //TODO(#9604) obtain current JS execution line
+            evtarget.set_event_handler_uncompiled(window.get_url(),
+                                                  source_line,
+                                                  &attr.local_name()[2..],
+                                                  DOMString::from((**attr.value()).to_owned()));
+            true
+        },
+        _ => false, // HTMLElement::attribute_mutated will take care of this.
+    }
+}
@@ -3,60 +3,131 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use base64;
-use canvas_traits::canvas::{CanvasMsg, CanvasId, FromScriptMsg};
+use canvas_traits::canvas::{Canvas2dMsg, CanvasMsg, CanvasId, FromScriptMsg};
 use canvas_traits::webgl::WebGLVersion;
 use dom::attr::Attr;
+use dom::bindings::callback::ExceptionHandling;
 use dom::bindings::cell::DomRefCell;
 use dom::bindings::codegen::Bindings::CanvasRenderingContext2DBinding::CanvasRenderingContext2DMethods;
 use dom::bindings::codegen::Bindings::HTMLCanvasElementBinding;
-use dom::bindings::codegen::Bindings::HTMLCanvasElementBinding::{HTMLCanvasElementMethods, RenderingContext};
+use dom::bindings::codegen::Bindings::HTMLCanvasElementBinding::{BlobCallback, HTMLCanvasElementMethods};
+use dom::bindings::codegen::Bindings::HTMLCanvasElementBinding::RenderingContext;
 use dom::bindings::codegen::Bindings::WebGLRenderingContextBinding::WebGLContextAttributes;
 use dom::bindings::conversions::ConversionResult;
-use dom::bindings::error::{Error, Fallible};
+use dom::bindings::error::{Error, ErrorResult, Fallible};
 use dom::bindings::inheritance::Castable;
 use dom::bindings::num::Finite;
+use dom::bindings::refcounted::Trusted;
 use dom::bindings::reflector::DomObject;
-use dom::bindings::root::{Dom, DomRoot, LayoutDom};
+use dom::bindings::root::{Dom, DomRoot, LayoutDom, RootedReference};
 use dom::bindings::str::DOMString;
+use dom::blob::{Blob, BlobImpl};
 use dom::canvasrenderingcontext2d::{CanvasRenderingContext2D, LayoutCanvasRenderingContext2DHelpers};
 use dom::document::Document;
 use dom::element::{AttributeMutation, Element, RawLayoutElementHelpers};
 use dom::globalscope::GlobalScope;
 use dom::htmlelement::HTMLElement;
-use dom::node::{Node, window_from_node};
+use dom::node::{Node, NodeDamage, window_from_node};
+use dom::offscreencanvas::{OffscreenCanvas, OffscreenCanvasPlaceholder};
 use dom::virtualmethods::VirtualMethods;
 use dom::webgl2renderingcontext::WebGL2RenderingContext;
 use dom::webglrenderingcontext::{LayoutCanvasWebGLRenderingContextHelpers, WebGLRenderingContext};
 use dom_struct::dom_struct;
-use euclid::Size2D;
+use euclid::{Point2D, Rect, Size2D, Vector2D};
 use html5ever::{LocalName, Prefix};
 use image::ColorType;
+use image::jpeg::JPEGEncoder;
 use image::png::PNGEncoder;
+use ipc_channel::ipc::IpcSender;
 use js::error::throw_type_error;
 use js::jsapi::JSContext;
 use js::rust::HandleValue;
 use offscreen_gl_context::GLContextAttributes;
 use profile_traits::ipc;
 use script_layout_interface::{HTMLCanvasData, HTMLCanvasDataSource};
+use script_traits::ScriptMsg;
 use servo_config::prefs::PREFS;
+use std::cell::Cell;
+use std::collections::HashMap;
 use std::iter::repeat;
+use std::rc::Rc;
 use style::attr::{AttrValue, LengthOrPercentageOrAuto};
+use task_source::TaskSource;
 
 const DEFAULT_WIDTH: u32 = 300;
 const DEFAULT_HEIGHT: u32 = 150;
 
+/// The quality used for lossy formats when none, or an invalid one, is given.
+const DEFAULT_JPEG_QUALITY: u8 = 92;
+
+/// The image formats a canvas bitmap can be serialised to.
+/// <https://html.spec.whatwg.org/multipage/#a-serialisation-of-the-bitmap-as-a-file>
+#[derive(Clone, Copy)]
+enum EncodedImageType {
+    Png,
+    Jpeg(u8),
+}
+
+impl EncodedImageType {
+    /// Picks the format for a `type` argument, falling back to PNG for
+    /// unsupported types, and reads the quality from the first of `arguments`.
+    fn new(mime_type: Option<DOMString>, arguments: &[HandleValue]) -> EncodedImageType {
+        let mime_type = mime_type.map(|mime_type| mime_type.to_ascii_lowercase());
+        match mime_type.as_ref().map(|mime_type| &**mime_type) {
+            Some("image/jpeg") => {
+                let quality = arguments.get(0).and_then(|quality| {
+                    let quality = quality.get();
+                    if quality.is_double() {
+                        Some(quality.to_double())
+                    } else if quality.is_int32() {
+                        Some(quality.to_int32() as f64)
+                    } else {
+                        None
+                    }
+                }).and_then(|quality| {
+                    if quality >= 0.0 && quality <= 1.0 {
+                        Some((quality * 100.0).round() as u8)
+                    } else {
+                        None
+                    }
+                });
+                EncodedImageType::Jpeg(quality.unwrap_or(DEFAULT_JPEG_QUALITY).max(1))
+            },
+            _ => EncodedImageType::Png,
+        }
+    }
+
+    fn mime_type(&self) -> &'static str {
+        match *self {
+            EncodedImageType::Png => "image/png",
+            EncodedImageType::Jpeg(_) => "image/jpeg",
+        }
+    }
+}
+
 #[must_root]
 #[derive(Clone, JSTraceable, MallocSizeOf)]
 pub enum CanvasContext {
     Context2d(Dom<CanvasRenderingContext2D>),
     WebGL(Dom<WebGLRenderingContext>),
     WebGL2(Dom<WebGL2RenderingContext>),
+    /// Rendering has been handed over to an OffscreenCanvas.
+    Placeholder(Dom<OffscreenCanvas>),
 }
 
 #[dom_struct]
 pub struct HTMLCanvasElement {
     htmlelement: HTMLElement,
     context: DomRefCell<Option<CanvasContext>>,
+    /// Callbacks passed to `toBlob` that are waiting for their blob.
+    #[ignore_malloc_size_of = "can't measure Rc values"]
+    blob_callbacks: DomRefCell<HashMap<u32, Rc<BlobCallback>>>,
+    next_blob_callback_id: Cell<u32>,
+    /// A canvas owned by this element holding a copy of the bitmap last
+    /// committed by the OffscreenCanvas this element is a placeholder for,
+    /// along with its size.
+    #[ignore_malloc_size_of = "Defined in ipc-channel"]
+    placeholder_bitmap: DomRefCell<Option<(IpcSender<CanvasMsg>, CanvasId, Size2D<i32>)>>,
 }
 
 impl HTMLCanvasElement {
@@ -66,6 +137,9 @@ impl HTMLCanvasElement {
         HTMLCanvasElement {
             htmlelement: HTMLElement::new_inherited(local_name, prefix, document),
             context: DomRefCell::new(None),
+            blob_callbacks: DomRefCell::new(HashMap::new()),
+            next_blob_callback_id: Cell::new(0),
+            placeholder_bitmap: DomRefCell::new(None),
         }
     }
 
@@ -85,6 +159,7 @@ impl HTMLCanvasElement {
                 CanvasContext::Context2d(ref context) => context.set_bitmap_dimensions(size),
                 CanvasContext::WebGL(ref context) => context.recreate(size),
                 CanvasContext::WebGL2(ref context) => context.recreate(size),
+                CanvasContext::Placeholder(_) => {},
             }
         }
     }
@@ -99,6 +174,72 @@ impl HTMLCanvasElement {
             _ => true,
         }
     }
+
+    /// Reads back the canvas bitmap as non-premultiplied RGBA pixels.
+    /// Returns None if the pixels of a WebGL context couldn't be read.
+    fn get_bitmap_data(&self) -> Fallible<Option<Vec<u8>>> {
+        let data = match *self.context.borrow() {
+            Some(CanvasContext::Context2d(ref context)) => {
+                let image_data = context.GetImageData(Finite::wrap(0f64), Finite::wrap(0f64),
+                                                      Finite::wrap(self.Width() as f64),
+                                                      Finite::wrap(self.Height() as f64))?;
+                Some(image_data.get_data_array())
+            }
+            Some(CanvasContext::WebGL(ref context)) => {
+                context.get_image_data(self.Width(), self.Height())
+            }
+            Some(CanvasContext::WebGL2(ref context)) => {
+                context.base_context().get_image_data(self.Width(), self.Height())
+            }
+            Some(CanvasContext::Placeholder(_)) => return Err(Error::InvalidState),
+            None => {
+                // Each pixel is fully-transparent black.
+                Some(vec![0; (self.Width() * self.Height() * 4) as usize])
+            }
+        };
+        Ok(data)
+    }
+
+    /// <https://html.spec.whatwg.org/multipage/#a-serialisation-of-the-bitmap-as-a-file>
+    /// Returns None if the encoder failed, which the spec calls null.
+    fn encode_bitmap(&self, data: &[u8], image_type: EncodedImageType) -> Option<Vec<u8>> {
+        let mut encoded = Vec::new();
+        let result = match image_type {
+            EncodedImageType::Png => {
+                let encoder: PNGEncoder<&mut Vec<u8>> = PNGEncoder::new(&mut encoded);
+                encoder.encode(data, self.Width(), self.Height(), ColorType::RGBA(8))
+            },
+            EncodedImageType::Jpeg(quality) => {
+                // JPEG has no alpha channel, so composite the bitmap onto opaque black.
+                let rgb: Vec<u8> = data.chunks(4).flat_map(|pixel| {
+                    let alpha = pixel[3] as u32;
+                    pixel[..3].iter().map(move |channel| (*channel as u32 * alpha / 255) as u8)
+                }).collect();
+                let mut encoder = JPEGEncoder::new_with_quality(&mut encoded, quality);
+                encoder.encode(&rgb, self.Width(), self.Height(), ColorType::RGB(8))
+            },
+        };
+        match result {
+            Ok(()) => Some(encoded),
+            Err(err) => {
+                warn!("Failed to encode the canvas bitmap ({:?}).", err);
+                None
+            },
+        }
+    }
+
+    /// Invokes the `toBlob` callback registered under `id` with a blob
+    /// holding `encoded`, or with null if the bitmap couldn't be serialised.
+    fn resolve_blob_callback(&self, id: u32, encoded: Option<(Vec<u8>, &'static str)>) {
+        let callback = match self.blob_callbacks.borrow_mut().remove(&id) {
+            Some(callback) => callback,
+            None => return,
+        };
+        let blob = encoded.map(|(bytes, mime_type)| {
+            Blob::new(&self.global(), BlobImpl::new_from_bytes(bytes), mime_type.to_owned())
+        });
+        let _ = callback.Call__(blob.r(), ExceptionHandling::Report);
+    }
 }
 
 pub trait LayoutHTMLCanvasElementHelpers {
@@ -123,9 +264,11 @@ impl LayoutHTMLCanvasElementHelpers for LayoutDom<HTMLCanvasElement> {
                 Some(&CanvasContext::WebGL2(ref context)) => {
                     context.to_layout().canvas_data_source()
                 },
-                None => {
-                    HTMLCanvasDataSource::Image(None)
-                }
+                Some(&CanvasContext::Placeholder(_)) => {
+                    let bitmap = canvas.placeholder_bitmap.borrow_for_layout();
+                    HTMLCanvasDataSource::Image(bitmap.as_ref().map(|&(ref ipc_renderer, _, _)| ipc_renderer.clone()))
+                },
+                None => HTMLCanvasDataSource::Image(None),
             };
 
             let width_attr = canvas.upcast::<Element>().get_attr_for_layout(&ns!(), &local_name!("width"));
@@ -163,10 +306,13 @@ impl LayoutHTMLCanvasElementHelpers for LayoutDom<HTMLCanvasElement> {
     fn get_canvas_id_for_layout(&self) -> CanvasId {
         unsafe {
             let canvas = &*self.unsafe_get();
-            if let &Some(CanvasContext::Context2d(ref context)) = canvas.context.borrow_for_layout() {
-                context.to_layout().get_canvas_id()
-            } else {
-                CanvasId(0)
+            match *canvas.context.borrow_for_layout() {
+                Some(CanvasContext::Context2d(ref context)) => context.to_layout().get_canvas_id(),
+                Some(CanvasContext::Placeholder(_)) => {
+                    canvas.placeholder_bitmap.borrow_for_layout().as_ref()
+                        .map_or(CanvasId(0), |&(_, ref canvas_id, _)| canvas_id.clone())
+                },
+                _ => CanvasId(0),
             }
         }
     }
@@ -261,6 +407,30 @@ impl HTMLCanvasElement {
         }
     }
 
+    /// Displays the RGBA pixels committed by this placeholder's OffscreenCanvas.
+    ///
+    /// https://html.spec.whatwg.org/multipage/#offscreencanvas-placeholder
+    pub fn set_placeholder_bitmap(&self, pixels: Vec<u8>, size: Size2D<i32>) {
+        let mut bitmap = self.placeholder_bitmap.borrow_mut();
+        if bitmap.is_none() {
+            let global = self.global();
+            let (sender, receiver) = ipc::channel(global.time_profiler_chan().clone()).unwrap();
+            global.script_to_constellation_chan().send(ScriptMsg::CreateCanvasPaintThread(size, sender)).unwrap();
+            let (ipc_renderer, canvas_id) = receiver.recv().unwrap();
+            *bitmap = Some((ipc_renderer, canvas_id, size));
+        }
+
+        let (ref ipc_renderer, ref canvas_id, ref mut bitmap_size) = *bitmap.as_mut().unwrap();
+        if *bitmap_size != size {
+            ipc_renderer.send(CanvasMsg::Recreate(size, canvas_id.clone())).unwrap();
+            *bitmap_size = size;
+        }
+        let size = size.to_f64();
+        let msg = Canvas2dMsg::PutImageData(pixels.into(), Vector2D::zero(), size, Rect::new(Point2D::zero(), size));
+        ipc_renderer.send(CanvasMsg::Canvas2d(msg, canvas_id.clone())).unwrap();
+        self.upcast::<Node>().dirty(NodeDamage::OtherNodeDamage);
+    }
+
     pub fn is_valid(&self) -> bool {
         self.Height() != 0 && self.Width() != 0
     }
@@ -288,6 +458,9 @@ impl HTMLCanvasElement {
                 // TODO: add a method in WebGL2RenderingContext to get the pixels.
                 return None;
             },
+            Some(&CanvasContext::Placeholder(_)) => {
+                return None;
+            },
             None => {
                 repeat(0xffu8).take((size.height as usize) * (size.width as usize) * 4).collect()
             }
@@ -316,8 +489,12 @@ impl HTMLCanvasElementMethods for HTMLCanvasElement {
                   cx: *mut JSContext,
                   id: DOMString,
                   attributes: Vec<HandleValue>)
-        -> Option<RenderingContext> {
-        match &*id {
+        -> Fallible<Option<RenderingContext>> {
+        if let Some(CanvasContext::Placeholder(_)) = *self.context.borrow() {
+            return Err(Error::InvalidState);
+        }
+
+        Ok(match &*id {
             "2d" => {
                 self.get_or_init_2d_context()
                     .map(RenderingContext::CanvasRenderingContext2D)
@@ -331,15 +508,36 @@ impl HTMLCanvasElementMethods for HTMLCanvasElement {
                     .map(RenderingContext::WebGL2RenderingContext)
             }
             _ => None
+        })
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-canvas-transfercontroltooffscreen
+    fn TransferControlToOffscreen(&self) -> Fallible<DomRoot<OffscreenCanvas>> {
+        // Step 1.
+        if self.context.borrow().is_some() {
+            return Err(Error::InvalidState);
         }
+
+        // Step 2-3.
+        let window = window_from_node(self);
+        let offscreen_canvas = OffscreenCanvas::new(window.upcast(),
+                                                    self.Width() as u64,
+                                                    self.Height() as u64,
+                                                    Some(OffscreenCanvasPlaceholder::new(self)));
+
+        // Step 4.
+        *self.context.borrow_mut() = Some(CanvasContext::Placeholder(Dom::from_ref(&*offscreen_canvas)));
+
+        // Step 5.
+        Ok(offscreen_canvas)
     }
 
     #[allow(unsafe_code)]
     // https://html.spec.whatwg.org/multipage/#dom-canvas-todataurl
     unsafe fn ToDataURL(&self,
                  _context: *mut JSContext,
-                 _mime_type: Option<DOMString>,
-                 _arguments: Vec<HandleValue>) -> Fallible<DOMString> {
+                 mime_type: Option<DOMString>,
+                 arguments: Vec<HandleValue>) -> Fallible<DOMString> {
         // Step 1.
         if let Some(CanvasContext::Context2d(ref context)) = *self.context.borrow() {
             if !context.origin_is_clean() {
@@ -353,42 +551,67 @@ impl HTMLCanvasElementMethods for HTMLCanvasElement {
         }
 
         // Step 3.
-        let raw_data = match *self.context.borrow() {
-            Some(CanvasContext::Context2d(ref context)) => {
-                let image_data = context.GetImageData(Finite::wrap(0f64), Finite::wrap(0f64),
-                                                           Finite::wrap(self.Width() as f64),
-                                                           Finite::wrap(self.Height() as f64))?;
-                image_data.get_data_array()
-            }
-            Some(CanvasContext::WebGL(ref context)) => {
-                match context.get_image_data(self.Width(), self.Height()) {
-                    Some(data) => data,
-                    None => return Ok("data:,".into()),
-                }
-            }
-            Some(CanvasContext::WebGL2(ref context)) => {
-                match context.base_context().get_image_data(self.Width(), self.Height()) {
-                    Some(data) => data,
-                    None => return Ok("data:,".into()),
-                }
-            }
-            None => {
-                // Each pixel is fully-transparent black.
-                vec![0; (self.Width() * self.Height() * 4) as usize]
-            }
+        let raw_data = match self.get_bitmap_data()? {
+            Some(data) => data,
+            None => return Ok("data:,".into()),
         };
 
-        // Only handle image/png for now.
-        let mime_type = "image/png";
+        let image_type = EncodedImageType::new(mime_type, &arguments);
+        let encoded = match self.encode_bitmap(&raw_data, image_type) {
+            Some(encoded) => base64::encode(&encoded),
+            None => return Ok("data:,".into()),
+        };
+        Ok(DOMString::from(format!("data:{};base64,{}", image_type.mime_type(), encoded)))
+    }
 
-        let mut encoded = Vec::new();
-        {
-            let encoder: PNGEncoder<&mut Vec<u8>> = PNGEncoder::new(&mut encoded);
-            encoder.encode(&raw_data, self.Width(), self.Height(), ColorType::RGBA(8)).unwrap();
+    #[allow(unsafe_code)]
+    // https://html.spec.whatwg.org/multipage/#dom-canvas-toblob
+    unsafe fn ToBlob(&self,
+                     _context: *mut JSContext,
+                     callback: Rc<BlobCallback>,
+                     mime_type: Option<DOMString>,
+                     arguments: Vec<HandleValue>) -> ErrorResult {
+        // Step 1.
+        if !self.origin_is_clean() {
+            return Err(Error::Security);
         }
 
-        let encoded = base64::encode(&encoded);
-        Ok(DOMString::from(format!("data:{};base64,{}", mime_type, encoded)))
+        // Step 2-3.
+        let result = if self.Width() == 0 || self.Height() == 0 {
+            None
+        } else {
+            self.get_bitmap_data()?
+        };
+
+        // Step 4. The bitmap is serialised right away rather than in parallel,
+        // but the callback is still invoked from a task.
+        let image_type = EncodedImageType::new(mime_type, &arguments);
+        let encoded = result.and_then(|data| self.encode_bitmap(&data, image_type))
+            .map(|bytes| (bytes, image_type.mime_type()));
+
+        let id = self.next_blob_callback_id.get();
+        self.next_blob_callback_id.set(id.wrapping_add(1));
+        self.blob_callbacks.borrow_mut().insert(id, callback);
+
+        let window = window_from_node(self);
+        let this = Trusted::new(self);
+        window.dom_manipulation_task_source().queue(
+            task!(invoke_blob_callback: move || {
+                this.root().resolve_blob_callback(id, encoded);
+            }),
+            window.upcast(),
+        ).unwrap();
+        Ok(())
+    }
+}
+
+impl Drop for HTMLCanvasElement {
+    fn drop(&mut self) {
+        if let Some((ref ipc_renderer, ref canvas_id, _)) = *self.placeholder_bitmap.borrow() {
+            if let Err(err) = ipc_renderer.send(CanvasMsg::Close(canvas_id.clone())) {
+                warn!("Could not close placeholder canvas: {}", err)
+            }
+        }
     }
 }
 
@@ -38,7 +38,7 @@ use script_layout_interface::message::QueryMsg;
 use std::collections::HashSet;
 use std::default::Default;
 use std::rc::Rc;
-use style::attr::AttrValue;
+use style::attr::{AttrValue, parse_integer};
 use style::element_state::*;
 
 #[dom_struct]
@@ -91,9 +91,8 @@ impl HTMLElement {
                     => node.set_flag(NodeFlags::SEQUENTIALLY_FOCUSABLE, true),
                 NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLLinkElement)) |
                 NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLAnchorElement)) => {
-                    if element.has_attribute(&local_name!("href")) {
-                        node.set_flag(NodeFlags::SEQUENTIALLY_FOCUSABLE, true);
-                    }
+                    node.set_flag(NodeFlags::SEQUENTIALLY_FOCUSABLE,
+                                  element.has_attribute(&local_name!("href")));
                 },
                 _ => {
                     if let Some(attr) = element.get_attribute(&ns!(), &local_name!("draggable")) {
@@ -183,7 +182,7 @@ impl HTMLElementMethods for HTMLElement {
         if self.is_body_or_frameset() {
             let document = document_from_node(self);
             if document.has_browsing_context() {
-                document.window().GetOnload()
+                document.window().GetOnresize()
             } else {
                 None
             }
@@ -330,6 +329,10 @@ impl HTMLElementMethods for HTMLElement {
     fn Focus(&self) {
         // TODO: Mark the element as locked for focus and run the focusing steps.
         // https://html.spec.whatwg.org/multipage/#focusing-steps
+        // Step 1.
+        if !self.upcast::<Element>().is_focusable_area() {
+            return;
+        }
         let document = document_from_node(self);
         document.begin_focus_transaction();
         document.request_focus(self.upcast());
@@ -349,6 +352,34 @@ impl HTMLElementMethods for HTMLElement {
         document.commit_focus_transaction(FocusType::Element);
     }
 
+    // https://html.spec.whatwg.org/multipage/#dom-tabindex
+    fn TabIndex(&self) -> i32 {
+        let element = self.upcast::<Element>();
+        let value = element.get_attribute(&ns!(), &local_name!("tabindex"))
+                           .and_then(|attr| parse_integer(attr.value().chars()).ok());
+        if let Some(value) = value {
+            return value;
+        }
+        // Elements that are focusable by default return 0, all others -1.
+        match self.upcast::<Node>().type_id() {
+            NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLAnchorElement)) |
+            NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLAreaElement)) |
+            NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLButtonElement)) |
+            NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLFrameElement)) |
+            NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLIFrameElement)) |
+            NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLInputElement)) |
+            NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLObjectElement)) |
+            NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLSelectElement)) |
+            NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLTextAreaElement)) => 0,
+            _ => -1,
+        }
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-tabindex
+    fn SetTabIndex(&self, value: i32) {
+        self.upcast::<Element>().set_int_attribute(&local_name!("tabindex"), value);
+    }
+
     // https://drafts.csswg.org/cssom-view/#dom-htmlelement-offsetparent
     fn GetOffsetParent(&self) -> Option<DomRoot<Element>> {
         if self.is::<HTMLBodyElement>() || self.is::<HTMLHtmlElement>() {
@@ -544,25 +575,42 @@ fn to_camel_case(name: &str) -> Option<DOMString> {
     Some(DOMString::from(result))
 }
 
+// Whether the name contains a hyphen followed by a lowercase ASCII letter,
+// which could never have come from converting a data-* attribute name.
+fn has_hyphen_before_lowercase(name: &str) -> bool {
+    name.chars()
+        .zip(name.chars().skip(1))
+        .any(|(ch, next)| ch == DATA_HYPHEN_SEPARATOR && is_ascii_lowercase(next))
+}
+
 impl HTMLElement {
     pub fn set_custom_attr(&self, name: DOMString, value: DOMString) -> ErrorResult {
-        if name.chars()
-               .skip_while(|&ch| ch != '\u{2d}')
-               .nth(1).map_or(false, |ch| ch >= 'a' && ch <= 'z') {
+        // Step 1.
+        if has_hyphen_before_lowercase(&name) {
             return Err(Error::Syntax);
         }
+        // Steps 2-5.
         self.upcast::<Element>().set_custom_attribute(to_snake_case(name), value)
     }
 
-    pub fn get_custom_attr(&self, local_name: DOMString) -> Option<DOMString> {
-        // FIXME(ajeffrey): Convert directly from DOMString to LocalName
-        let local_name = LocalName::from(to_snake_case(local_name));
-        self.upcast::<Element>().get_attribute(&ns!(), &local_name).map(|attr| {
+    pub fn get_custom_attr(&self, name: DOMString) -> Option<DOMString> {
+        // Only names coming out of the attribute name conversion are
+        // supported, so "data-foo-bar" can be read as "fooBar" but not as
+        // "foo-bar". Only attributes in the null namespace are data-* attributes.
+        self.upcast::<Element>().attrs().iter().find(|attr| {
+            *attr.namespace() == ns!() &&
+                to_camel_case(attr.local_name()).map_or(false, |camel_case| camel_case == name)
+        }).map(|attr| {
             DOMString::from(&**attr.value()) // FIXME(ajeffrey): Convert directly from AttrValue to DOMString
         })
     }
 
     pub fn delete_custom_attr(&self, local_name: DOMString) {
+        // Step 1.
+        if has_hyphen_before_lowercase(&local_name) {
+            return;
+        }
+        // Steps 2-4.
         // FIXME(ajeffrey): Convert directly from DOMString to LocalName
         let local_name = LocalName::from(to_snake_case(local_name));
         self.upcast::<Element>().remove_attribute(&ns!(), &local_name);
@@ -614,7 +662,7 @@ impl HTMLElement {
 
     pub fn supported_prop_names_custom_attr(&self) -> Vec<DOMString> {
         let element = self.upcast::<Element>();
-        element.attrs().iter().filter_map(|attr| {
+        element.attrs().iter().filter(|attr| *attr.namespace() == ns!()).filter_map(|attr| {
             let raw_name = attr.local_name();
             to_camel_case(&raw_name)
         }).collect()
@@ -677,6 +725,7 @@ impl VirtualMethods for HTMLElement {
                                                       // FIXME(ajeffrey): Convert directly from AttrValue to DOMString
                                                       DOMString::from(&**attr.value()));
             },
+            (&local_name!("tabindex"), _) => self.update_sequentially_focusable_status(),
             _ => {}
         }
     }
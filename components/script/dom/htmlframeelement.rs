@@ -2,23 +2,33 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use dom::bindings::codegen::Bindings::HTMLFrameElementBinding;
-use dom::bindings::root::DomRoot;
+use dom::attr::Attr;
+use dom::bindings::codegen::Bindings::HTMLFrameElementBinding::{self, HTMLFrameElementMethods};
+use dom::bindings::inheritance::Castable;
+use dom::bindings::root::{DomRoot, LayoutDom};
+use dom::bindings::str::DOMString;
 use dom::document::Document;
+use dom::element::AttributeMutation;
 use dom::htmlelement::HTMLElement;
-use dom::node::Node;
+use dom::htmliframeelement::{BrowsingContextContainer, NestedBrowsingContext, ProcessingMode};
+use dom::node::{Node, UnbindContext};
+use dom::virtualmethods::VirtualMethods;
+use dom::windowproxy::WindowProxy;
 use dom_struct::dom_struct;
 use html5ever::{LocalName, Prefix};
+use msg::constellation_msg::{BrowsingContextId, PipelineId};
 
 #[dom_struct]
 pub struct HTMLFrameElement {
-    htmlelement: HTMLElement
+    htmlelement: HTMLElement,
+    nested_browsing_context: NestedBrowsingContext,
 }
 
 impl HTMLFrameElement {
     fn new_inherited(local_name: LocalName, prefix: Option<Prefix>, document: &Document) -> HTMLFrameElement {
         HTMLFrameElement {
-            htmlelement: HTMLElement::new_inherited(local_name, prefix, document)
+            htmlelement: HTMLElement::new_inherited(local_name, prefix, document),
+            nested_browsing_context: NestedBrowsingContext::new(),
         }
     }
 
@@ -31,3 +41,139 @@ impl HTMLFrameElement {
                            HTMLFrameElementBinding::Wrap)
     }
 }
+
+impl BrowsingContextContainer for HTMLFrameElement {
+    fn container_element(&self) -> &HTMLElement {
+        self.upcast()
+    }
+
+    fn nested_browsing_context(&self) -> &NestedBrowsingContext {
+        &self.nested_browsing_context
+    }
+}
+
+pub trait HTMLFrameElementLayoutMethods {
+    fn pipeline_id(&self) -> Option<PipelineId>;
+    fn browsing_context_id(&self) -> Option<BrowsingContextId>;
+}
+
+impl HTMLFrameElementLayoutMethods for LayoutDom<HTMLFrameElement> {
+    #[inline]
+    #[allow(unsafe_code)]
+    fn pipeline_id(&self) -> Option<PipelineId> {
+        unsafe {
+            (*self.unsafe_get()).nested_browsing_context.pipeline_id()
+        }
+    }
+
+    #[inline]
+    #[allow(unsafe_code)]
+    fn browsing_context_id(&self) -> Option<BrowsingContextId> {
+        unsafe {
+            (*self.unsafe_get()).nested_browsing_context.browsing_context_id()
+        }
+    }
+}
+
+impl HTMLFrameElementMethods for HTMLFrameElement {
+    // https://html.spec.whatwg.org/multipage/#dom-frame-name
+    make_getter!(Name, "name");
+
+    // https://html.spec.whatwg.org/multipage/#dom-frame-name
+    make_setter!(SetName, "name");
+
+    // https://html.spec.whatwg.org/multipage/#dom-frame-scrolling
+    make_getter!(Scrolling, "scrolling");
+
+    // https://html.spec.whatwg.org/multipage/#dom-frame-scrolling
+    make_setter!(SetScrolling, "scrolling");
+
+    // https://html.spec.whatwg.org/multipage/#dom-frame-src
+    make_url_getter!(Src, "src");
+
+    // https://html.spec.whatwg.org/multipage/#dom-frame-src
+    make_url_setter!(SetSrc, "src");
+
+    // https://html.spec.whatwg.org/multipage/#dom-frame-frameborder
+    make_getter!(FrameBorder, "frameborder");
+
+    // https://html.spec.whatwg.org/multipage/#dom-frame-frameborder
+    make_setter!(SetFrameBorder, "frameborder");
+
+    // https://html.spec.whatwg.org/multipage/#dom-frame-longdesc
+    make_url_getter!(LongDesc, "longdesc");
+
+    // https://html.spec.whatwg.org/multipage/#dom-frame-longdesc
+    make_url_setter!(SetLongDesc, "longdesc");
+
+    // https://html.spec.whatwg.org/multipage/#dom-frame-noresize
+    make_bool_getter!(NoResize, "noresize");
+
+    // https://html.spec.whatwg.org/multipage/#dom-frame-noresize
+    make_bool_setter!(SetNoResize, "noresize");
+
+    // https://html.spec.whatwg.org/multipage/#dom-frame-contentdocument
+    fn GetContentDocument(&self) -> Option<DomRoot<Document>> {
+        self.content_document()
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-frame-contentwindow
+    fn GetContentWindow(&self) -> Option<DomRoot<WindowProxy>> {
+        self.content_window()
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-frame-marginheight
+    make_getter!(MarginHeight, "marginheight");
+
+    // https://html.spec.whatwg.org/multipage/#dom-frame-marginheight
+    make_setter!(SetMarginHeight, "marginheight");
+
+    // https://html.spec.whatwg.org/multipage/#dom-frame-marginwidth
+    make_getter!(MarginWidth, "marginwidth");
+
+    // https://html.spec.whatwg.org/multipage/#dom-frame-marginwidth
+    make_setter!(SetMarginWidth, "marginwidth");
+}
+
+impl VirtualMethods for HTMLFrameElement {
+    fn super_type(&self) -> Option<&VirtualMethods> {
+        Some(self.upcast::<HTMLElement>() as &VirtualMethods)
+    }
+
+    fn attribute_mutated(&self, attr: &Attr, mutation: AttributeMutation) {
+        self.super_type().unwrap().attribute_mutated(attr, mutation);
+        match attr.local_name() {
+            &local_name!("src") => {
+                // https://html.spec.whatwg.org/multipage/#frames
+                // "Whenever a frame element with a non-null nested browsing context has
+                // its src attribute set, changed, or removed, the user agent must process
+                // the frame attributes."
+                if self.upcast::<Node>().is_in_doc_with_browsing_context() {
+                    self.process_the_attributes(ProcessingMode::NotFirstTime);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn bind_to_tree(&self, tree_in_doc: bool) {
+        if let Some(ref s) = self.super_type() {
+            s.bind_to_tree(tree_in_doc);
+        }
+
+        // https://html.spec.whatwg.org/multipage/#frames
+        // "When a frame element is inserted into a document whose browsing
+        // context is non-null, the user agent must create a new child browsing
+        // context, and then process the frame attributes."
+        if self.upcast::<Node>().is_in_doc_with_browsing_context() {
+            self.create_nested_browsing_context();
+            self.process_the_attributes(ProcessingMode::FirstTime);
+        }
+    }
+
+    fn unbind_from_tree(&self, context: &UnbindContext) {
+        self.super_type().unwrap().unbind_from_tree(context);
+
+        self.discard_nested_browsing_context();
+    }
+}
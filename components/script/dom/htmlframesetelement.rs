@@ -2,15 +2,26 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use dom::attr::Attr;
 use dom::bindings::codegen::Bindings::HTMLFrameSetElementBinding;
 use dom::bindings::codegen::Bindings::HTMLFrameSetElementBinding::HTMLFrameSetElementMethods;
 use dom::bindings::codegen::Bindings::WindowBinding::WindowMethods;
-use dom::bindings::root::DomRoot;
+use dom::bindings::inheritance::Castable;
+use dom::bindings::root::{DomRoot, LayoutDom};
+use dom::bindings::str::DOMString;
 use dom::document::Document;
+use dom::element::{AttributeMutation, Element, RawLayoutElementHelpers};
+use dom::htmlbodyelement::forward_event_handler_attribute_to_window;
 use dom::htmlelement::HTMLElement;
-use dom::node::{Node, document_from_node};
+use dom::htmlframeelement::HTMLFrameElement;
+use dom::node::{ChildrenMutation, LayoutNodeHelpers, Node, NodeDamage, document_from_node};
+use dom::virtualmethods::VirtualMethods;
 use dom_struct::dom_struct;
 use html5ever::{LocalName, Prefix};
+use style::str::HTML_SPACE_CHARACTERS;
+use style::values::CSSFloat;
+use style::values::computed::Percentage;
+use style::values::specified::{AbsoluteLength, CalcLengthOrPercentage, LengthOrPercentageOrAuto};
 
 #[dom_struct]
 pub struct HTMLFrameSetElement {
@@ -35,9 +46,258 @@ impl HTMLFrameSetElement {
                            document,
                            HTMLFrameSetElementBinding::Wrap)
     }
+
+    /// Restyles the frames and nested framesets of this frameset, whose
+    /// geometry depends on its `rows` and `cols` attributes and on their
+    /// position among their siblings.
+    fn dirty_frames(&self) {
+        for child in self.upcast::<Node>().children() {
+            if is_frameset_child(&child) {
+                child.dirty(NodeDamage::OtherNodeDamage);
+            }
+        }
+    }
+}
+
+fn is_frameset_child(node: &Node) -> bool {
+    node.is::<HTMLFrameElement>() || node.is::<HTMLFrameSetElement>()
 }
 
 impl HTMLFrameSetElementMethods for HTMLFrameSetElement {
+    // https://html.spec.whatwg.org/multipage/#dom-frameset-cols
+    make_getter!(Cols, "cols");
+
+    // https://html.spec.whatwg.org/multipage/#dom-frameset-cols
+    make_setter!(SetCols, "cols");
+
+    // https://html.spec.whatwg.org/multipage/#dom-frameset-rows
+    make_getter!(Rows, "rows");
+
+    // https://html.spec.whatwg.org/multipage/#dom-frameset-rows
+    make_setter!(SetRows, "rows");
+
     // https://html.spec.whatwg.org/multipage/#windoweventhandlers
     window_event_handlers!(ForwardToWindow);
 }
+
+impl VirtualMethods for HTMLFrameSetElement {
+    fn super_type(&self) -> Option<&VirtualMethods> {
+        Some(self.upcast::<HTMLElement>() as &VirtualMethods)
+    }
+
+    fn attribute_mutated(&self, attr: &Attr, mutation: AttributeMutation) {
+        let do_super_mutate = match mutation {
+            AttributeMutation::Set(_) => !forward_event_handler_attribute_to_window(self.upcast(), attr),
+            AttributeMutation::Removed => true,
+        };
+
+        if do_super_mutate {
+            self.super_type().unwrap().attribute_mutated(attr, mutation);
+        }
+
+        match attr.local_name() {
+            &local_name!("rows") | &local_name!("cols") => self.dirty_frames(),
+            _ => {},
+        }
+    }
+
+    fn children_changed(&self, mutation: &ChildrenMutation) {
+        if let Some(ref s) = self.super_type() {
+            s.children_changed(mutation);
+        }
+
+        self.dirty_frames();
+    }
+}
+
+/// An entry of a list of dimensions, as used by the `rows` and `cols`
+/// attributes of framesets.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Dimension {
+    Absolute(f64),
+    Percentage(f64),
+    Relative(f64),
+}
+
+/// https://html.spec.whatwg.org/multipage/#rules-for-parsing-a-list-of-dimensions
+pub fn parse_a_list_of_dimensions(input: &str) -> Vec<Dimension> {
+    // Steps 1-2.
+    let input = if input.ends_with(',') {
+        &input[..input.len() - 1]
+    } else {
+        input
+    };
+
+    // Steps 3-5.
+    input.split(',').map(parse_a_dimension).collect()
+}
+
+/// Step 5 of https://html.spec.whatwg.org/multipage/#rules-for-parsing-a-list-of-dimensions
+fn parse_a_dimension(input: &str) -> Dimension {
+    let mut chars = input.chars().peekable();
+    let mut value = 0.;
+
+    // Step 5.4.
+    while chars.peek().map_or(false, |c| HTML_SPACE_CHARACTERS.contains(c)) {
+        chars.next();
+    }
+
+    // Step 5.5. A bare `*` gets a weight of one, like in other engines.
+    if chars.peek().is_none() {
+        return Dimension::Relative(1.);
+    }
+
+    // Step 5.6.
+    let mut has_digits = false;
+    while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+        value = value * 10. + digit as f64;
+        has_digits = true;
+        chars.next();
+    }
+
+    // Step 5.7.
+    if chars.peek() == Some(&'.') {
+        chars.next();
+        let mut divisor = 1.;
+        while let Some(&c) = chars.peek() {
+            if let Some(digit) = c.to_digit(10) {
+                divisor *= 10.;
+                value += digit as f64 / divisor;
+                has_digits = true;
+            } else if !HTML_SPACE_CHARACTERS.contains(&c) {
+                break;
+            }
+            chars.next();
+        }
+    }
+
+    // Step 5.8.
+    while chars.peek().map_or(false, |c| HTML_SPACE_CHARACTERS.contains(c)) {
+        chars.next();
+    }
+
+    // Steps 5.9-5.10.
+    match chars.peek() {
+        Some(&'%') => Dimension::Percentage(value),
+        Some(&'*') if !has_digits => Dimension::Relative(1.),
+        Some(&'*') => Dimension::Relative(value),
+        _ => Dimension::Absolute(value),
+    }
+}
+
+/// The size of a row or column of a frameset, as a percentage of the
+/// frameset's size plus an absolute length in pixels.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TrackSize {
+    pub percentage: CSSFloat,
+    pub pixels: CSSFloat,
+}
+
+impl TrackSize {
+    pub fn to_specified_value(&self) -> LengthOrPercentageOrAuto {
+        LengthOrPercentageOrAuto::Calc(Box::new(CalcLengthOrPercentage {
+            absolute: Some(AbsoluteLength::Px(self.pixels)),
+            percentage: Some(Percentage(self.percentage / 100.)),
+            .. Default::default()
+        }))
+    }
+}
+
+/// Converts a list of dimensions to the sizes of the tracks they describe,
+/// with relative entries sharing whatever space absolute and percentage
+/// entries leave over in proportion to their weight.
+///
+/// Unlike the algorithm in the spec, absolute and percentage entries that
+/// overflow the frameset are not scaled down.
+///
+/// https://html.spec.whatwg.org/multipage/#convert-a-list-of-dimensions-to-a-list-of-pixel-values
+pub fn dimensions_to_track_sizes(dimensions: &[Dimension]) -> Vec<TrackSize> {
+    let mut total_absolute = 0.;
+    let mut total_percentage = 0.;
+    let mut total_relative = 0.;
+    for dimension in dimensions {
+        match *dimension {
+            Dimension::Absolute(value) => total_absolute += value,
+            Dimension::Percentage(value) => total_percentage += value,
+            Dimension::Relative(value) => total_relative += value,
+        }
+    }
+
+    let remaining_percentage = (100. - total_percentage).max(0.);
+    dimensions.iter().map(|dimension| {
+        let (percentage, pixels) = match *dimension {
+            Dimension::Absolute(value) => (0., value),
+            Dimension::Percentage(value) => (value, 0.),
+            Dimension::Relative(_) if total_relative == 0. => (0., 0.),
+            Dimension::Relative(value) => {
+                let share = value / total_relative;
+                (remaining_percentage * share, -total_absolute * share)
+            },
+        };
+        TrackSize {
+            percentage: percentage as CSSFloat,
+            pixels: pixels as CSSFloat,
+        }
+    }).collect()
+}
+
+/// Where a frame or nested frameset is laid out in its parent frameset.
+///
+/// https://html.spec.whatwg.org/multipage/#frames-and-framesets
+pub enum FramesetCell {
+    /// The child occupies a cell of the given width and height.
+    Cell(TrackSize, TrackSize),
+    /// The frameset has fewer cells than children, and this one is not
+    /// rendered.
+    Hidden,
+}
+
+pub trait HTMLFrameSetElementLayoutHelpers {
+    fn get_rows(&self) -> Vec<TrackSize>;
+    fn get_cols(&self) -> Vec<TrackSize>;
+}
+
+impl HTMLFrameSetElementLayoutHelpers for LayoutDom<HTMLFrameSetElement> {
+    #[allow(unsafe_code)]
+    fn get_rows(&self) -> Vec<TrackSize> {
+        let rows = unsafe {
+            (&*self.upcast::<Element>().unsafe_get())
+                .get_attr_val_for_layout(&ns!(), &local_name!("rows"))
+        };
+        dimensions_to_track_sizes(&parse_a_list_of_dimensions(rows.unwrap_or("100%")))
+    }
+
+    #[allow(unsafe_code)]
+    fn get_cols(&self) -> Vec<TrackSize> {
+        let cols = unsafe {
+            (&*self.upcast::<Element>().unsafe_get())
+                .get_attr_val_for_layout(&ns!(), &local_name!("cols"))
+        };
+        dimensions_to_track_sizes(&parse_a_list_of_dimensions(cols.unwrap_or("100%")))
+    }
+}
+
+/// Returns the cell of its parent frameset that a frame or nested frameset
+/// is laid out in, if its parent is a frameset.
+#[allow(unsafe_code)]
+pub fn frameset_cell_for_layout(node: LayoutDom<Node>) -> Option<FramesetCell> {
+    let frameset = unsafe { node.parent_node_ref() }
+        .and_then(|parent| parent.downcast::<HTMLFrameSetElement>())?;
+
+    let mut index = 0;
+    let mut sibling = unsafe { node.prev_sibling_ref() };
+    while let Some(node) = sibling {
+        if node.downcast::<HTMLFrameElement>().is_some() ||
+           node.downcast::<HTMLFrameSetElement>().is_some() {
+            index += 1;
+        }
+        sibling = unsafe { node.prev_sibling_ref() };
+    }
+
+    let rows = frameset.get_rows();
+    let cols = frameset.get_cols();
+    if index >= rows.len() * cols.len() {
+        return Some(FramesetCell::Hidden);
+    }
+    Some(FramesetCell::Cell(cols[index % cols.len()], rows[index / cols.len()]))
+}
@@ -2,8 +2,9 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use dom::bindings::codegen::Bindings::HTMLHeadingElementBinding;
+use dom::bindings::codegen::Bindings::HTMLHeadingElementBinding::{self, HTMLHeadingElementMethods};
 use dom::bindings::root::DomRoot;
+use dom::bindings::str::DOMString;
 use dom::document::Document;
 use dom::htmlelement::HTMLElement;
 use dom::node::Node;
@@ -48,3 +49,11 @@ impl HTMLHeadingElement {
                            HTMLHeadingElementBinding::Wrap)
     }
 }
+
+impl HTMLHeadingElementMethods for HTMLHeadingElement {
+    // https://html.spec.whatwg.org/multipage/#dom-h1-align
+    make_getter!(Align, "align");
+
+    // https://html.spec.whatwg.org/multipage/#dom-h1-align
+    make_setter!(SetAlign, "align");
+}
@@ -19,6 +19,7 @@ use dom::element::{AttributeMutation, Element, RawLayoutElementHelpers};
 use dom::eventtarget::EventTarget;
 use dom::globalscope::GlobalScope;
 use dom::htmlelement::HTMLElement;
+use dom::htmlframeelement::HTMLFrameElement;
 use dom::node::{Node, NodeDamage, UnbindContext, document_from_node, window_from_node};
 use dom::virtualmethods::VirtualMethods;
 use dom::window::ReflowReason;
@@ -33,9 +34,11 @@ use script_thread::ScriptThread;
 use script_traits::{IFrameLoadInfo, IFrameLoadInfoWithData, JsEvalResult, LoadData, UpdatePipelineIdReason};
 use script_traits::{NewLayoutInfo, ScriptMsg};
 use script_traits::IFrameSandboxState::{IFrameSandboxed, IFrameUnsandboxed};
+use servo_atoms::Atom;
 use servo_config::prefs::PREFS;
 use servo_url::ServoUrl;
 use std::cell::Cell;
+use std::ops::Deref;
 use style::attr::{AttrValue, LengthOrPercentageOrAuto};
 use task_source::TaskSource;
 
@@ -59,48 +62,92 @@ pub enum NavigationType {
 }
 
 #[derive(PartialEq)]
-enum ProcessingMode {
+pub enum ProcessingMode {
     FirstTime,
     NotFirstTime,
 }
 
-#[dom_struct]
-pub struct HTMLIFrameElement {
-    htmlelement: HTMLElement,
+/// The nested browsing context of an `<iframe>` or `<frame>` element.
+#[derive(JSTraceable, MallocSizeOf)]
+#[must_root]
+pub struct NestedBrowsingContext {
     top_level_browsing_context_id: Cell<Option<TopLevelBrowsingContextId>>,
     browsing_context_id: Cell<Option<BrowsingContextId>>,
     pipeline_id: Cell<Option<PipelineId>>,
     pending_pipeline_id: Cell<Option<PipelineId>>,
     about_blank_pipeline_id: Cell<Option<PipelineId>>,
-    sandbox: MutNullableDom<DOMTokenList>,
-    sandbox_allowance: Cell<Option<SandboxAllowance>>,
     load_blocker: DomRefCell<Option<LoadBlocker>>,
     visibility: Cell<bool>,
 }
 
-impl HTMLIFrameElement {
-    pub fn is_sandboxed(&self) -> bool {
-        self.sandbox_allowance.get().is_some()
+impl NestedBrowsingContext {
+    pub fn new() -> NestedBrowsingContext {
+        NestedBrowsingContext {
+            top_level_browsing_context_id: Cell::new(None),
+            browsing_context_id: Cell::new(None),
+            pipeline_id: Cell::new(None),
+            pending_pipeline_id: Cell::new(None),
+            about_blank_pipeline_id: Cell::new(None),
+            load_blocker: DomRefCell::new(None),
+            visibility: Cell::new(true),
+        }
+    }
+
+    #[inline]
+    pub fn pipeline_id(&self) -> Option<PipelineId> {
+        self.pipeline_id.get()
+    }
+
+    #[inline]
+    pub fn browsing_context_id(&self) -> Option<BrowsingContextId> {
+        self.browsing_context_id.get()
+    }
+}
+
+/// An element that has a nested browsing context, i.e. an `<iframe>` or a
+/// `<frame>`. Both share the implementation of the default methods.
+pub trait BrowsingContextContainer {
+    fn container_element(&self) -> &HTMLElement;
+
+    fn nested_browsing_context(&self) -> &NestedBrowsingContext;
+
+    fn is_sandboxed(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn pipeline_id(&self) -> Option<PipelineId> {
+        self.nested_browsing_context().pipeline_id()
+    }
+
+    #[inline]
+    fn browsing_context_id(&self) -> Option<BrowsingContextId> {
+        self.nested_browsing_context().browsing_context_id()
+    }
+
+    #[inline]
+    fn top_level_browsing_context_id(&self) -> Option<TopLevelBrowsingContextId> {
+        self.nested_browsing_context().top_level_browsing_context_id.get()
     }
 
     /// <https://html.spec.whatwg.org/multipage/#otherwise-steps-for-iframe-or-frame-elements>,
     /// step 1.
     fn get_url(&self) -> ServoUrl {
-        let element = self.upcast::<Element>();
+        let element = self.container_element().upcast::<Element>();
         element.get_attribute(&ns!(), &local_name!("src")).and_then(|src| {
             let url = src.value();
             if url.is_empty() {
                 None
             } else {
-                document_from_node(self).base_url().join(&url).ok()
+                document_from_node(element).base_url().join(&url).ok()
             }
         }).unwrap_or_else(|| ServoUrl::parse("about:blank").unwrap())
     }
 
-    pub fn navigate_or_reload_child_browsing_context(&self,
-                                                     mut load_data: Option<LoadData>,
-                                                     nav_type: NavigationType,
-                                                     replace: bool) {
+    fn navigate_or_reload_child_browsing_context(&self,
+                                                 mut load_data: Option<LoadData>,
+                                                 nav_type: NavigationType,
+                                                 replace: bool) {
         let sandboxed = if self.is_sandboxed() {
             IFrameSandboxed
         } else {
@@ -117,9 +164,10 @@ impl HTMLIFrameElement {
             Some(id) => id,
         };
 
-        let document = document_from_node(self);
+        let context = self.nested_browsing_context();
+        let document = document_from_node(self.container_element());
 
-        let mut load_blocker = self.load_blocker.borrow_mut();
+        let mut load_blocker = context.load_blocker.borrow_mut();
         // Any oustanding load is finished from the point of view of the blocked
         // document; the new navigation will continue blocking it.
         LoadBlocker::terminate(&mut load_blocker);
@@ -127,7 +175,7 @@ impl HTMLIFrameElement {
         if let Some(ref mut load_data) = load_data {
             let is_javascript = load_data.url.scheme() == "javascript";
             if is_javascript {
-                let window_proxy = self.GetContentWindow();
+                let window_proxy = self.content_window();
                 if let Some(window_proxy) = window_proxy {
                     ScriptThread::eval_js_url(&window_proxy.global(), load_data);
                 }
@@ -146,10 +194,10 @@ impl HTMLIFrameElement {
             };
         }
 
-        let window = window_from_node(self);
+        let window = window_from_node(self.container_element());
         let old_pipeline_id = self.pipeline_id();
         let new_pipeline_id = PipelineId::new();
-        self.pending_pipeline_id.set(Some(new_pipeline_id));
+        context.pending_pipeline_id.set(Some(new_pipeline_id));
 
         let global_scope = window.upcast::<GlobalScope>();
         let load_info = IFrameLoadInfo {
@@ -165,7 +213,7 @@ impl HTMLIFrameElement {
             NavigationType::InitialAboutBlank => {
                 let (pipeline_sender, pipeline_receiver) = ipc::channel().unwrap();
 
-                self.about_blank_pipeline_id.set(Some(new_pipeline_id));
+                context.about_blank_pipeline_id.set(Some(new_pipeline_id));
 
                 global_scope
                     .script_to_constellation_chan()
@@ -182,9 +230,10 @@ impl HTMLIFrameElement {
                     content_process_shutdown_chan: None,
                     window_size: None,
                     layout_threads: PREFS.get("layout.threads").as_u64().expect("count") as usize,
+                    reset_browsing_context_name: false,
                 };
 
-                self.pipeline_id.set(Some(new_pipeline_id));
+                context.pipeline_id.set(Some(new_pipeline_id));
                 ScriptThread::process_attach_layout(new_layout_info, document.origin().clone());
             },
             NavigationType::Regular => {
@@ -203,19 +252,23 @@ impl HTMLIFrameElement {
     }
 
     /// <https://html.spec.whatwg.org/multipage/#process-the-iframe-attributes>
-    fn process_the_iframe_attributes(&self, mode: ProcessingMode) {
+    /// <https://html.spec.whatwg.org/multipage/#process-the-frame-attributes>
+    fn process_the_attributes(&self, mode: ProcessingMode) {
         // TODO: srcdoc
 
-        let window = window_from_node(self);
+        let window = window_from_node(self.container_element());
 
         // https://github.com/whatwg/html/issues/490
-        if mode == ProcessingMode::FirstTime && !self.upcast::<Element>().has_attribute(&local_name!("src")) {
-            let this = Trusted::new(self);
+        if mode == ProcessingMode::FirstTime &&
+           !self.container_element().upcast::<Element>().has_attribute(&local_name!("src")) {
+            let this = Trusted::new(self.container_element());
             let pipeline_id = self.pipeline_id().unwrap();
             // FIXME(nox): Why are errors silenced here?
             let _ = window.dom_manipulation_task_source().queue(
                 task!(iframe_load_event_steps: move || {
-                    this.root().iframe_load_event_steps(pipeline_id);
+                    if let Some(container) = BrowsingContextContainerRoot::from_element(&this.root()) {
+                        container.iframe_load_event_steps(pipeline_id);
+                    }
                 }),
                 window.upcast(),
             );
@@ -232,116 +285,126 @@ impl HTMLIFrameElement {
             None
         };
 
-        let document = document_from_node(self);
+        let document = document_from_node(self.container_element());
         let load_data = LoadData::new(url, creator_pipeline_id, document.get_referrer_policy(), Some(document.url()));
 
         let pipeline_id = self.pipeline_id();
         // If the initial `about:blank` page is the current page, load with replacement enabled.
-        let replace = pipeline_id.is_some() && pipeline_id == self.about_blank_pipeline_id.get();
+        let replace = pipeline_id.is_some() &&
+            pipeline_id == self.nested_browsing_context().about_blank_pipeline_id.get();
         self.navigate_or_reload_child_browsing_context(Some(load_data), NavigationType::Regular, replace);
     }
 
     fn create_nested_browsing_context(&self) {
         // Synchronously create a new context and navigate it to about:blank.
         let url = ServoUrl::parse("about:blank").unwrap();
-        let document = document_from_node(self);
-        let window = window_from_node(self);
+        let document = document_from_node(self.container_element());
+        let window = window_from_node(self.container_element());
         let pipeline_id = Some(window.upcast::<GlobalScope>().pipeline_id());
         let load_data = LoadData::new(url, pipeline_id, document.get_referrer_policy(), Some(document.url().clone()));
         let browsing_context_id = BrowsingContextId::new();
         let top_level_browsing_context_id = window.window_proxy().top_level_browsing_context_id();
-        self.pipeline_id.set(None);
-        self.pending_pipeline_id.set(None);
-        self.top_level_browsing_context_id.set(Some(top_level_browsing_context_id));
-        self.browsing_context_id.set(Some(browsing_context_id));
+        let context = self.nested_browsing_context();
+        context.pipeline_id.set(None);
+        context.pending_pipeline_id.set(None);
+        context.top_level_browsing_context_id.set(Some(top_level_browsing_context_id));
+        context.browsing_context_id.set(Some(browsing_context_id));
         self.navigate_or_reload_child_browsing_context(Some(load_data), NavigationType::InitialAboutBlank, false);
     }
 
     fn destroy_nested_browsing_context(&self) {
-        self.pipeline_id.set(None);
-        self.pending_pipeline_id.set(None);
-        self.about_blank_pipeline_id.set(None);
-        self.top_level_browsing_context_id.set(None);
-        self.browsing_context_id.set(None);
+        let context = self.nested_browsing_context();
+        context.pipeline_id.set(None);
+        context.pending_pipeline_id.set(None);
+        context.about_blank_pipeline_id.set(None);
+        context.top_level_browsing_context_id.set(None);
+        context.browsing_context_id.set(None);
+    }
+
+    /// <https://html.spec.whatwg.org/multipage/#a-browsing-context-is-discarded>
+    fn discard_nested_browsing_context(&self) {
+        let mut blocker = self.nested_browsing_context().load_blocker.borrow_mut();
+        LoadBlocker::terminate(&mut blocker);
+
+        let window = window_from_node(self.container_element());
+        let (sender, receiver) =
+            ProfiledIpc::channel(window.upcast::<GlobalScope>().time_profiler_chan().clone()).unwrap();
+
+        // Ask the constellation to remove the iframe, and tell us the
+        // pipeline ids of the closed pipelines.
+        let browsing_context_id = match self.browsing_context_id() {
+            None => return warn!("Unbinding already unbound iframe."),
+            Some(id) => id,
+        };
+        debug!("Unbinding frame {}.", browsing_context_id);
+
+        let msg = ScriptMsg::RemoveIFrame(browsing_context_id, sender);
+        window.upcast::<GlobalScope>().script_to_constellation_chan().send(msg).unwrap();
+        let exited_pipeline_ids = receiver.recv().unwrap();
+
+        // The spec for discarding is synchronous,
+        // so we need to discard the browsing contexts now, rather than
+        // when the `PipelineExit` message arrives.
+        for exited_pipeline_id in exited_pipeline_ids {
+            if let Some(exited_document) = ScriptThread::find_document(exited_pipeline_id) {
+                debug!("Discarding browsing context for pipeline {}", exited_pipeline_id);
+                exited_document.window().window_proxy().discard_browsing_context();
+                for exited_container in exited_document.iter_browsing_context_containers() {
+                    debug!("Discarding nested browsing context");
+                    exited_container.destroy_nested_browsing_context();
+                }
+            }
+        }
+
+        // Resetting the pipeline_id to None is required here so that
+        // if this iframe is subsequently re-added to the document
+        // the load doesn't think that it's a navigation, but instead
+        // a new iframe. Without this, the constellation gets very
+        // confused.
+        self.destroy_nested_browsing_context();
     }
 
-    pub fn update_pipeline_id(&self, new_pipeline_id: PipelineId, reason: UpdatePipelineIdReason) {
-        if self.pending_pipeline_id.get() != Some(new_pipeline_id) && reason == UpdatePipelineIdReason::Navigation {
+    fn update_pipeline_id(&self, new_pipeline_id: PipelineId, reason: UpdatePipelineIdReason) {
+        let context = self.nested_browsing_context();
+        if context.pending_pipeline_id.get() != Some(new_pipeline_id) &&
+           reason == UpdatePipelineIdReason::Navigation {
             return;
         }
 
-        self.pipeline_id.set(Some(new_pipeline_id));
+        context.pipeline_id.set(Some(new_pipeline_id));
 
         // Only terminate the load blocker if the pipeline id was updated due to a traversal.
         // The load blocker will be terminated for a navigation in iframe_load_event_steps.
         if reason == UpdatePipelineIdReason::Traversal {
-            let mut blocker = self.load_blocker.borrow_mut();
+            let mut blocker = context.load_blocker.borrow_mut();
             LoadBlocker::terminate(&mut blocker);
         }
 
-        self.upcast::<Node>().dirty(NodeDamage::OtherNodeDamage);
-        let window = window_from_node(self);
+        self.container_element().upcast::<Node>().dirty(NodeDamage::OtherNodeDamage);
+        let window = window_from_node(self.container_element());
         window.reflow(ReflowGoal::Full, ReflowReason::FramedContentChanged);
     }
 
-    fn new_inherited(local_name: LocalName,
-                     prefix: Option<Prefix>,
-                     document: &Document) -> HTMLIFrameElement {
-        HTMLIFrameElement {
-            htmlelement: HTMLElement::new_inherited(local_name, prefix, document),
-            browsing_context_id: Cell::new(None),
-            top_level_browsing_context_id: Cell::new(None),
-            pipeline_id: Cell::new(None),
-            pending_pipeline_id: Cell::new(None),
-            about_blank_pipeline_id: Cell::new(None),
-            sandbox: Default::default(),
-            sandbox_allowance: Cell::new(None),
-            load_blocker: DomRefCell::new(None),
-            visibility: Cell::new(true),
+    fn change_visibility_status(&self, visibility: bool) {
+        let context = self.nested_browsing_context();
+        if context.visibility.get() != visibility {
+            context.visibility.set(visibility);
         }
     }
 
-    #[allow(unrooted_must_root)]
-    pub fn new(local_name: LocalName,
-               prefix: Option<Prefix>,
-               document: &Document) -> DomRoot<HTMLIFrameElement> {
-        Node::reflect_node(Box::new(HTMLIFrameElement::new_inherited(local_name, prefix, document)),
-                           document,
-                           HTMLIFrameElementBinding::Wrap)
-    }
-
-    #[inline]
-    pub fn pipeline_id(&self) -> Option<PipelineId> {
-        self.pipeline_id.get()
-    }
-
-    #[inline]
-    pub fn browsing_context_id(&self) -> Option<BrowsingContextId> {
-        self.browsing_context_id.get()
-    }
-
-    #[inline]
-    pub fn top_level_browsing_context_id(&self) -> Option<TopLevelBrowsingContextId> {
-        self.top_level_browsing_context_id.get()
-    }
-
-    pub fn change_visibility_status(&self, visibility: bool) {
-        if self.visibility.get() != visibility {
-            self.visibility.set(visibility);
-        }
-    }
-
-    pub fn set_visible(&self, visible: bool) {
+    fn set_visible(&self, visible: bool) {
         let msg = ScriptMsg::SetVisible(visible);
-        let window = window_from_node(self);
+        let window = window_from_node(self.container_element());
         window.upcast::<GlobalScope>().script_to_constellation_chan().send(msg).unwrap();
     }
 
     /// https://html.spec.whatwg.org/multipage/#iframe-load-event-steps steps 1-4
-    pub fn iframe_load_event_steps(&self, loaded_pipeline: PipelineId) {
+    fn iframe_load_event_steps(&self, loaded_pipeline: PipelineId) {
+        let context = self.nested_browsing_context();
+
         // TODO(#9592): assert that the load blocker is present at all times when we
         //              can guarantee that it's created for the case of iframe.reload().
-        if Some(loaded_pipeline) != self.pending_pipeline_id.get() { return; }
+        if Some(loaded_pipeline) != context.pending_pipeline_id.get() { return; }
 
         // TODO A cross-origin child document would not be easily accessible
         //      from this script thread. It's unclear how to implement
@@ -350,16 +413,113 @@ impl HTMLIFrameElement {
         // TODO Step 3 - set child document  `mut iframe load` flag
 
         // Step 4
-        self.upcast::<EventTarget>().fire_event(atom!("load"));
+        self.container_element().upcast::<EventTarget>().fire_event(atom!("load"));
 
-        let mut blocker = self.load_blocker.borrow_mut();
+        let mut blocker = context.load_blocker.borrow_mut();
         LoadBlocker::terminate(&mut blocker);
 
         // TODO Step 5 - unset child document `mut iframe load` flag
 
-        let window = window_from_node(self);
+        let window = window_from_node(self.container_element());
         window.reflow(ReflowGoal::Full, ReflowReason::IFrameLoadEvent);
     }
+
+    // https://html.spec.whatwg.org/multipage/#dom-iframe-contentwindow
+    fn content_window(&self) -> Option<DomRoot<WindowProxy>> {
+        self.browsing_context_id()
+            .and_then(|browsing_context_id| ScriptThread::find_window_proxy(browsing_context_id))
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-iframe-contentdocument
+    // https://html.spec.whatwg.org/multipage/#concept-bcc-content-document
+    fn content_document(&self) -> Option<DomRoot<Document>> {
+        // Step 1.
+        let pipeline_id = self.pipeline_id()?;
+
+        // Step 2-3.
+        // Note that this lookup will fail if the document is dissimilar-origin,
+        // so we should return None in that case.
+        let document = ScriptThread::find_document(pipeline_id)?;
+
+        // Step 4.
+        let current = GlobalScope::current().expect("No current global object").as_window().Document();
+        if !current.origin().same_origin_domain(document.origin()) {
+            return None;
+        }
+        // Step 5.
+        Some(document)
+    }
+}
+
+/// A rooted `<iframe>` or `<frame>` element.
+pub enum BrowsingContextContainerRoot {
+    IFrame(DomRoot<HTMLIFrameElement>),
+    Frame(DomRoot<HTMLFrameElement>),
+}
+
+impl BrowsingContextContainerRoot {
+    pub fn from_element(element: &HTMLElement) -> Option<BrowsingContextContainerRoot> {
+        if let Some(iframe) = element.downcast::<HTMLIFrameElement>() {
+            return Some(BrowsingContextContainerRoot::IFrame(DomRoot::from_ref(iframe)));
+        }
+        element.downcast::<HTMLFrameElement>()
+            .map(|frame| BrowsingContextContainerRoot::Frame(DomRoot::from_ref(frame)))
+    }
+}
+
+impl Deref for BrowsingContextContainerRoot {
+    type Target = BrowsingContextContainer;
+
+    fn deref(&self) -> &BrowsingContextContainer {
+        match *self {
+            BrowsingContextContainerRoot::IFrame(ref iframe) => &**iframe,
+            BrowsingContextContainerRoot::Frame(ref frame) => &**frame,
+        }
+    }
+}
+
+#[dom_struct]
+pub struct HTMLIFrameElement {
+    htmlelement: HTMLElement,
+    nested_browsing_context: NestedBrowsingContext,
+    sandbox: MutNullableDom<DOMTokenList>,
+    sandbox_allowance: Cell<Option<SandboxAllowance>>,
+}
+
+impl HTMLIFrameElement {
+    fn new_inherited(local_name: LocalName,
+                     prefix: Option<Prefix>,
+                     document: &Document) -> HTMLIFrameElement {
+        HTMLIFrameElement {
+            htmlelement: HTMLElement::new_inherited(local_name, prefix, document),
+            nested_browsing_context: NestedBrowsingContext::new(),
+            sandbox: Default::default(),
+            sandbox_allowance: Cell::new(None),
+        }
+    }
+
+    #[allow(unrooted_must_root)]
+    pub fn new(local_name: LocalName,
+               prefix: Option<Prefix>,
+               document: &Document) -> DomRoot<HTMLIFrameElement> {
+        Node::reflect_node(Box::new(HTMLIFrameElement::new_inherited(local_name, prefix, document)),
+                           document,
+                           HTMLIFrameElementBinding::Wrap)
+    }
+}
+
+impl BrowsingContextContainer for HTMLIFrameElement {
+    fn container_element(&self) -> &HTMLElement {
+        self.upcast()
+    }
+
+    fn nested_browsing_context(&self) -> &NestedBrowsingContext {
+        &self.nested_browsing_context
+    }
+
+    fn is_sandboxed(&self) -> bool {
+        self.sandbox_allowance.get().is_some()
+    }
 }
 
 pub trait HTMLIFrameElementLayoutMethods {
@@ -374,7 +534,7 @@ impl HTMLIFrameElementLayoutMethods for LayoutDom<HTMLIFrameElement> {
     #[allow(unsafe_code)]
     fn pipeline_id(&self) -> Option<PipelineId> {
         unsafe {
-            (*self.unsafe_get()).pipeline_id.get()
+            (*self.unsafe_get()).nested_browsing_context.pipeline_id()
         }
     }
 
@@ -382,7 +542,7 @@ impl HTMLIFrameElementLayoutMethods for LayoutDom<HTMLIFrameElement> {
     #[allow(unsafe_code)]
     fn browsing_context_id(&self) -> Option<BrowsingContextId> {
         unsafe {
-            (*self.unsafe_get()).browsing_context_id.get()
+            (*self.unsafe_get()).nested_browsing_context.browsing_context_id()
         }
     }
 
@@ -419,33 +579,26 @@ impl HTMLIFrameElementMethods for HTMLIFrameElement {
 
     // https://html.spec.whatwg.org/multipage/#dom-iframe-sandbox
     fn Sandbox(&self) -> DomRoot<DOMTokenList> {
-        self.sandbox.or_init(|| DOMTokenList::new(self.upcast::<Element>(), &local_name!("sandbox")))
+        self.sandbox.or_init(|| {
+            DOMTokenList::new(self.upcast::<Element>(), &local_name!("sandbox"), Some(vec![
+                Atom::from("allow-forms"),
+                Atom::from("allow-pointer-lock"),
+                Atom::from("allow-popups"),
+                Atom::from("allow-same-origin"),
+                Atom::from("allow-scripts"),
+                Atom::from("allow-top-navigation"),
+            ]))
+        })
     }
 
     // https://html.spec.whatwg.org/multipage/#dom-iframe-contentwindow
     fn GetContentWindow(&self) -> Option<DomRoot<WindowProxy>> {
-        self.browsing_context_id.get()
-            .and_then(|browsing_context_id| ScriptThread::find_window_proxy(browsing_context_id))
+        self.content_window()
     }
 
     // https://html.spec.whatwg.org/multipage/#dom-iframe-contentdocument
-    // https://html.spec.whatwg.org/multipage/#concept-bcc-content-document
     fn GetContentDocument(&self) -> Option<DomRoot<Document>> {
-        // Step 1.
-        let pipeline_id = self.pipeline_id.get()?;
-
-        // Step 2-3.
-        // Note that this lookup will fail if the document is dissimilar-origin,
-        // so we should return None in that case.
-        let document = ScriptThread::find_document(pipeline_id)?;
-
-        // Step 4.
-        let current = GlobalScope::current().expect("No current global object").as_window().Document();
-        if !current.origin().same_origin_domain(document.origin()) {
-            return None;
-        }
-        // Step 5.
-        Some(document)
+        self.content_document()
     }
 
     // https://html.spec.whatwg.org/multipage/#attr-iframe-allowfullscreen
@@ -521,7 +674,7 @@ impl VirtualMethods for HTMLIFrameElement {
                 // the child browsing context to be created.
                 if self.upcast::<Node>().is_in_doc_with_browsing_context() {
                     debug!("iframe src set while in browsing context.");
-                    self.process_the_iframe_attributes(ProcessingMode::NotFirstTime);
+                    self.process_the_attributes(ProcessingMode::NotFirstTime);
                 }
             },
             &local_name!("name") => {
@@ -557,51 +710,13 @@ impl VirtualMethods for HTMLIFrameElement {
             debug!("iframe bound to browsing context.");
             debug_assert!(tree_in_doc, "is_in_doc_with_bc, but not tree_in_doc");
             self.create_nested_browsing_context();
-            self.process_the_iframe_attributes(ProcessingMode::FirstTime);
+            self.process_the_attributes(ProcessingMode::FirstTime);
         }
     }
 
     fn unbind_from_tree(&self, context: &UnbindContext) {
         self.super_type().unwrap().unbind_from_tree(context);
 
-        let mut blocker = self.load_blocker.borrow_mut();
-        LoadBlocker::terminate(&mut blocker);
-
-        // https://html.spec.whatwg.org/multipage/#a-browsing-context-is-discarded
-        let window = window_from_node(self);
-        let (sender, receiver) = ProfiledIpc::channel(self.global().time_profiler_chan().clone()).unwrap();
-
-        // Ask the constellation to remove the iframe, and tell us the
-        // pipeline ids of the closed pipelines.
-        let browsing_context_id = match self.browsing_context_id() {
-            None => return warn!("Unbinding already unbound iframe."),
-            Some(id) => id,
-        };
-        debug!("Unbinding frame {}.", browsing_context_id);
-
-        let msg = ScriptMsg::RemoveIFrame(browsing_context_id, sender);
-        window.upcast::<GlobalScope>().script_to_constellation_chan().send(msg).unwrap();
-        let exited_pipeline_ids = receiver.recv().unwrap();
-
-        // The spec for discarding is synchronous,
-        // so we need to discard the browsing contexts now, rather than
-        // when the `PipelineExit` message arrives.
-        for exited_pipeline_id in exited_pipeline_ids {
-            if let Some(exited_document) = ScriptThread::find_document(exited_pipeline_id) {
-                debug!("Discarding browsing context for pipeline {}", exited_pipeline_id);
-                exited_document.window().window_proxy().discard_browsing_context();
-                for exited_iframe in exited_document.iter_iframes() {
-                    debug!("Discarding nested browsing context");
-                    exited_iframe.destroy_nested_browsing_context();
-                }
-            }
-        }
-
-        // Resetting the pipeline_id to None is required here so that
-        // if this iframe is subsequently re-added to the document
-        // the load doesn't think that it's a navigation, but instead
-        // a new iframe. Without this, the constellation gets very
-        // confused.
-        self.destroy_nested_browsing_context();
+        self.discard_nested_browsing_context();
     }
 }
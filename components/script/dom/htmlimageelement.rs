@@ -36,7 +36,7 @@ use dom::values::UNSIGNED_LONG_MAX;
 use dom::virtualmethods::VirtualMethods;
 use dom::window::Window;
 use dom_struct::dom_struct;
-use euclid::Point2D;
+use euclid::{Point2D, Size2D};
 use html5ever::{LocalName, Prefix};
 use ipc_channel::ipc;
 use ipc_channel::router::ROUTER;
@@ -59,7 +59,7 @@ use std::i32;
 use std::sync::{Arc, Mutex};
 use style::attr::{AttrValue, LengthOrPercentageOrAuto, parse_double, parse_unsigned_integer};
 use style::context::QuirksMode;
-use style::media_queries::MediaQuery;
+use style::media_queries::{MediaList, MediaQuery};
 use style::parser::ParserContext;
 use style::str::is_ascii_digit;
 use style::values::specified::{Length, ViewportPercentageLength};
@@ -369,20 +369,88 @@ impl HTMLImageElement {
     }
 
     /// <https://html.spec.whatwg.org/multipage/#update-the-source-set>
-    fn update_source_set(&self) -> Vec<DOMString> {
+    fn update_source_set(&self) -> Vec<ImageSource> {
         let elem = self.upcast::<Element>();
-        // TODO: follow the algorithm
+        // TODO: take a parent picture element into account.
+        // Step 5.
+        let srcset = elem.get_string_attribute(&local_name!("srcset"));
+        let mut source_set = parse_a_srcset_attribute(&srcset);
+        // Step 6.
+        let sizes = elem.get_string_attribute(&local_name!("sizes"));
+        let source_size = self.evaluate_source_size_list(&parse_a_sizes_attribute(sizes, None));
+        // Step 7.
         let src = elem.get_string_attribute(&local_name!("src"));
-        if src.is_empty() {
-            return vec![]
+        let has_1x_or_width_descriptor = source_set.iter().any(|source| {
+            source.descriptor.wid.is_some() || source.descriptor.den == Some(1.)
+        });
+        if !src.is_empty() && !has_1x_or_width_descriptor {
+            source_set.push(ImageSource {
+                url: src.into(),
+                descriptor: Descriptor { wid: None, den: Some(1.) },
+            });
+        }
+        // Step 8.
+        normalise_source_densities(&mut source_set, source_size);
+        source_set
+    }
+
+    /// Evaluates a source size list, as returned by `parse_a_sizes_attribute`,
+    /// against the current viewport, returning the source size in CSS pixels.
+    fn evaluate_source_size_list(&self, source_size_list: &[Size]) -> f64 {
+        let document = document_from_node(self);
+        let device = document.device();
+        let viewport_size = device.as_ref()
+            .map_or(Size2D::new(Au(0), Au(0)), |device| device.au_viewport_size());
+        let size = source_size_list.iter().find(|size| {
+            match size.query {
+                Some(ref query) => device.as_ref().map_or(false, |device| {
+                    let media_list = MediaList { media_queries: vec![query.clone()] };
+                    media_list.evaluate(device, document.quirks_mode())
+                }),
+                None => true,
+            }
+        });
+        match size.map(|size| &size.length) {
+            Some(&Length::NoCalc(NoCalcLength::Absolute(ref length))) => length.to_px() as f64,
+            Some(&Length::NoCalc(NoCalcLength::ViewportPercentage(ref length))) => {
+                length.to_computed_value(viewport_size).px() as f64
+            },
+            // TODO: resolve font-relative and calc() lengths; fall back to 100vw for now.
+            _ => viewport_size.width.to_f64_px(),
         }
-        vec![src]
     }
 
     /// <https://html.spec.whatwg.org/multipage/#select-an-image-source>
-    fn select_image_source(&self) -> Option<DOMString> {
-        // TODO: select an image source from source set
-        self.update_source_set().first().cloned()
+    fn select_image_source(&self) -> Option<(DOMString, f64)> {
+        // Step 1-2.
+        let source_set = self.update_source_set();
+        // Step 3.
+        if source_set.is_empty() {
+            return None;
+        }
+        // Step 4-6.
+        // Prefer the least dense candidate that still covers the device pixel
+        // ratio, falling back to the densest candidate otherwise. Ties keep the
+        // earliest candidate.
+        let device_pixel_ratio = window_from_node(self).device_pixel_ratio().get() as f64;
+        let mut selected: Option<&ImageSource> = None;
+        for source in &source_set {
+            let density = source.descriptor.den.unwrap_or(1.);
+            let is_better = match selected {
+                None => true,
+                Some(selected) => {
+                    let selected_density = selected.descriptor.den.unwrap_or(1.);
+                    (selected_density < device_pixel_ratio && density > selected_density) ||
+                    (density >= device_pixel_ratio && density < selected_density)
+                },
+            };
+            if is_better {
+                selected = Some(source);
+            }
+        }
+        selected.map(|source| {
+            (DOMString::from(source.url.clone()), source.descriptor.den.unwrap_or(1.))
+        })
     }
 
     fn init_image_request(&self,
@@ -449,7 +517,7 @@ impl HTMLImageElement {
         let task_source = window.dom_manipulation_task_source();
         let this = Trusted::new(self);
         let src = match self.select_image_source() {
-            Some(src) => {
+            Some((src, _pixel_density)) => {
                 // Step 8.
                 // TODO: Handle pixel density.
                 src
@@ -560,8 +628,8 @@ impl HTMLImageElement {
         // NOTE: Servo only supports images, skipping this step
 
         // step 3, 4
-        // TODO: take srcset and parent images into account
-        if !src.is_empty() {
+        // TODO: take parent images into account
+        if !elem.has_attribute(&local_name!("srcset")) && !src.is_empty() {
             // TODO: take pixel density into account
             if let Ok(img_url) = base_url.join(&src) {
                 // step 5, check the list of available images
@@ -824,6 +892,16 @@ impl HTMLImageElementMethods for HTMLImageElement {
     // https://html.spec.whatwg.org/multipage/#dom-img-src
     make_setter!(SetSrc, "src");
 
+    // https://html.spec.whatwg.org/multipage/#dom-img-srcset
+    make_getter!(Srcset, "srcset");
+    // https://html.spec.whatwg.org/multipage/#dom-img-srcset
+    make_setter!(SetSrcset, "srcset");
+
+    // https://html.spec.whatwg.org/multipage/#dom-img-sizes
+    make_getter!(Sizes, "sizes");
+    // https://html.spec.whatwg.org/multipage/#dom-img-sizes
+    make_setter!(SetSizes, "sizes");
+
     // https://html.spec.whatwg.org/multipage/#dom-img-crossOrigin
     fn GetCrossOrigin(&self) -> Option<DOMString> {
         reflect_cross_origin_attribute(self.upcast::<Element>())
@@ -895,12 +973,12 @@ impl HTMLImageElementMethods for HTMLImageElement {
     // https://html.spec.whatwg.org/multipage/#dom-img-complete
     fn Complete(&self) -> bool {
         let elem = self.upcast::<Element>();
-        // TODO: take srcset into account
-        if !elem.has_attribute(&local_name!("src")) {
+        let has_srcset = elem.has_attribute(&local_name!("srcset"));
+        if !elem.has_attribute(&local_name!("src")) && !has_srcset {
             return true
         }
         let src = elem.get_string_attribute(&local_name!("src"));
-        if src.is_empty() {
+        if !has_srcset && src.is_empty() {
             return true
         }
         let request = self.current_request.borrow();
@@ -970,7 +1048,9 @@ impl VirtualMethods for HTMLImageElement {
     fn attribute_mutated(&self, attr: &Attr, mutation: AttributeMutation) {
         self.super_type().unwrap().attribute_mutated(attr, mutation);
         match attr.local_name() {
-            &local_name!("src") => self.update_the_image_data(),
+            &local_name!("src") |
+            &local_name!("srcset") |
+            &local_name!("sizes") => self.update_the_image_data(),
             _ => {},
         }
     }
@@ -1077,6 +1157,20 @@ pub fn collect_sequence_characters<F>(s: &str, predicate: F) -> (&str, &str)
     return (s, "");
 }
 
+/// <https://html.spec.whatwg.org/multipage/#normalise-the-source-densities>
+fn normalise_source_densities(source_set: &mut [ImageSource], source_size: f64) {
+    for source in source_set.iter_mut() {
+        if source.descriptor.den.is_some() {
+            continue;
+        }
+        let density = match source.descriptor.wid {
+            Some(width) => width as f64 / source_size,
+            None => 1.,
+        };
+        source.descriptor.den = Some(density);
+    }
+}
+
 /// Parse an `srcset` attribute - https://html.spec.whatwg.org/multipage/#parsing-a-srcset-attribute.
 pub fn parse_a_srcset_attribute(input: &str) -> Vec<ImageSource> {
     let mut url_len = 0;
@@ -3,20 +3,24 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use caseless::compatibility_caseless_match_str;
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
 use dom::activation::{Activatable, ActivationSource, synthetic_click_activation};
 use dom::attr::Attr;
 use dom::bindings::cell::DomRefCell;
+use dom::bindings::codegen::Bindings::DocumentBinding::DocumentMethods;
 use dom::bindings::codegen::Bindings::EventBinding::EventMethods;
 use dom::bindings::codegen::Bindings::FileListBinding::FileListMethods;
 use dom::bindings::codegen::Bindings::HTMLFormElementBinding::SelectionMode;
 use dom::bindings::codegen::Bindings::HTMLInputElementBinding;
 use dom::bindings::codegen::Bindings::HTMLInputElementBinding::HTMLInputElementMethods;
 use dom::bindings::codegen::Bindings::KeyboardEventBinding::KeyboardEventMethods;
+use dom::bindings::codegen::Bindings::NodeBinding::NodeMethods;
 use dom::bindings::error::{Error, ErrorResult};
 use dom::bindings::inheritance::Castable;
 use dom::bindings::reflector::DomObject;
 use dom::bindings::root::{Dom, DomRoot, LayoutDom, MutNullableDom, RootedReference};
-use dom::bindings::str::DOMString;
+use dom::bindings::str::{DOMString, parse_date_string, parse_local_date_and_time_string};
+use dom::bindings::str::{parse_month_string, parse_time_component, parse_week_string};
 use dom::document::Document;
 use dom::element::{AttributeMutation, Element, LayoutElementHelpers, RawLayoutElementHelpers};
 use dom::event::{Event, EventBubbles, EventCancelable};
@@ -24,6 +28,7 @@ use dom::eventtarget::EventTarget;
 use dom::file::File;
 use dom::filelist::FileList;
 use dom::globalscope::GlobalScope;
+use dom::htmldatalistelement::HTMLDataListElement;
 use dom::htmlelement::HTMLElement;
 use dom::htmlfieldsetelement::HTMLFieldSetElement;
 use dom::htmlformelement::{FormControl, FormDatum, FormDatumValue, FormSubmitter, HTMLFormElement};
@@ -39,6 +44,8 @@ use dom::validitystate::ValidationFlags;
 use dom::virtualmethods::VirtualMethods;
 use dom_struct::dom_struct;
 use html5ever::{LocalName, Prefix};
+use js::jsapi::{ClippedTime, JSContext, JSObject, NewDateObject};
+use js::rust::wrappers::DateGetMsecSinceEpoch;
 use mime_guess;
 use msg::constellation_msg::InputMethodType;
 use net_traits::{CoreResourceMsg, IpcSend};
@@ -50,7 +57,9 @@ use script_traits::ScriptToConstellationChan;
 use servo_atoms::Atom;
 use std::borrow::ToOwned;
 use std::cell::Cell;
+use std::f64;
 use std::ops::Range;
+use std::ptr::NonNull;
 use style::attr::AttrValue;
 use style::element_state::ElementState;
 use style::str::split_commas;
@@ -318,6 +327,32 @@ impl HTMLInputElement {
     pub fn input_type(&self) -> InputType {
         self.input_type.get()
     }
+
+    /// https://html.spec.whatwg.org/multipage/#concept-input-list
+    pub fn suggestions_source_element(&self) -> Option<DomRoot<HTMLDataListElement>> {
+        match self.input_type() {
+            InputType::Button | InputType::Checkbox | InputType::File |
+            InputType::Hidden | InputType::Image | InputType::Password |
+            InputType::Radio | InputType::Reset | InputType::Submit => return None,
+            _ => {},
+        }
+
+        let list_id = self.upcast::<Element>().get_string_attribute(&local_name!("list"));
+        if list_id.is_empty() {
+            return None;
+        }
+
+        let node = self.upcast::<Node>();
+        let first_with_id = if node.is_in_doc() {
+            document_from_node(self).GetElementById(list_id)
+        } else {
+            node.inclusive_ancestors().last().unwrap()
+                .traverse_preorder()
+                .filter_map(DomRoot::downcast::<Element>)
+                .find(|element| element.get_string_attribute(&local_name!("id")) == list_id)
+        };
+        first_with_id.and_then(DomRoot::downcast::<HTMLDataListElement>)
+    }
 }
 
 pub trait LayoutHTMLInputElementHelpers {
@@ -495,9 +530,16 @@ impl HTMLInputElementMethods for HTMLInputElement {
 
     // https://html.spec.whatwg.org/multipage/#dom-input-files
     fn GetFiles(&self) -> Option<DomRoot<FileList>> {
-        match self.filelist.get() {
-            Some(ref fl) => Some(fl.clone()),
-            None => None,
+        if self.input_type() != InputType::File {
+            return None;
+        }
+        Some(self.filelist.or_init(|| FileList::new(&window_from_node(self), vec![])))
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-input-files
+    fn SetFiles(&self, files: Option<&FileList>) {
+        if self.input_type() == InputType::File && files.is_some() {
+            self.filelist.set(files);
         }
     }
 
@@ -610,6 +652,72 @@ impl HTMLInputElementMethods for HTMLInputElement {
         Ok(())
     }
 
+    // https://html.spec.whatwg.org/multipage/#dom-input-valueasdate
+    #[allow(unsafe_code)]
+    unsafe fn GetValueAsDate(&self, cx: *mut JSContext) -> Option<NonNull<JSObject>> {
+        if !self.does_value_as_date_apply() {
+            return None;
+        }
+        self.convert_string_to_naive_datetime(&self.Value()).map(|datetime| {
+            let time = time_clip(naive_datetime_to_milliseconds(&datetime));
+            NonNull::new_unchecked(NewDateObject(cx, time))
+        })
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-input-valueasdate
+    #[allow(unsafe_code)]
+    unsafe fn SetValueAsDate(&self, cx: *mut JSContext, value: *mut JSObject) -> ErrorResult {
+        // Step 1.
+        if !self.does_value_as_date_apply() {
+            return Err(Error::InvalidState);
+        }
+
+        // Step 2.
+        if value.is_null() {
+            return self.SetValue(DOMString::new());
+        }
+        rooted!(in(cx) let value = value);
+        let mut milliseconds = 0.0;
+        if !DateGetMsecSinceEpoch(cx, value.handle(), &mut milliseconds) {
+            return Err(Error::JSFailed);
+        }
+
+        // Step 3.
+        let value = milliseconds_to_naive_datetime(milliseconds)
+            .and_then(|datetime| self.convert_naive_datetime_to_string(&datetime))
+            .unwrap_or_default();
+        self.SetValue(value)
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-input-valueasnumber
+    fn ValueAsNumber(&self) -> f64 {
+        if !self.does_value_as_number_apply() {
+            return f64::NAN;
+        }
+        self.convert_string_to_number(&self.Value()).unwrap_or(f64::NAN)
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-input-valueasnumber
+    fn SetValueAsNumber(&self, value: f64) -> ErrorResult {
+        // Step 1.
+        if value.is_infinite() {
+            return Err(Error::Type("Value is not finite".to_owned()));
+        }
+
+        // Step 2.
+        if !self.does_value_as_number_apply() {
+            return Err(Error::InvalidState);
+        }
+
+        // Step 3.
+        let value = if value.is_nan() {
+            DOMString::new()
+        } else {
+            self.convert_number_to_string(value).unwrap_or_default()
+        };
+        self.SetValue(value)
+    }
+
     // https://html.spec.whatwg.org/multipage/#dom-input-defaultvalue
     make_getter!(DefaultValue, "value");
 
@@ -691,6 +799,16 @@ impl HTMLInputElementMethods for HTMLInputElement {
     // https://html.spec.whatwg.org/multipage/#dom-input-multiple
     make_bool_setter!(SetMultiple, "multiple");
 
+    // https://wicg.github.io/entries-api/#dom-htmlinputelement-webkitdirectory
+    fn Webkitdirectory(&self) -> bool {
+        self.upcast::<Element>().has_attribute(&LocalName::from("webkitdirectory"))
+    }
+
+    // https://wicg.github.io/entries-api/#dom-htmlinputelement-webkitdirectory
+    fn SetWebkitdirectory(&self, value: bool) {
+        self.upcast::<Element>().set_bool_attribute(&LocalName::from("webkitdirectory"), value)
+    }
+
     // https://html.spec.whatwg.org/multipage/#dom-input-pattern
     make_getter!(Pattern, "pattern");
 
@@ -715,6 +833,11 @@ impl HTMLInputElementMethods for HTMLInputElement {
     // https://html.spec.whatwg.org/multipage/#dom-input-step
     make_setter!(SetStep, "step");
 
+    // https://html.spec.whatwg.org/multipage/#dom-input-list
+    fn GetList(&self) -> Option<DomRoot<HTMLElement>> {
+        self.suggestions_source_element().map(DomRoot::upcast)
+    }
+
     // https://html.spec.whatwg.org/multipage/#dom-input-indeterminate
     fn Indeterminate(&self) -> bool {
         self.upcast::<Element>().state().contains(ElementState::IN_INDETERMINATE_STATE)
@@ -841,6 +964,54 @@ fn in_same_group(other: &HTMLInputElement, owner: Option<&HTMLFormElement>,
     }
 }
 
+fn naive_time_from_components((hour, minute, second): (u32, u32, f32)) -> Option<NaiveTime> {
+    let whole_seconds = second.trunc();
+    // Rounding must not carry into the next second, which chrono would read
+    // as a leap second.
+    let milliseconds = ((second as f64 - whole_seconds as f64) * 1000.0).round().min(999.0);
+    NaiveTime::from_hms_milli_opt(hour, minute, whole_seconds as u32, milliseconds as u32)
+}
+
+fn naive_datetime_to_milliseconds(datetime: &NaiveDateTime) -> f64 {
+    datetime.timestamp() as f64 * 1000.0 + datetime.timestamp_subsec_millis() as f64
+}
+
+/// <https://tc39.github.io/ecma262/#sec-timeclip>
+fn time_clip(time: f64) -> ClippedTime {
+    // Steps 1-2.
+    if !time.is_finite() || time.abs() > 8.64e15 {
+        return ClippedTime { t: f64::NAN };
+    }
+    // Step 3. The `+ 0.0` turns -0 into +0.
+    ClippedTime { t: time.trunc() + 0.0 }
+}
+
+fn milliseconds_to_naive_datetime(milliseconds: f64) -> Option<NaiveDateTime> {
+    if !milliseconds.is_finite() {
+        return None;
+    }
+    let milliseconds = milliseconds.floor();
+    let seconds = (milliseconds / 1000.0).floor();
+    if seconds < i64::min_value() as f64 || seconds > i64::max_value() as f64 {
+        return None;
+    }
+    let remainder = (milliseconds - seconds * 1000.0) as u32;
+    NaiveDateTime::from_timestamp_opt(seconds as i64, remainder * 1_000_000)
+}
+
+/// <https://html.spec.whatwg.org/multipage/#valid-time-string>, using the
+/// shortest form that represents the time.
+fn format_time(time: &NaiveTime) -> String {
+    let milliseconds = time.nanosecond() / 1_000_000;
+    if milliseconds != 0 {
+        format!("{:02}:{:02}:{:02}.{:03}", time.hour(), time.minute(), time.second(), milliseconds)
+    } else if time.second() != 0 {
+        format!("{:02}:{:02}:{:02}", time.hour(), time.minute(), time.second())
+    } else {
+        format!("{:02}:{:02}", time.hour(), time.minute())
+    }
+}
+
 impl HTMLInputElement {
     fn radio_group_updated(&self, group: Option<&Atom>) {
         if self.Checked() {
@@ -992,7 +1163,30 @@ impl HTMLInputElement {
         let filter = filter_from_accept(&self.Accept());
         let target = self.upcast::<EventTarget>();
 
-        if self.Multiple() {
+        // Passing no test paths clears the selection, as if the user had
+        // removed every file from it.
+        let clear_selection = opt_test_paths.as_ref().map_or(false, |paths| paths.is_empty());
+
+        if clear_selection {
+            // Nothing to ask the file manager for.
+        } else if self.Webkitdirectory() {
+            // https://wicg.github.io/entries-api/#html-forms
+            let opt_test_path = opt_test_paths.map(|paths| paths[0].to_string()); // neglect other paths
+
+            let (chan, recv) =
+                ipc::channel(self.global().time_profiler_chan().clone()).expect("Error initializing channel");
+            let msg = FileManagerThreadMsg::SelectDirectory(chan, origin, opt_test_path);
+            let _ = resource_threads.send(CoreResourceMsg::ToFileManager(msg)).unwrap();
+
+            match recv.recv().expect("IpcSender side error") {
+                Ok(selected_files) => {
+                    for selected in selected_files {
+                        files.push(File::new_from_selected(&window, selected));
+                    }
+                },
+                Err(err) => error = Some(err),
+            };
+        } else if self.Multiple() {
             let opt_test_paths = opt_test_paths.map(|paths| paths.iter().map(|p| p.to_string()).collect());
 
             let (chan, recv) =
@@ -1009,16 +1203,7 @@ impl HTMLInputElement {
                 Err(err) => error = Some(err),
             };
         } else {
-            let opt_test_path = match opt_test_paths {
-                Some(paths) => {
-                    if paths.len() == 0 {
-                        return;
-                    } else {
-                        Some(paths[0].to_string()) // neglect other paths
-                    }
-                }
-                None => None,
-            };
+            let opt_test_path = opt_test_paths.map(|paths| paths[0].to_string()); // neglect other paths
 
             let (chan, recv) =
                 ipc::channel(self.global().time_profiler_chan().clone()).expect("Error initializing channel");
@@ -1035,13 +1220,20 @@ impl HTMLInputElement {
 
         if let Some(err) = error {
             debug!("Input file select error: {:?}", err);
-        } else {
-            let filelist = FileList::new(&window, files);
-            self.filelist.set(Some(&filelist));
+            return;
+        }
 
-            target.fire_bubbling_event(atom!("input"));
-            target.fire_bubbling_event(atom!("change"));
+        // Clearing a selection that was already empty changes nothing.
+        let was_empty = self.filelist.get().map_or(true, |fl| fl.Length() == 0);
+        if files.is_empty() && was_empty {
+            return;
         }
+
+        let filelist = FileList::new(&window, files);
+        self.filelist.set(Some(&filelist));
+
+        target.fire_bubbling_event(atom!("input"));
+        target.fire_bubbling_event(atom!("change"));
     }
 
     // https://html.spec.whatwg.org/multipage/#value-sanitization-algorithm
@@ -1108,6 +1300,133 @@ impl HTMLInputElement {
         }
     }
 
+    /// <https://html.spec.whatwg.org/multipage/#concept-input-apply>
+    fn does_value_as_date_apply(&self) -> bool {
+        match self.input_type() {
+            InputType::Date | InputType::Month | InputType::Week | InputType::Time => true,
+            _ => false,
+        }
+    }
+
+    /// <https://html.spec.whatwg.org/multipage/#concept-input-apply>
+    fn does_value_as_number_apply(&self) -> bool {
+        match self.input_type() {
+            InputType::Date | InputType::Month | InputType::Week | InputType::Time |
+            InputType::DatetimeLocal | InputType::Number | InputType::Range => true,
+            _ => false,
+        }
+    }
+
+    /// <https://html.spec.whatwg.org/multipage/#concept-input-value-string-date>
+    /// Also used for the date-based types' string to number conversion, which
+    /// counts milliseconds from the same instant.
+    fn convert_string_to_naive_datetime(&self, value: &str) -> Option<NaiveDateTime> {
+        match self.input_type() {
+            InputType::Date => {
+                parse_date_string(value).ok()
+                    .and_then(|(year, month, day)| NaiveDate::from_ymd_opt(year as i32, month, day))
+                    .map(|date| date.and_hms(0, 0, 0))
+            },
+            InputType::Month => {
+                parse_month_string(value).ok()
+                    .and_then(|(year, month)| NaiveDate::from_ymd_opt(year as i32, month, 1))
+                    .map(|date| date.and_hms(0, 0, 0))
+            },
+            InputType::Week => {
+                parse_week_string(value).ok()
+                    .and_then(|(year, week)| NaiveDate::from_isoywd_opt(year as i32, week, Weekday::Mon))
+                    .map(|date| date.and_hms(0, 0, 0))
+            },
+            InputType::Time => {
+                if !DOMString::from(value).is_valid_time_string() {
+                    return None;
+                }
+                parse_time_component(value).ok()
+                    .and_then(|time| naive_time_from_components(time))
+                    .map(|time| NaiveDate::from_ymd(1970, 1, 1).and_time(time))
+            },
+            InputType::DatetimeLocal => {
+                parse_local_date_and_time_string(value).ok()
+                    .and_then(|((year, month, day), time)| {
+                        NaiveDate::from_ymd_opt(year as i32, month, day)
+                            .and_then(|date| naive_time_from_components(time).map(|time| date.and_time(time)))
+                    })
+            },
+            _ => None,
+        }
+    }
+
+    /// <https://html.spec.whatwg.org/multipage/#concept-input-value-date-string>
+    fn convert_naive_datetime_to_string(&self, datetime: &NaiveDateTime) -> Option<DOMString> {
+        // Valid date, month and week strings need a year of at least 1.
+        if datetime.year() < 1 {
+            return None;
+        }
+        let value = match self.input_type() {
+            InputType::Date => format!("{:04}-{:02}-{:02}", datetime.year(), datetime.month(), datetime.day()),
+            InputType::Month => format!("{:04}-{:02}", datetime.year(), datetime.month()),
+            InputType::Week => {
+                let week = datetime.iso_week();
+                format!("{:04}-W{:02}", week.year(), week.week())
+            },
+            InputType::Time => format_time(&datetime.time()),
+            InputType::DatetimeLocal => {
+                format!("{:04}-{:02}-{:02}T{}",
+                        datetime.year(), datetime.month(), datetime.day(), format_time(&datetime.time()))
+            },
+            _ => return None,
+        };
+        Some(DOMString::from(value))
+    }
+
+    /// <https://html.spec.whatwg.org/multipage/#concept-input-value-string-number>
+    fn convert_string_to_number(&self, value: &str) -> Option<f64> {
+        match self.input_type() {
+            // The number of months between January 1970 and the parsed month.
+            InputType::Month => {
+                parse_month_string(value).ok()
+                    .map(|(year, month)| (year as f64 - 1970.0) * 12.0 + (month as f64 - 1.0))
+            },
+            InputType::Date | InputType::Week | InputType::Time | InputType::DatetimeLocal => {
+                self.convert_string_to_naive_datetime(value)
+                    .map(|datetime| naive_datetime_to_milliseconds(&datetime))
+            },
+            InputType::Number | InputType::Range => {
+                if !DOMString::from(value).is_valid_floating_point_number_string() {
+                    return None;
+                }
+                value.parse::<f64>().ok().and_then(|number| if number.is_finite() { Some(number) } else { None })
+            },
+            _ => None,
+        }
+    }
+
+    /// <https://html.spec.whatwg.org/multipage/#concept-input-value-number-string>
+    fn convert_number_to_string(&self, value: f64) -> Option<DOMString> {
+        match self.input_type() {
+            InputType::Month => {
+                let months = value.floor();
+                let year = 1970.0 + (months / 12.0).floor();
+                let month = months - (year - 1970.0) * 12.0 + 1.0;
+                if year < 1.0 || year > i32::max_value() as f64 {
+                    return None;
+                }
+                NaiveDate::from_ymd_opt(year as i32, month as u32, 1)
+                    .and_then(|date| self.convert_naive_datetime_to_string(&date.and_hms(0, 0, 0)))
+            },
+            InputType::Date | InputType::Week | InputType::Time | InputType::DatetimeLocal => {
+                milliseconds_to_naive_datetime(value)
+                    .and_then(|datetime| self.convert_naive_datetime_to_string(&datetime))
+            },
+            InputType::Number | InputType::Range => {
+                // Negative zero serializes as "0", not "-0".
+                let value = if value == 0.0 { 0.0 } else { value };
+                Some(DOMString::from(value.to_string()))
+            },
+            _ => None,
+        }
+    }
+
     #[allow(unrooted_must_root)]
     fn selection(&self) -> TextControlSelection<Self> {
         TextControlSelection::new(&self, &self.textinput)
@@ -2,14 +2,17 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use dom::attr::Attr;
 use dom::bindings::codegen::Bindings::HTMLLIElementBinding;
 use dom::bindings::codegen::Bindings::HTMLLIElementBinding::HTMLLIElementMethods;
 use dom::bindings::inheritance::Castable;
-use dom::bindings::root::DomRoot;
+use dom::bindings::root::{DomRoot, LayoutDom};
 use dom::bindings::str::DOMString;
 use dom::document::Document;
+use dom::element::{Element, RawLayoutElementHelpers};
 use dom::htmlelement::HTMLElement;
-use dom::node::Node;
+use dom::htmlolistelement::{HTMLOListElement, HTMLOListElementLayoutHelpers};
+use dom::node::{LayoutNodeHelpers, Node};
 use dom::virtualmethods::VirtualMethods;
 use dom_struct::dom_struct;
 use html5ever::{LocalName, Prefix};
@@ -50,6 +53,14 @@ impl VirtualMethods for HTMLLIElement {
         Some(self.upcast::<HTMLElement>() as &VirtualMethods)
     }
 
+    fn attribute_affects_presentational_hints(&self, attr: &Attr) -> bool {
+        if attr.local_name() == &local_name!("value") {
+            return true;
+        }
+
+        self.super_type().unwrap().attribute_affects_presentational_hints(attr)
+    }
+
     fn parse_plain_attribute(&self, name: &LocalName, value: DOMString) -> AttrValue {
         match name {
             &local_name!("value") => AttrValue::from_i32(value.into(), 0),
@@ -57,3 +68,40 @@ impl VirtualMethods for HTMLLIElement {
         }
     }
 }
+
+pub trait HTMLLIElementLayoutHelpers {
+    fn get_list_item_counter_reset(&self) -> Option<i32>;
+    fn get_list_item_counter_increment(&self) -> Option<i32>;
+}
+
+impl HTMLLIElementLayoutHelpers for LayoutDom<HTMLLIElement> {
+    /// The value the `list-item` counter is reset to so that this item gets
+    /// the ordinal value given by its `value` attribute, if any.
+    ///
+    /// https://html.spec.whatwg.org/multipage/#ordinal-value
+    #[allow(unsafe_code)]
+    fn get_list_item_counter_reset(&self) -> Option<i32> {
+        let value = unsafe {
+            (&*self.upcast::<Element>().unsafe_get())
+                .get_attr_for_layout(&ns!(), &local_name!("value"))
+                .and_then(|value| match *value {
+                    AttrValue::Int(_, value) => Some(value),
+                    _ => None,
+                })
+        };
+        value.map(|value| match self.get_list_item_counter_increment() {
+            Some(_) => value.saturating_add(1),
+            None => value.saturating_sub(1),
+        })
+    }
+
+    /// Items of a reversed list count down rather than up.
+    #[allow(unsafe_code)]
+    fn get_list_item_counter_increment(&self) -> Option<i32> {
+        let parent = unsafe { self.upcast::<Node>().parent_node_ref() };
+        match parent.and_then(|parent| parent.downcast::<HTMLOListElement>()) {
+            Some(ref list) if list.get_reversed() => Some(-1),
+            _ => None,
+        }
+    }
+}
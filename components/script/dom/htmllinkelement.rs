@@ -26,6 +26,7 @@ use html5ever::{LocalName, Prefix};
 use net_traits::ReferrerPolicy;
 use script_traits::ScriptMsg;
 use servo_arc::Arc;
+use servo_atoms::Atom;
 use std::borrow::ToOwned;
 use std::cell::Cell;
 use std::default::Default;
@@ -395,7 +396,15 @@ impl HTMLLinkElementMethods for HTMLLinkElement {
 
     // https://html.spec.whatwg.org/multipage/#dom-link-rellist
     fn RelList(&self) -> DomRoot<DOMTokenList> {
-        self.rel_list.or_init(|| DOMTokenList::new(self.upcast(), &local_name!("rel")))
+        self.rel_list.or_init(|| {
+            DOMTokenList::new(self.upcast(), &local_name!("rel"), Some(vec![
+                Atom::from("alternate"),
+                Atom::from("apple-touch-icon"),
+                Atom::from("icon"),
+                Atom::from("noreferrer"),
+                Atom::from("stylesheet"),
+            ]))
+        })
     }
 
     // https://html.spec.whatwg.org/multipage/#dom-link-charset
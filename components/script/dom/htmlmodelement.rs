@@ -2,8 +2,9 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use dom::bindings::codegen::Bindings::HTMLModElementBinding;
+use dom::bindings::codegen::Bindings::HTMLModElementBinding::{self, HTMLModElementMethods};
 use dom::bindings::root::DomRoot;
+use dom::bindings::str::DOMString;
 use dom::document::Document;
 use dom::htmlelement::HTMLElement;
 use dom::node::Node;
@@ -34,3 +35,17 @@ impl HTMLModElement {
                            HTMLModElementBinding::Wrap)
     }
 }
+
+impl HTMLModElementMethods for HTMLModElement {
+    // https://html.spec.whatwg.org/multipage/#dom-mod-cite
+    make_url_getter!(Cite, "cite");
+
+    // https://html.spec.whatwg.org/multipage/#dom-mod-cite
+    make_url_setter!(SetCite, "cite");
+
+    // https://html.spec.whatwg.org/multipage/#dom-mod-datetime
+    make_getter!(DateTime, "datetime");
+
+    // https://html.spec.whatwg.org/multipage/#dom-mod-datetime
+    make_setter!(SetDateTime, "datetime");
+}
@@ -2,13 +2,21 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use dom::attr::Attr;
 use dom::bindings::codegen::Bindings::HTMLOListElementBinding;
-use dom::bindings::root::DomRoot;
+use dom::bindings::codegen::Bindings::HTMLOListElementBinding::HTMLOListElementMethods;
+use dom::bindings::inheritance::Castable;
+use dom::bindings::root::{DomRoot, LayoutDom};
+use dom::bindings::str::DOMString;
 use dom::document::Document;
+use dom::element::{AttributeMutation, Element, RawLayoutElementHelpers};
 use dom::htmlelement::HTMLElement;
-use dom::node::Node;
+use dom::htmllielement::HTMLLIElement;
+use dom::node::{ChildrenMutation, LayoutNodeHelpers, Node, NodeDamage};
+use dom::virtualmethods::VirtualMethods;
 use dom_struct::dom_struct;
 use html5ever::{LocalName, Prefix};
+use style::attr::{AttrValue, parse_integer};
 
 #[dom_struct]
 pub struct HTMLOListElement {
@@ -32,4 +40,120 @@ impl HTMLOListElement {
                            document,
                            HTMLOListElementBinding::Wrap)
     }
+
+    /// Restyles the list items of this list, whose numbering depends on
+    /// whether the list is reversed.
+    fn dirty_list_items(&self) {
+        for child in self.upcast::<Node>().children() {
+            if child.is::<HTMLLIElement>() {
+                child.dirty(NodeDamage::OtherNodeDamage);
+            }
+        }
+    }
+}
+
+impl HTMLOListElementMethods for HTMLOListElement {
+    // https://html.spec.whatwg.org/multipage/#dom-ol-reversed
+    make_bool_getter!(Reversed, "reversed");
+
+    // https://html.spec.whatwg.org/multipage/#dom-ol-reversed
+    make_bool_setter!(SetReversed, "reversed");
+
+    // https://html.spec.whatwg.org/multipage/#dom-ol-start
+    make_int_getter!(Start, "start", 1);
+
+    // https://html.spec.whatwg.org/multipage/#dom-ol-start
+    make_int_setter!(SetStart, "start");
+
+    // https://html.spec.whatwg.org/multipage/#dom-ol-type
+    make_getter!(Type, "type");
+
+    // https://html.spec.whatwg.org/multipage/#dom-ol-type
+    make_setter!(SetType, "type");
+}
+
+impl VirtualMethods for HTMLOListElement {
+    fn super_type(&self) -> Option<&VirtualMethods> {
+        Some(self.upcast::<HTMLElement>() as &VirtualMethods)
+    }
+
+    fn attribute_affects_presentational_hints(&self, attr: &Attr) -> bool {
+        match attr.local_name() {
+            &local_name!("reversed") | &local_name!("start") => true,
+            _ => self.super_type().unwrap().attribute_affects_presentational_hints(attr),
+        }
+    }
+
+    fn attribute_mutated(&self, attr: &Attr, mutation: AttributeMutation) {
+        self.super_type().unwrap().attribute_mutated(attr, mutation);
+        if attr.local_name() == &local_name!("reversed") {
+            self.dirty_list_items();
+        }
+    }
+
+    fn children_changed(&self, mutation: &ChildrenMutation) {
+        if let Some(ref s) = self.super_type() {
+            s.children_changed(mutation);
+        }
+
+        // The starting value of a reversed list without a start attribute
+        // depends on the number of list items it owns.
+        let element = self.upcast::<Element>();
+        if element.has_attribute(&local_name!("reversed")) {
+            self.upcast::<Node>().dirty(NodeDamage::OtherNodeDamage);
+        }
+    }
+
+    fn parse_plain_attribute(&self, name: &LocalName, value: DOMString) -> AttrValue {
+        match name {
+            &local_name!("start") => AttrValue::from_i32(value.into(), 1),
+            _ => self.super_type().unwrap().parse_plain_attribute(name, value),
+        }
+    }
+}
+
+pub trait HTMLOListElementLayoutHelpers {
+    fn get_reversed(&self) -> bool;
+    fn get_list_item_counter_reset(&self) -> i32;
+}
+
+impl HTMLOListElementLayoutHelpers for LayoutDom<HTMLOListElement> {
+    #[allow(unsafe_code)]
+    fn get_reversed(&self) -> bool {
+        unsafe {
+            (&*self.upcast::<Element>().unsafe_get())
+                .get_attr_for_layout(&ns!(), &local_name!("reversed"))
+                .is_some()
+        }
+    }
+
+    /// The value the `list-item` counter is reset to, so that the first list
+    /// item of this list gets its starting value once it increments it.
+    ///
+    /// https://html.spec.whatwg.org/multipage/#concept-ol-start
+    #[allow(unsafe_code)]
+    fn get_list_item_counter_reset(&self) -> i32 {
+        let start = unsafe {
+            (&*self.upcast::<Element>().unsafe_get())
+                .get_attr_val_for_layout(&ns!(), &local_name!("start"))
+                .and_then(|value| parse_integer(value.chars()).ok())
+        };
+
+        if !self.get_reversed() {
+            return start.unwrap_or(1).saturating_sub(1);
+        }
+
+        let start = start.unwrap_or_else(|| unsafe {
+            let mut count = 0;
+            let mut child = self.upcast::<Node>().first_child_ref();
+            while let Some(node) = child {
+                if node.downcast::<HTMLLIElement>().is_some() {
+                    count += 1;
+                }
+                child = node.next_sibling_ref();
+            }
+            count
+        });
+        start.saturating_add(1)
+    }
 }
@@ -2,8 +2,9 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use dom::bindings::codegen::Bindings::HTMLParagraphElementBinding;
+use dom::bindings::codegen::Bindings::HTMLParagraphElementBinding::{self, HTMLParagraphElementMethods};
 use dom::bindings::root::DomRoot;
+use dom::bindings::str::DOMString;
 use dom::document::Document;
 use dom::htmlelement::HTMLElement;
 use dom::node::Node;
@@ -34,3 +35,11 @@ impl HTMLParagraphElement {
                            HTMLParagraphElementBinding::Wrap)
     }
 }
+
+impl HTMLParagraphElementMethods for HTMLParagraphElement {
+    // https://html.spec.whatwg.org/multipage/#dom-p-align
+    make_getter!(Align, "align");
+
+    // https://html.spec.whatwg.org/multipage/#dom-p-align
+    make_setter!(SetAlign, "align");
+}
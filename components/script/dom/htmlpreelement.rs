@@ -2,13 +2,17 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use dom::bindings::codegen::Bindings::HTMLPreElementBinding;
+use dom::bindings::codegen::Bindings::HTMLPreElementBinding::{self, HTMLPreElementMethods};
+use dom::bindings::inheritance::Castable;
 use dom::bindings::root::DomRoot;
+use dom::bindings::str::DOMString;
 use dom::document::Document;
 use dom::htmlelement::HTMLElement;
 use dom::node::Node;
+use dom::virtualmethods::VirtualMethods;
 use dom_struct::dom_struct;
 use html5ever::{LocalName, Prefix};
+use style::attr::AttrValue;
 
 #[dom_struct]
 pub struct HTMLPreElement {
@@ -34,3 +38,24 @@ impl HTMLPreElement {
                            HTMLPreElementBinding::Wrap)
     }
 }
+
+impl HTMLPreElementMethods for HTMLPreElement {
+    // https://html.spec.whatwg.org/multipage/#dom-pre-width
+    make_int_getter!(Width, "width", 0);
+
+    // https://html.spec.whatwg.org/multipage/#dom-pre-width
+    make_int_setter!(SetWidth, "width", 0);
+}
+
+impl VirtualMethods for HTMLPreElement {
+    fn super_type(&self) -> Option<&VirtualMethods> {
+        Some(self.upcast::<HTMLElement>() as &VirtualMethods)
+    }
+
+    fn parse_plain_attribute(&self, name: &LocalName, value: DOMString) -> AttrValue {
+        match name {
+            &local_name!("width") => AttrValue::from_i32(value.into(), 0),
+            _ => self.super_type().unwrap().parse_plain_attribute(name, value),
+        }
+    }
+}
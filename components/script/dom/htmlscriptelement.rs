@@ -110,6 +110,13 @@ static SCRIPT_JS_MIMES: StaticStringVec = &[
     "text/x-javascript",
 ];
 
+/// <https://html.spec.whatwg.org/multipage/#concept-script-type>
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ScriptType {
+    Classic,
+    Module,
+}
+
 #[derive(JSTraceable, MallocSizeOf)]
 pub struct ClassicScript {
     text: DOMString,
@@ -314,9 +321,10 @@ impl HTMLScriptElement {
         }
 
         // Step 6.
-        if !self.is_javascript() {
-            return;
-        }
+        let script_type = match self.get_script_type() {
+            Some(script_type) => script_type,
+            None => return,
+        };
 
         // Step 7.
         if was_parser_inserted {
@@ -338,7 +346,13 @@ impl HTMLScriptElement {
             return;
         }
 
-        // TODO: Step 11: nomodule content attribute
+        // TODO: Step 11: nomodule content attribute. Module scripts are not
+        // executed yet, so nomodule scripts are still needed as a fallback.
+
+        // TODO: Fetch and evaluate module scripts.
+        if script_type == ScriptType::Module {
+            return;
+        }
 
         // TODO(#4577): Step 12: CSP.
 
@@ -587,7 +601,9 @@ impl HTMLScriptElement {
                             EventCancelable::NotCancelable);
     }
 
-    pub fn is_javascript(&self) -> bool {
+    /// Steps 6.1-6.5 of <https://html.spec.whatwg.org/multipage/#prepare-a-script>,
+    /// returning `None` if the script block's type is not supported.
+    fn get_script_type(&self) -> Option<ScriptType> {
         let element = self.upcast::<Element>();
         let type_attr = element.get_attribute(&ns!(), &local_name!("type"));
         let is_js = match type_attr.as_ref().map(|s| s.value()) {
@@ -596,6 +612,10 @@ impl HTMLScriptElement {
                 debug!("script type empty, inferring js");
                 true
             },
+            Some(ref s) if s.eq_ignore_ascii_case("module") => {
+                debug!("script type=module");
+                return Some(ScriptType::Module);
+            },
             Some(s) => {
                 debug!("script type={}", &**s);
                 SCRIPT_JS_MIMES.contains(&s.to_ascii_lowercase().trim_matches(HTML_SPACE_CHARACTERS))
@@ -623,8 +643,11 @@ impl HTMLScriptElement {
                 is_js
             }
         };
-        // https://github.com/rust-lang/rust/issues/21114
-        is_js
+        if is_js {
+            Some(ScriptType::Classic)
+        } else {
+            None
+        }
     }
 
     pub fn set_parser_inserted(&self, parser_inserted: bool) {
@@ -708,6 +731,16 @@ impl HTMLScriptElementMethods for HTMLScriptElement {
     // https://html.spec.whatwg.org/multipage/#dom-script-type
     make_setter!(SetType, "type");
 
+    // https://html.spec.whatwg.org/multipage/#dom-script-nomodule
+    fn NoModule(&self) -> bool {
+        self.upcast::<Element>().has_attribute(&LocalName::from("nomodule"))
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-script-nomodule
+    fn SetNoModule(&self, value: bool) {
+        self.upcast::<Element>().set_bool_attribute(&LocalName::from("nomodule"), value)
+    }
+
     // https://html.spec.whatwg.org/multipage/#dom-script-charset
     make_getter!(Charset, "charset");
     // https://html.spec.whatwg.org/multipage/#dom-script-charset
@@ -2,10 +2,11 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use chrono::{Datelike, Duration, NaiveDate, Timelike};
 use dom::bindings::codegen::Bindings::HTMLTimeElementBinding;
 use dom::bindings::codegen::Bindings::HTMLTimeElementBinding::HTMLTimeElementMethods;
 use dom::bindings::root::DomRoot;
-use dom::bindings::str::DOMString;
+use dom::bindings::str::{DOMString, max_day_in_month, parse_date_string, parse_time_component};
 use dom::document::Document;
 use dom::htmlelement::HTMLElement;
 use dom::node::Node;
@@ -41,3 +42,276 @@ impl HTMLTimeElementMethods for HTMLTimeElement {
     // https://html.spec.whatwg.org/multipage/#dom-time-datetime
     make_setter!(SetDateTime, "datetime");
 }
+
+/// The kinds of machine-readable value a `<time>` element can represent.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DateTimeKind {
+    Month,
+    Date,
+    YearlessDate,
+    Time,
+    LocalDateAndTime,
+    TimeZoneOffset,
+    GlobalDateAndTime,
+    Week,
+    Year,
+    Duration,
+}
+
+/// Parses a datetime value in the order listed by
+/// https://html.spec.whatwg.org/multipage/#the-time-element,
+/// returning the kind of value it represents and its normalized form.
+pub fn parse_a_datetime_value(value: &str) -> Option<(DateTimeKind, String)> {
+    let string = DOMString::from(value);
+    if string.is_valid_month_string() {
+        return Some((DateTimeKind::Month, value.to_owned()));
+    }
+    if string.is_valid_date_string() {
+        return Some((DateTimeKind::Date, value.to_owned()));
+    }
+    if let Some((month, day)) = parse_yearless_date_string(value) {
+        return Some((DateTimeKind::YearlessDate, format!("{:02}-{:02}", month, day)));
+    }
+    if let Some(time) = parse_time_string(value) {
+        return Some((DateTimeKind::Time, normalize_time(time)));
+    }
+    if let Some(((year, month, day), time)) = parse_local_date_and_time_string(value) {
+        let normalized = format!("{:04}-{:02}-{:02}T{}", year, month, day, normalize_time(time));
+        return Some((DateTimeKind::LocalDateAndTime, normalized));
+    }
+    if let Some(offset) = parse_time_zone_offset_string(value) {
+        return Some((DateTimeKind::TimeZoneOffset, normalize_time_zone_offset(offset)));
+    }
+    if let Some(normalized) = parse_global_date_and_time_string(value) {
+        return Some((DateTimeKind::GlobalDateAndTime, normalized));
+    }
+    if string.is_valid_week_string() {
+        return Some((DateTimeKind::Week, value.to_owned()));
+    }
+    if is_valid_year_string(value) {
+        return Some((DateTimeKind::Year, value.to_owned()));
+    }
+    if let Some(seconds) = parse_duration_string(value) {
+        return Some((DateTimeKind::Duration, format!("PT{}S", seconds)));
+    }
+    None
+}
+
+/// https://html.spec.whatwg.org/multipage/#parse-a-yearless-date-string
+fn parse_yearless_date_string(value: &str) -> Option<(u32, u32)> {
+    let value = if value.starts_with("--") { &value[2..] } else { value };
+    let mut iterator = value.split('-');
+    let month = iterator.next()?;
+    let day = iterator.next()?;
+    if iterator.next().is_some() || month.len() != 2 || day.len() != 2 {
+        return None;
+    }
+    if !month.bytes().chain(day.bytes()).all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let month = month.parse::<u32>().ok()?;
+    let day = day.parse::<u32>().ok()?;
+    // Any leap year gives the maximum number of days in February.
+    let max_day = max_day_in_month(4, month).ok()?;
+    if day == 0 || day > max_day {
+        return None;
+    }
+    Some((month, day))
+}
+
+/// https://html.spec.whatwg.org/multipage/#parse-a-time-string
+fn parse_time_string(value: &str) -> Option<(u32, u32, f32)> {
+    if !DOMString::from(value).is_valid_time_string() {
+        return None;
+    }
+    parse_time_component(value).ok()
+}
+
+/// https://html.spec.whatwg.org/multipage/#parse-a-local-date-and-time-string
+fn parse_local_date_and_time_string(value: &str) -> Option<((u32, u32, u32), (u32, u32, f32))> {
+    let index = value.find(|c| c == 'T' || c == ' ')?;
+    let date = parse_date_string(&value[..index]).ok()?;
+    let time = parse_time_string(&value[index + 1..])?;
+    Some((date, time))
+}
+
+/// https://html.spec.whatwg.org/multipage/#parse-a-time-zone-offset-string
+///
+/// Returns the offset in minutes.
+fn parse_time_zone_offset_string(value: &str) -> Option<i32> {
+    if value == "Z" {
+        return Some(0);
+    }
+
+    let sign = match value.chars().next()? {
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+    // Work on bytes so that non-ASCII input can't split a character.
+    let rest = value[1..].as_bytes();
+    let (hours, minutes) = match rest.len() {
+        4 => (&rest[..2], &rest[2..]),
+        5 if rest[2] == b':' => (&rest[..2], &rest[3..]),
+        _ => return None,
+    };
+    if !hours.iter().chain(minutes).all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let two_digits = |digits: &[u8]| ((digits[0] - b'0') * 10 + (digits[1] - b'0')) as i32;
+    let hours = two_digits(hours);
+    let minutes = two_digits(minutes);
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    Some(sign * (hours * 60 + minutes))
+}
+
+/// https://html.spec.whatwg.org/multipage/#parse-a-global-date-and-time-string
+///
+/// Returns the valid normalized forced-UTC global date and time string.
+fn parse_global_date_and_time_string(value: &str) -> Option<String> {
+    let zone_index = value.rfind(|c| c == 'Z' || c == '+' || c == '-')?;
+    let ((year, month, day), (hour, minute, second)) =
+        parse_local_date_and_time_string(&value[..zone_index])?;
+    let offset = parse_time_zone_offset_string(&value[zone_index..])?;
+
+    let milliseconds = (second.fract() * 1000.).round() as u32;
+    let local = NaiveDate::from_ymd_opt(year as i32, month, day)?
+        .and_hms_milli_opt(hour, minute, second.trunc() as u32, milliseconds)?;
+    let utc = local.checked_sub_signed(Duration::minutes(offset as i64))?;
+
+    let second = utc.second() as f32 + utc.nanosecond() as f32 / 1e9;
+    Some(format!("{:04}-{:02}-{:02}T{}Z",
+                 utc.year(), utc.month(), utc.day(),
+                 normalize_time((utc.hour(), utc.minute(), second))))
+}
+
+/// https://html.spec.whatwg.org/multipage/#valid-year-string
+fn is_valid_year_string(value: &str) -> bool {
+    value.len() >= 4 &&
+        value.bytes().all(|b| b.is_ascii_digit()) &&
+        value.parse::<u32>().map_or(false, |year| year > 0)
+}
+
+/// https://html.spec.whatwg.org/multipage/#valid-duration-string
+///
+/// Returns the number of seconds the duration represents.
+fn parse_duration_string(value: &str) -> Option<f64> {
+    if value.starts_with('P') {
+        parse_iso_duration_string(&value[1..])
+    } else {
+        parse_duration_components(value)
+    }
+}
+
+/// The "P" form of a valid duration string, with the leading "P" removed.
+fn parse_iso_duration_string(value: &str) -> Option<f64> {
+    let (date, time) = match value.find('T') {
+        Some(index) => (&value[..index], Some(&value[index + 1..])),
+        None => (value, None),
+    };
+
+    let mut seconds = 0.;
+    let mut found_component = false;
+    if !date.is_empty() {
+        if !date.ends_with('D') {
+            return None;
+        }
+        seconds += parse_duration_number(&date[..date.len() - 1], false)? * 86400.;
+        found_component = true;
+    }
+
+    if let Some(mut time) = time {
+        if time.is_empty() {
+            return None;
+        }
+        for &(unit, scale) in &[('H', 3600.), ('M', 60.), ('S', 1.)] {
+            if let Some(index) = time.find(unit) {
+                seconds += parse_duration_number(&time[..index], unit == 'S')? * scale;
+                time = &time[index + 1..];
+                found_component = true;
+            }
+        }
+        if !time.is_empty() {
+            return None;
+        }
+    }
+
+    if found_component { Some(seconds) } else { None }
+}
+
+/// One or more duration time components, such as "1h 30m".
+fn parse_duration_components(value: &str) -> Option<f64> {
+    let mut seconds = 0.;
+    let mut seen_units = String::new();
+    let mut rest = value.trim_left_matches(|c: char| c.is_ascii_whitespace());
+    if rest.is_empty() {
+        return None;
+    }
+
+    while !rest.is_empty() {
+        let number_end = rest.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+        let number = &rest[..number_end];
+        rest = rest[number_end..].trim_left_matches(|c: char| c.is_ascii_whitespace());
+
+        let unit = rest.chars().next()?.to_ascii_uppercase();
+        let scale = match unit {
+            'W' => 604800.,
+            'D' => 86400.,
+            'H' => 3600.,
+            'M' => 60.,
+            'S' => 1.,
+            _ => return None,
+        };
+        if seen_units.contains(unit) {
+            return None;
+        }
+        seen_units.push(unit);
+
+        seconds += parse_duration_number(number, unit == 'S')? * scale;
+        rest = rest[1..].trim_left_matches(|c: char| c.is_ascii_whitespace());
+    }
+
+    Some(seconds)
+}
+
+/// One or more ASCII digits, optionally followed by a fraction of one to
+/// three digits when `allow_fraction` is set.
+fn parse_duration_number(value: &str, allow_fraction: bool) -> Option<f64> {
+    let mut parts = value.splitn(2, '.');
+    let integer = parts.next()?;
+    if integer.is_empty() || !integer.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    if let Some(fraction) = parts.next() {
+        if !allow_fraction || fraction.is_empty() || fraction.len() > 3 ||
+           !fraction.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+    }
+    value.parse::<f64>().ok()
+}
+
+/// Serializes a time as the shortest valid time string that represents it.
+fn normalize_time((hour, minute, second): (u32, u32, f32)) -> String {
+    if second == 0. {
+        format!("{:02}:{:02}", hour, minute)
+    } else if second.fract() == 0. {
+        format!("{:02}:{:02}:{:02}", hour, minute, second as u32)
+    } else {
+        let seconds = format!("{:06.3}", second);
+        format!("{:02}:{:02}:{}", hour, minute, seconds.trim_right_matches('0'))
+    }
+}
+
+/// Serializes a time-zone offset in minutes as "Z" or "+HH:MM".
+fn normalize_time_zone_offset(offset: i32) -> String {
+    if offset == 0 {
+        return "Z".to_owned();
+    }
+    let sign = if offset < 0 { '-' } else { '+' };
+    format!("{}{:02}:{:02}", sign, offset.abs() / 60, offset.abs() % 60)
+}
@@ -36,6 +36,7 @@ pub struct KeyboardEvent {
     is_composing: Cell<bool>,
     char_code: Cell<Option<u32>>,
     key_code: Cell<u32>,
+    which: Cell<Option<u32>>,
     printable: Cell<Option<char>>,
 }
 
@@ -55,6 +56,7 @@ impl KeyboardEvent {
             is_composing: Cell::new(false),
             char_code: Cell::new(None),
             key_code: Cell::new(0),
+            which: Cell::new(None),
             printable: Cell::new(None),
         }
     }
@@ -70,7 +72,7 @@ impl KeyboardEvent {
                can_bubble: bool,
                cancelable: bool,
                view: Option<&Window>,
-               _detail: i32,
+               detail: i32,
                ch: Option<char>,
                key: Option<Key>,
                key_string: DOMString,
@@ -85,8 +87,10 @@ impl KeyboardEvent {
                char_code: Option<u32>,
                key_code: u32) -> DomRoot<KeyboardEvent> {
         let ev = KeyboardEvent::new_uninitialized(window);
-        ev.InitKeyboardEvent(type_, can_bubble, cancelable, view, key_string, location,
-                             DOMString::new(), repeat, DOMString::new());
+        ev.upcast::<UIEvent>().InitUIEvent(type_, can_bubble, cancelable, view, detail);
+        *ev.key_string.borrow_mut() = key_string;
+        ev.location.set(location);
+        ev.repeat.set(repeat);
         ev.key.set(key);
         *ev.code.borrow_mut() = code;
         ev.ctrl.set(ctrl_key);
@@ -114,7 +118,8 @@ impl KeyboardEvent {
                                        init.key.clone(), init.code.clone(), init.location,
                                        init.repeat, init.isComposing, init.parent.ctrlKey,
                                        init.parent.altKey, init.parent.shiftKey, init.parent.metaKey,
-                                       None, 0);
+                                       Some(init.charCode), init.keyCode);
+        event.which.set(Some(init.which));
         Ok(event)
     }
 
@@ -827,7 +832,7 @@ impl KeyboardEventMethods for KeyboardEvent {
     // https://w3c.github.io/uievents/#dom-keyboardevent-getmodifierstate
     fn GetModifierState(&self, key_arg: DOMString) -> bool {
         match &*key_arg {
-            "Ctrl" => self.CtrlKey(),
+            "Control" => self.CtrlKey(),
             "Alt" => self.AltKey(),
             "Shift" => self.ShiftKey(),
             "Meta" => self.MetaKey(),
@@ -849,7 +854,7 @@ impl KeyboardEventMethods for KeyboardEvent {
 
     // https://w3c.github.io/uievents/#widl-KeyboardEvent-which
     fn Which(&self) -> u32 {
-        self.char_code.get().unwrap_or(self.KeyCode())
+        self.which.get().unwrap_or_else(|| self.char_code.get().unwrap_or(self.KeyCode()))
     }
 
     // https://dom.spec.whatwg.org/#dom-event-istrusted
@@ -56,6 +56,23 @@ impl Location {
         }
     }
 
+    /// Parses `url` relative to the document's base URL and navigates to it.
+    /// A URL that differs from the current one only by its fragment results in
+    /// a same-document fragment navigation instead of a new load.
+    /// <https://html.spec.whatwg.org/multipage/#location-object-navigate>
+    fn navigate(&self, url: USVString, replace: bool) -> ErrorResult {
+        // TODO: per spec, we should use the _API base URL_ specified by the
+        //       _entry settings object_.
+        let base_url = self.window.Document().base_url();
+        match base_url.join(&url.0) {
+            Ok(url) => {
+                self.window.load_url(url, replace, false, None);
+                Ok(())
+            },
+            Err(_) => Err(Error::Syntax),
+        }
+    }
+
     // https://html.spec.whatwg.org/multipage/#dom-location-reload
     pub fn reload_without_origin_check(&self) {
         self.window.load_url(self.get_url(), true, true, None);
@@ -71,15 +88,7 @@ impl LocationMethods for Location {
     // https://html.spec.whatwg.org/multipage/#dom-location-assign
     fn Assign(&self, url: USVString) -> ErrorResult {
         self.check_same_origin_domain()?;
-        // TODO: per spec, we should use the _API base URL_ specified by the
-        //       _entry settings object_.
-        let base_url = self.window.get_url();
-        if let Ok(url) = base_url.join(&url.0) {
-            self.window.load_url(url, false, false, None);
-            Ok(())
-        } else {
-            Err(Error::Syntax)
-        }
+        self.navigate(url, false)
     }
 
     // https://html.spec.whatwg.org/multipage/#dom-location-reload
@@ -92,15 +101,7 @@ impl LocationMethods for Location {
     // https://html.spec.whatwg.org/multipage/#dom-location-replace
     fn Replace(&self, url: USVString) -> ErrorResult {
         // Note: no call to self.check_same_origin_domain()
-        // TODO: per spec, we should use the _API base URL_ specified by the
-        //       _entry settings object_.
-        let base_url = self.window.get_url();
-        if let Ok(url) = base_url.join(&url.0) {
-            self.window.load_url(url, true, false, None);
-            Ok(())
-        } else {
-            Err(Error::Syntax)
-        }
+        self.navigate(url, true)
     }
 
     // https://html.spec.whatwg.org/multipage/#dom-location-hash
@@ -6,6 +6,7 @@ use cssparser::{Parser, ParserInput};
 use dom::bindings::codegen::Bindings::MediaListBinding;
 use dom::bindings::codegen::Bindings::MediaListBinding::MediaListMethods;
 use dom::bindings::codegen::Bindings::WindowBinding::WindowBinding::WindowMethods;
+use dom::bindings::error::{Error, ErrorResult};
 use dom::bindings::reflector::{DomObject, Reflector, reflect_dom_object};
 use dom::bindings::root::{Dom, DomRoot};
 use dom::bindings::str::DOMString;
@@ -135,7 +136,7 @@ impl MediaListMethods for MediaList {
     }
 
     // https://drafts.csswg.org/cssom/#dom-medialist-deletemedium
-    fn DeleteMedium(&self, medium: DOMString) {
+    fn DeleteMedium(&self, medium: DOMString) -> ErrorResult {
         // Step 1
         let mut input = ParserInput::new(&medium);
         let mut parser = Parser::new(&mut input);
@@ -149,15 +150,18 @@ impl MediaListMethods for MediaList {
         let m = MediaQuery::parse(&context, &mut parser);
         // Step 2
         if let Err(_) = m {
-            return;
+            return Ok(());
         }
         // Step 3
         let m_serialized = m.unwrap().to_css_string();
         let mut guard = self.shared_lock().write();
         let media_list = self.media_queries.write_with(&mut guard);
-        let new_vec = media_list.media_queries.drain(..)
-                                .filter(|q| m_serialized != q.to_css_string())
-                                .collect();
-        media_list.media_queries = new_vec;
+        let old_len = media_list.media_queries.len();
+        media_list.media_queries.retain(|q| m_serialized != q.to_css_string());
+        // Step 4
+        if media_list.media_queries.len() == old_len {
+            return Err(Error::NotFound);
+        }
+        Ok(())
     }
 }
@@ -49,9 +49,13 @@ impl MediaQueryList {
     }
 
     pub fn new(document: &Document, media_query_list: MediaList) -> DomRoot<MediaQueryList> {
-        reflect_dom_object(Box::new(MediaQueryList::new_inherited(document, media_query_list)),
-                           document.window(),
-                           MediaQueryListBinding::Wrap)
+        let mql = reflect_dom_object(Box::new(MediaQueryList::new_inherited(document, media_query_list)),
+                                     document.window(),
+                                     MediaQueryListBinding::Wrap);
+        // Record the matches state at creation, so that only subsequent changes
+        // are reported by evaluate_and_report_changes.
+        mql.last_match_state.set(Some(mql.evaluate()));
+        mql
     }
 }
 
@@ -99,7 +103,12 @@ impl MediaQueryListMethods for MediaQueryList {
         self.upcast::<EventTarget>().add_event_listener(
             DOMString::from_string("change".to_owned()),
             listener,
-            AddEventListenerOptions { parent: EventListenerOptions { capture: false } },
+            AddEventListenerOptions {
+                parent: EventListenerOptions { capture: false },
+                passive: false,
+                once: false,
+                signal: None,
+            },
         );
     }
 
@@ -131,6 +140,15 @@ impl WeakMediaQueryListVec {
         self.cell.borrow_mut().push(WeakRef::new(mql));
     }
 
+    /// Returns a live MediaQueryList whose media queries serialize the same
+    /// way as `media_query_list`, if any.
+    pub fn find(&self, media_query_list: &MediaList) -> Option<DomRoot<MediaQueryList>> {
+        let media = media_query_list.to_css_string();
+        self.cell.borrow().iter()
+            .filter_map(|mql| mql.root())
+            .find(|mql| mql.media_query_list.to_css_string() == media)
+    }
+
     /// Evaluate media query lists and report changes
     /// <https://drafts.csswg.org/cssom-view/#evaluate-media-queries-and-report-changes>
     pub fn evaluate_and_report_changes(&self) {
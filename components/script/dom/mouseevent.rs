@@ -2,20 +2,25 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use dom::bindings::codegen::Bindings::ElementBinding::ElementMethods;
+use dom::bindings::codegen::Bindings::EventBinding::EventMethods;
 use dom::bindings::codegen::Bindings::MouseEventBinding;
 use dom::bindings::codegen::Bindings::MouseEventBinding::MouseEventMethods;
 use dom::bindings::codegen::Bindings::UIEventBinding::UIEventMethods;
+use dom::bindings::codegen::Bindings::WindowBinding::WindowMethods;
 use dom::bindings::error::Fallible;
 use dom::bindings::inheritance::Castable;
 use dom::bindings::reflector::reflect_dom_object;
 use dom::bindings::root::{DomRoot, MutNullableDom, RootedReference};
 use dom::bindings::str::DOMString;
+use dom::element::Element;
 use dom::event::{Event, EventBubbles, EventCancelable};
 use dom::eventtarget::EventTarget;
+use dom::node::Node;
 use dom::uievent::UIEvent;
 use dom::window::Window;
 use dom_struct::dom_struct;
-use euclid::Point2D;
+use euclid::{Point2D, Vector2D};
 use servo_config::prefs::PREFS;
 use std::cell::Cell;
 use std::default::Default;
@@ -33,7 +38,9 @@ pub struct MouseEvent {
     meta_key: Cell<bool>,
     button: Cell<i16>,
     related_target: MutNullableDom<EventTarget>,
-    point_in_target: Cell<Option<Point2D<f32>>>
+    point_in_target: Cell<Option<Point2D<f32>>>,
+    movement_x: Cell<i32>,
+    movement_y: Cell<i32>,
 }
 
 impl MouseEvent {
@@ -51,6 +58,8 @@ impl MouseEvent {
             button: Cell::new(0),
             related_target: Default::default(),
             point_in_target: Cell::new(None),
+            movement_x: Cell::new(0),
+            movement_y: Cell::new(0),
         }
     }
 
@@ -108,12 +117,50 @@ impl MouseEvent {
             init.parent.altKey, init.parent.shiftKey, init.parent.metaKey,
             init.button, init.relatedTarget.r(), None
         );
+        event.set_movement(Vector2D::new(init.movementX, init.movementY));
         Ok(event)
     }
 
     pub fn point_in_target(&self) -> Option<Point2D<f32>> {
         self.point_in_target.get()
     }
+
+    /// Sets the distance moved since the previous `mousemove` event.
+    /// <https://w3c.github.io/pointerlock/#extensions-to-the-mouseevent-interface>
+    pub fn set_movement(&self, movement: Vector2D<i32>) {
+        self.movement_x.set(movement.x);
+        self.movement_y.set(movement.y);
+    }
+
+    // https://drafts.csswg.org/cssom-view/#dom-mouseevent-pagex
+    // https://drafts.csswg.org/cssom-view/#dom-mouseevent-pagey
+    fn page_point(&self) -> Point2D<f64> {
+        let (scroll_x, scroll_y) = match self.upcast::<UIEvent>().GetView() {
+            Some(window) => (window.ScrollX(), window.ScrollY()),
+            None => (0, 0),
+        };
+        Point2D::new((self.client_x.get() + scroll_x) as f64,
+                     (self.client_y.get() + scroll_y) as f64)
+    }
+
+    // https://drafts.csswg.org/cssom-view/#dom-mouseevent-offsetx
+    // https://drafts.csswg.org/cssom-view/#dom-mouseevent-offsety
+    fn offset_point(&self) -> Point2D<f64> {
+        let page_point = self.page_point();
+        let event = self.upcast::<Event>();
+        if !event.dispatching() {
+            return page_point;
+        }
+        let element = match event.GetTarget().and_then(DomRoot::downcast::<Element>) {
+            Some(element) => element,
+            None => return page_point,
+        };
+        // Layout boxes are relative to the initial containing block, and the
+        // padding edge lies inside the border.
+        let border_box = element.upcast::<Node>().bounding_content_box_or_zero();
+        Point2D::new(page_point.x - border_box.origin.x.to_f64_px() - element.ClientLeft() as f64,
+                     page_point.y - border_box.origin.y.to_f64_px() - element.ClientTop() as f64)
+    }
 }
 
 impl MouseEventMethods for MouseEvent {
@@ -167,6 +214,36 @@ impl MouseEventMethods for MouseEvent {
         self.related_target.get()
     }
 
+    // https://drafts.csswg.org/cssom-view/#dom-mouseevent-pagex
+    fn PageX(&self) -> f64 {
+        self.page_point().x
+    }
+
+    // https://drafts.csswg.org/cssom-view/#dom-mouseevent-pagey
+    fn PageY(&self) -> f64 {
+        self.page_point().y
+    }
+
+    // https://drafts.csswg.org/cssom-view/#dom-mouseevent-offsetx
+    fn OffsetX(&self) -> f64 {
+        self.offset_point().x
+    }
+
+    // https://drafts.csswg.org/cssom-view/#dom-mouseevent-offsety
+    fn OffsetY(&self) -> f64 {
+        self.offset_point().y
+    }
+
+    // https://w3c.github.io/pointerlock/#dom-mouseevent-movementx
+    fn MovementX(&self) -> i32 {
+        self.movement_x.get()
+    }
+
+    // https://w3c.github.io/pointerlock/#dom-mouseevent-movementy
+    fn MovementY(&self) -> i32 {
+        self.movement_y.get()
+    }
+
     // See discussion at:
     //  - https://github.com/servo/servo/issues/6643
     //  - https://bugzilla.mozilla.org/show_bug.cgi?id=1186125
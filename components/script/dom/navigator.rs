@@ -5,22 +5,38 @@
 use dom::bindings::codegen::Bindings::NavigatorBinding;
 use dom::bindings::codegen::Bindings::NavigatorBinding::NavigatorMethods;
 use dom::bindings::codegen::Bindings::VRBinding::VRBinding::VRMethods;
+use dom::bindings::codegen::Bindings::XMLHttpRequestBinding::BodyInit;
+use dom::bindings::error::{Error, Fallible};
 use dom::bindings::reflector::{Reflector, DomObject, reflect_dom_object};
 use dom::bindings::root::{DomRoot, MutNullableDom};
-use dom::bindings::str::DOMString;
+use dom::bindings::str::{DOMString, USVString};
 use dom::bluetooth::Bluetooth;
 use dom::gamepadlist::GamepadList;
 use dom::mimetypearray::MimeTypeArray;
-use dom::navigatorinfo;
+use dom::navigatorinfo::{self, LanguagesArray};
 use dom::permissions::Permissions;
 use dom::pluginarray::PluginArray;
 use dom::promise::Promise;
 use dom::serviceworkercontainer::ServiceWorkerContainer;
 use dom::vr::VR;
 use dom::window::Window;
+use dom::xmlhttprequest::Extractable;
 use dom_struct::dom_struct;
+use hyper::header::Headers;
+use hyper::method::Method;
+use ipc_channel::ipc;
+use js::jsapi::JSContext;
+use js::jsval::JSVal;
+use net_traits::{CoreResourceMsg, FetchChannels};
+use net_traits::request::{CredentialsMode, Destination, RequestInit, RequestMode};
 use std::rc::Rc;
 
+/// The maximum size of a beacon payload, in bytes. Larger beacons are not
+/// queued, matching the keepalive quota other engines enforce.
+///
+/// https://fetch.spec.whatwg.org/#http-network-or-cache-fetch (step 8.4)
+const BEACON_PAYLOAD_LIMIT: usize = 64 * 1024;
+
 #[dom_struct]
 pub struct Navigator {
     reflector_: Reflector,
@@ -31,6 +47,7 @@ pub struct Navigator {
     vr: MutNullableDom<VR>,
     gamepads: MutNullableDom<GamepadList>,
     permissions: MutNullableDom<Permissions>,
+    languages: LanguagesArray,
 }
 
 impl Navigator {
@@ -44,6 +61,7 @@ impl Navigator {
             vr: Default::default(),
             gamepads: Default::default(),
             permissions: Default::default(),
+            languages: LanguagesArray::new(),
         }
     }
 
@@ -100,6 +118,12 @@ impl NavigatorMethods for Navigator {
         navigatorinfo::Language()
     }
 
+    // https://html.spec.whatwg.org/multipage/#dom-navigator-languages
+    #[allow(unsafe_code)]
+    unsafe fn Languages(&self, cx: *mut JSContext) -> JSVal {
+        self.languages.get(cx)
+    }
+
     // https://html.spec.whatwg.org/multipage/#dom-navigator-plugins
     fn Plugins(&self) -> DomRoot<PluginArray> {
         self.plugins.or_init(|| PluginArray::new(&self.global()))
@@ -148,6 +172,78 @@ impl NavigatorMethods for Navigator {
     fn GetVRDisplays(&self) -> Rc<Promise> {
         self.Vr().GetDisplays()
     }
+
+    // https://w3c.github.io/beacon/#sec-sendBeacon-method
+    fn SendBeacon(&self, url: USVString, data: Option<BodyInit>) -> Fallible<bool> {
+        let global = self.global();
+
+        // Steps 1-2.
+        let url = match global.api_base_url().join(&url.0) {
+            Ok(url) => url,
+            Err(_) => return Err(Error::Type("Invalid beacon URL".to_owned())),
+        };
+
+        // Step 3.
+        match url.scheme() {
+            "http" | "https" => {},
+            _ => return Err(Error::Type("Beacon URL must be an HTTP(S) URL".to_owned())),
+        }
+
+        // Steps 5-6.
+        let mut headers = Headers::new();
+        let mut mode = RequestMode::NoCors;
+        let body = match data {
+            Some(data) => {
+                let (bytes, content_type) = data.extract();
+                if bytes.len() > BEACON_PAYLOAD_LIMIT {
+                    return Ok(false);
+                }
+                if let Some(content_type) = content_type {
+                    if !is_cors_safelisted_content_type(&content_type) {
+                        mode = RequestMode::CorsMode;
+                    }
+                    headers.set_raw("content-type", vec![content_type.as_bytes().to_vec()]);
+                }
+                Some(bytes)
+            },
+            None => None,
+        };
+
+        // Step 7.
+        let request = RequestInit {
+            method: Method::Post,
+            url: url,
+            headers: headers,
+            unsafe_request: true,
+            body: body,
+            destination: Destination::None,
+            use_cors_preflight: mode == RequestMode::CorsMode,
+            mode: mode,
+            credentials_mode: CredentialsMode::Include,
+            origin: global.origin().immutable().clone(),
+            referrer_url: Some(global.get_url()),
+            pipeline_id: Some(global.pipeline_id()),
+            .. RequestInit::default()
+        };
+
+        // Nobody listens for the response, so the fetch is not tied to this
+        // document and keeps going after it is unloaded.
+        let (action_sender, _) = ipc::channel().unwrap();
+        global.core_resource_thread().send(
+            CoreResourceMsg::Fetch(request, FetchChannels::ResponseMsg(action_sender, None))).unwrap();
+
+        // Step 8.
+        Ok(true)
+    }
+}
+
+/// https://fetch.spec.whatwg.org/#cors-safelisted-request-header
+fn is_cors_safelisted_content_type(content_type: &str) -> bool {
+    let essence = content_type.split(';').next().unwrap_or("").trim().to_ascii_lowercase();
+    match &*essence {
+        "application/x-www-form-urlencoded" | "multipart/form-data" | "text/plain" => true,
+        _ => false,
+    }
 }
 
 impl Navigator {
@@ -2,8 +2,13 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use dom::bindings::cell::DomRefCell;
 use dom::bindings::str::DOMString;
+use js::conversions::ToJSValConvertible;
+use js::jsapi::{Heap, JSContext, JS_FreezeObject};
+use js::jsval::{JSVal, UndefinedValue};
 use servo_config::opts;
+use servo_config::prefs::PREFS;
 
 pub fn Product() -> DOMString {
     DOMString::from("Gecko")
@@ -50,5 +55,55 @@ pub fn AppVersion() -> DOMString {
 }
 
 pub fn Language() -> DOMString {
-    DOMString::from("en-US")
+    Languages().swap_remove(0)
+}
+
+/// The user's preferred languages, most preferred first, as configured by
+/// the `intl.accept_languages` preference.
+pub fn Languages() -> Vec<DOMString> {
+    let languages: Vec<DOMString> = PREFS.get("intl.accept_languages").as_string()
+        .unwrap_or("")
+        .split(',')
+        .map(str::trim)
+        .filter(|language| !language.is_empty())
+        .map(DOMString::from)
+        .collect();
+    if languages.is_empty() {
+        return vec![DOMString::from("en-US")];
+    }
+    languages
+}
+
+/// The frozen array returned by `navigator.languages`. The same object is
+/// handed out until the preferred languages change.
+///
+/// https://html.spec.whatwg.org/multipage/#dom-navigator-languages
+#[derive(JSTraceable, MallocSizeOf)]
+pub struct LanguagesArray {
+    languages: DomRefCell<Vec<DOMString>>,
+    #[ignore_malloc_size_of = "Defined in rust-mozjs"]
+    array: Heap<JSVal>,
+}
+
+impl LanguagesArray {
+    pub fn new() -> LanguagesArray {
+        LanguagesArray {
+            languages: DomRefCell::new(vec![]),
+            array: Heap::default(),
+        }
+    }
+
+    #[allow(unsafe_code)]
+    pub unsafe fn get(&self, cx: *mut JSContext) -> JSVal {
+        let languages = Languages();
+        if self.array.get().is_undefined() || *self.languages.borrow() != languages {
+            rooted!(in(cx) let mut array = UndefinedValue());
+            languages.to_jsval(cx, array.handle_mut());
+            rooted!(in(cx) let object = array.to_object());
+            assert!(JS_FreezeObject(cx, object.handle()));
+            self.array.set(array.get());
+            *self.languages.borrow_mut() = languages;
+        }
+        self.array.get()
+    }
 }
@@ -12,7 +12,7 @@ use dom::bindings::codegen::Bindings::CharacterDataBinding::CharacterDataMethods
 use dom::bindings::codegen::Bindings::DocumentBinding::DocumentMethods;
 use dom::bindings::codegen::Bindings::ElementBinding::ElementMethods;
 use dom::bindings::codegen::Bindings::HTMLCollectionBinding::HTMLCollectionMethods;
-use dom::bindings::codegen::Bindings::NodeBinding::{NodeConstants, NodeMethods};
+use dom::bindings::codegen::Bindings::NodeBinding::{GetRootNodeOptions, NodeConstants, NodeMethods};
 use dom::bindings::codegen::Bindings::NodeListBinding::NodeListMethods;
 use dom::bindings::codegen::Bindings::ProcessingInstructionBinding::ProcessingInstructionMethods;
 use dom::bindings::codegen::Bindings::WindowBinding::WindowMethods;
@@ -39,6 +39,7 @@ use dom::htmlbodyelement::HTMLBodyElement;
 use dom::htmlcanvaselement::{HTMLCanvasElement, LayoutHTMLCanvasElementHelpers};
 use dom::htmlcollection::HTMLCollection;
 use dom::htmlelement::HTMLElement;
+use dom::htmlframeelement::{HTMLFrameElement, HTMLFrameElementLayoutMethods};
 use dom::htmliframeelement::{HTMLIFrameElement, HTMLIFrameElementLayoutMethods};
 use dom::htmlimageelement::{HTMLImageElement, LayoutHTMLImageElementHelpers};
 use dom::htmlinputelement::{HTMLInputElement, LayoutHTMLInputElementHelpers};
@@ -68,6 +69,8 @@ use script_layout_interface::message::Msg;
 use script_thread::ScriptThread;
 use script_traits::DocumentActivity;
 use script_traits::UntrustedNodeAddress;
+use selectors::Element as SelectorsElement;
+use selectors::OpaqueElement;
 use selectors::matching::{matches_selector_list, MatchingContext, MatchingMode};
 use selectors::parser::SelectorList;
 use servo_arc::Arc;
@@ -332,14 +335,18 @@ impl Node {
 pub struct QuerySelectorIterator {
     selectors: SelectorList<SelectorImpl>,
     iterator: TreeIterator,
+    scope_element: Option<OpaqueElement>,
 }
 
 impl<'a> QuerySelectorIterator {
-     fn new(iter: TreeIterator, selectors: SelectorList<SelectorImpl>)
+     fn new(iter: TreeIterator,
+            selectors: SelectorList<SelectorImpl>,
+            scope_element: Option<OpaqueElement>)
                   -> QuerySelectorIterator {
         QuerySelectorIterator {
             selectors: selectors,
             iterator: iter,
+            scope_element: scope_element,
         }
     }
 }
@@ -349,6 +356,7 @@ impl<'a> Iterator for QuerySelectorIterator {
 
     fn next(&mut self) -> Option<DomRoot<Node>> {
         let selectors = &self.selectors;
+        let scope_element = &self.scope_element;
 
         self.iterator.by_ref().filter_map(|node| {
             // TODO(cgaebel): Is it worth it to build a bloom filter here
@@ -357,6 +365,7 @@ impl<'a> Iterator for QuerySelectorIterator {
             // FIXME(bholley): Consider an nth-index cache here.
             let mut ctx = MatchingContext::new(MatchingMode::Normal, None, None,
                 node.owner_doc().quirks_mode());
+            ctx.scope_element = scope_element.clone();
             if let Some(element) = DomRoot::downcast(node) {
                 if matches_selector_list(selectors, &element, &mut ctx) {
                     return Some(DomRoot::upcast(element));
@@ -751,6 +760,18 @@ impl Node {
         self.AppendChild(&node).map(|_| ())
     }
 
+    // https://dom.spec.whatwg.org/#dom-parentnode-replacechildren
+    pub fn replace_children(&self, nodes: Vec<NodeOrString>) -> ErrorResult {
+        // Step 1.
+        let doc = self.owner_doc();
+        let node = doc.node_from_nodes_and_strings(nodes)?;
+        // Step 2.
+        Node::ensure_pre_insertion_validity(&node, self, None)?;
+        // Step 3.
+        Node::replace_all(Some(&node), self);
+        Ok(())
+    }
+
     // https://dom.spec.whatwg.org/#dom-parentnode-queryselector
     pub fn query_selector(&self, selectors: DOMString) -> Fallible<Option<DomRoot<Element>>> {
         // Step 1.
@@ -762,7 +783,11 @@ impl Node {
                 // FIXME(bholley): Consider an nth-index cache here.
                 let mut ctx = MatchingContext::new(MatchingMode::Normal, None, None,
                                                    self.owner_doc().quirks_mode());
-                Ok(self.traverse_preorder().filter_map(DomRoot::downcast).find(|element| {
+                ctx.scope_element = self.scope_element();
+                let mut descendants = self.traverse_preorder();
+                // Skip the root of the tree.
+                assert!(&*descendants.next().unwrap() == self);
+                Ok(descendants.filter_map(DomRoot::downcast).find(|element| {
                     matches_selector_list(&selectors, element, &mut ctx)
                 }))
             }
@@ -784,11 +809,18 @@ impl Node {
                 let mut descendants = self.traverse_preorder();
                 // Skip the root of the tree.
                 assert!(&*descendants.next().unwrap() == self);
-                Ok(QuerySelectorIterator::new(descendants, selectors))
+                Ok(QuerySelectorIterator::new(descendants, selectors, self.scope_element()))
             }
         }
     }
 
+    /// The element that `:scope` matches when this node is the scoping root
+    /// of a selector query. Documents and fragments have none, so `:scope`
+    /// falls back to matching the root element.
+    fn scope_element(&self) -> Option<OpaqueElement> {
+        self.downcast::<Element>().map(|element| DomRoot::from_ref(element).opaque())
+    }
+
     // https://dom.spec.whatwg.org/#dom-parentnode-queryselectorall
     #[allow(unsafe_code)]
     pub fn query_selector_all(&self, selectors: DOMString) -> Fallible<DomRoot<NodeList>> {
@@ -1179,12 +1211,18 @@ impl LayoutNodeHelpers for LayoutDom<Node> {
     }
 
     fn iframe_browsing_context_id(&self) -> Option<BrowsingContextId> {
+        if let Some(frame_element) = self.downcast::<HTMLFrameElement>() {
+            return frame_element.browsing_context_id();
+        }
         let iframe_element = self.downcast::<HTMLIFrameElement>()
             .expect("not an iframe element!");
         iframe_element.browsing_context_id()
     }
 
     fn iframe_pipeline_id(&self) -> Option<PipelineId> {
+        if let Some(frame_element) = self.downcast::<HTMLFrameElement>() {
+            return frame_element.pipeline_id();
+        }
         let iframe_element = self.downcast::<HTMLIFrameElement>()
             .expect("not an iframe element!");
         iframe_element.pipeline_id()
@@ -1964,6 +2002,11 @@ impl NodeMethods for Node {
         USVString(String::from(self.owner_doc().base_url().as_str()))
     }
 
+    // https://dom.spec.whatwg.org/#dom-node-isconnected
+    fn IsConnected(&self) -> bool {
+        self.is_in_doc()
+    }
+
     // https://dom.spec.whatwg.org/#dom-node-ownerdocument
     fn GetOwnerDocument(&self) -> Option<DomRoot<Document>> {
         match self.type_id() {
@@ -1976,7 +2019,9 @@ impl NodeMethods for Node {
     }
 
     // https://dom.spec.whatwg.org/#dom-node-getrootnode
-    fn GetRootNode(&self) -> DomRoot<Node> {
+    fn GetRootNode(&self, _options: &GetRootNodeOptions) -> DomRoot<Node> {
+        // TODO: return the shadow-including root when `composed` is true once
+        // shadow trees are supported. Until then both roots are the same.
         self.inclusive_ancestors().last().unwrap()
     }
 
@@ -2745,6 +2790,8 @@ impl Into<LayoutElementType> for ElementTypeId {
                 LayoutElementType::HTMLBRElement,
             ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLCanvasElement) =>
                 LayoutElementType::HTMLCanvasElement,
+            ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLFrameElement) =>
+                LayoutElementType::HTMLFrameElement,
             ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLIFrameElement) =>
                 LayoutElementType::HTMLIFrameElement,
             ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLImageElement) =>
@@ -0,0 +1,220 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use dom::bindings::cell::DomRefCell;
+use dom::bindings::codegen::Bindings::OffscreenCanvasBinding;
+use dom::bindings::codegen::Bindings::OffscreenCanvasBinding::OffscreenCanvasMethods;
+use dom::bindings::codegen::InterfaceObjectMap::Globals;
+use dom::bindings::error::{Error, Fallible};
+use dom::bindings::interface::is_exposed_in;
+use dom::bindings::refcounted::Trusted;
+use dom::bindings::reflector::{DomObject, reflect_dom_object};
+use dom::bindings::root::{DomRoot, MutNullableDom};
+use dom::bindings::str::DOMString;
+use dom::eventtarget::EventTarget;
+use dom::globalscope::GlobalScope;
+use dom::htmlcanvaselement::HTMLCanvasElement;
+use dom::node::window_from_node;
+use dom::offscreencanvasrenderingcontext2d::OffscreenCanvasRenderingContext2D;
+use dom_struct::dom_struct;
+use euclid::Size2D;
+use js::jsapi::JSContext;
+use js::rust::{HandleObject, HandleValue};
+use servo_config::prefs::PREFS;
+use std::cell::Cell;
+use std::cmp;
+use task::TaskCanceller;
+use task_source::TaskSource;
+use task_source::dom_manipulation::DOMManipulationTaskSource;
+
+/// A handle on the placeholder canvas element of an OffscreenCanvas, which
+/// can be used from the thread of any global the OffscreenCanvas is
+/// transferred to.
+///
+/// https://html.spec.whatwg.org/multipage/#offscreencanvas-placeholder
+#[derive(JSTraceable)]
+pub struct OffscreenCanvasPlaceholder {
+    canvas: Trusted<HTMLCanvasElement>,
+    task_source: DOMManipulationTaskSource,
+    canceller: TaskCanceller,
+}
+
+impl OffscreenCanvasPlaceholder {
+    pub fn new(canvas: &HTMLCanvasElement) -> OffscreenCanvasPlaceholder {
+        let window = window_from_node(canvas);
+        OffscreenCanvasPlaceholder {
+            canvas: Trusted::new(canvas),
+            task_source: window.dom_manipulation_task_source(),
+            canceller: window.task_canceller(),
+        }
+    }
+
+    /// Queues a task on the placeholder's event loop to make it display the
+    /// given RGBA pixels.
+    fn commit(&self, pixels: Vec<u8>, size: Size2D<i32>) {
+        let canvas = self.canvas.clone();
+        let _ = self.task_source.queue_with_canceller(
+            task!(commit_offscreen_canvas: move || {
+                canvas.root().set_placeholder_bitmap(pixels, size);
+            }),
+            &self.canceller,
+        );
+    }
+}
+
+/// The state an OffscreenCanvas carries over to the global it is
+/// transferred to.
+///
+/// https://html.spec.whatwg.org/multipage/#the-offscreencanvas-interface:transfer-steps
+pub struct OffscreenCanvasTransferData {
+    width: u64,
+    height: u64,
+    placeholder: Option<OffscreenCanvasPlaceholder>,
+}
+
+// https://html.spec.whatwg.org/multipage/#the-offscreencanvas-interface
+#[dom_struct]
+pub struct OffscreenCanvas {
+    eventtarget: EventTarget,
+    width: Cell<u64>,
+    height: Cell<u64>,
+    context: MutNullableDom<OffscreenCanvasRenderingContext2D>,
+    /// Whether this object was transferred to another global.
+    detached: Cell<bool>,
+    /// The canvas element this object took control of, if it was created by
+    /// `transferControlToOffscreen`.
+    #[ignore_malloc_size_of = "Channels are hard"]
+    placeholder: DomRefCell<Option<OffscreenCanvasPlaceholder>>,
+}
+
+impl OffscreenCanvas {
+    fn new_inherited(width: u64,
+                     height: u64,
+                     placeholder: Option<OffscreenCanvasPlaceholder>) -> OffscreenCanvas {
+        OffscreenCanvas {
+            eventtarget: EventTarget::new_inherited(),
+            width: Cell::new(width),
+            height: Cell::new(height),
+            context: Default::default(),
+            detached: Cell::new(false),
+            placeholder: DomRefCell::new(placeholder),
+        }
+    }
+
+    pub fn new(global: &GlobalScope,
+               width: u64,
+               height: u64,
+               placeholder: Option<OffscreenCanvasPlaceholder>) -> DomRoot<OffscreenCanvas> {
+        reflect_dom_object(Box::new(OffscreenCanvas::new_inherited(width, height, placeholder)),
+                           global,
+                           OffscreenCanvasBinding::Wrap)
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-offscreencanvas
+    pub fn Constructor(global: &GlobalScope,
+                       width: u64,
+                       height: u64) -> Fallible<DomRoot<OffscreenCanvas>> {
+        Ok(OffscreenCanvas::new(global, width, height, None))
+    }
+
+    pub fn get_size(&self) -> Size2D<i32> {
+        // Bitmap dimensions are i32s; clamp anything larger.
+        let clamp = |dimension: u64| cmp::min(dimension, i32::max_value() as u64) as i32;
+        Size2D::new(clamp(self.width.get()), clamp(self.height.get()))
+    }
+
+    /// Whether the canvas interfaces that workers only need for offscreen
+    /// canvases should be exposed in the global `obj`.
+    #[allow(unsafe_code)]
+    pub unsafe fn canvas_interface_enabled(_: *mut JSContext, obj: HandleObject) -> bool {
+        let workers = Globals::DEDICATED_WORKER_GLOBAL_SCOPE | Globals::SERVICE_WORKER_GLOBAL_SCOPE;
+        !is_exposed_in(obj, workers) || PREFS.get("dom.offscreen_canvas.enabled").as_boolean().unwrap_or(false)
+    }
+
+    /// Detaches this object so that it can be recreated in another global.
+    /// Returns None if it can't be transferred.
+    ///
+    /// https://html.spec.whatwg.org/multipage/#the-offscreencanvas-interface:transfer-steps
+    pub fn transfer(&self) -> Option<OffscreenCanvasTransferData> {
+        // Step 1.
+        if self.detached.get() || self.context.get().is_some() {
+            return None;
+        }
+
+        // Steps 2-5.
+        self.detached.set(true);
+        Some(OffscreenCanvasTransferData {
+            width: self.width.get(),
+            height: self.height.get(),
+            placeholder: self.placeholder.borrow_mut().take(),
+        })
+    }
+
+    // https://html.spec.whatwg.org/multipage/#the-offscreencanvas-interface:transfer-receiving-steps
+    pub fn from_transfer_data(global: &GlobalScope,
+                              data: OffscreenCanvasTransferData) -> DomRoot<OffscreenCanvas> {
+        OffscreenCanvas::new(global, data.width, data.height, data.placeholder)
+    }
+
+    /// Pushes a copy of the bitmap of this canvas' rendering context to its
+    /// placeholder canvas element, if it has one.
+    pub fn commit_to_placeholder(&self, pixels: Vec<u8>, size: Size2D<i32>) {
+        if let Some(ref placeholder) = *self.placeholder.borrow() {
+            placeholder.commit(pixels, size);
+        }
+    }
+
+    fn resize_context(&self) {
+        if let Some(context) = self.context.get() {
+            context.set_bitmap_dimensions(self.get_size());
+        }
+    }
+}
+
+impl OffscreenCanvasMethods for OffscreenCanvas {
+    #[allow(unsafe_code)]
+    // https://html.spec.whatwg.org/multipage/#dom-offscreencanvas-getcontext
+    unsafe fn GetContext(&self,
+                         _cx: *mut JSContext,
+                         id: DOMString,
+                         _options: HandleValue)
+                         -> Fallible<Option<DomRoot<OffscreenCanvasRenderingContext2D>>> {
+        if self.detached.get() {
+            return Err(Error::InvalidState);
+        }
+
+        match &*id {
+            "2d" => {
+                Ok(Some(self.context.or_init(|| {
+                    OffscreenCanvasRenderingContext2D::new(&self.global(), self, self.get_size())
+                })))
+            },
+            // TODO: WebGL contexts for OffscreenCanvas.
+            "webgl" | "webgl2" => Ok(None),
+            _ => Err(Error::Type(format!("'{}' is not a valid OffscreenCanvas context type", id))),
+        }
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-offscreencanvas-width
+    fn Width(&self) -> u64 {
+        self.width.get()
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-offscreencanvas-width
+    fn SetWidth(&self, value: u64) {
+        self.width.set(value);
+        self.resize_context();
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-offscreencanvas-height
+    fn Height(&self) -> u64 {
+        self.height.get()
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-offscreencanvas-height
+    fn SetHeight(&self, value: u64) {
+        self.height.set(value);
+        self.resize_context();
+    }
+}
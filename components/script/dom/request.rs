@@ -3,6 +3,7 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use body::{BodyOperations, BodyType, consume_body};
+use dom::abortsignal::AbortSignal;
 use dom::bindings::cell::DomRefCell;
 use dom::bindings::codegen::Bindings::HeadersBinding::{HeadersInit, HeadersMethods};
 use dom::bindings::codegen::Bindings::RequestBinding;
@@ -45,6 +46,7 @@ pub struct Request {
     request: DomRefCell<NetTraitsRequest>,
     body_used: Cell<bool>,
     headers: MutNullableDom<Headers>,
+    signal: MutNullableDom<AbortSignal>,
     mime_type: DomRefCell<Vec<u8>>,
     #[ignore_malloc_size_of = "Rc"]
     body_promise: DomRefCell<Option<(Rc<Promise>, BodyType)>>,
@@ -59,6 +61,7 @@ impl Request {
                 net_request_from_global(global, url)),
             body_used: Cell::new(false),
             headers: Default::default(),
+            signal: Default::default(),
             mime_type: DomRefCell::new("".to_string().into_bytes()),
             body_promise: DomRefCell::new(None),
         }
@@ -283,6 +286,19 @@ impl Request {
         let r = Request::from_net_request(global, request);
         r.headers.or_init(|| Headers::for_request(&r.global()));
 
+        // Make r's signal follow the input request's signal, or the one
+        // given in init if there is one.
+        let mut signal = match input {
+            RequestInfo::Request(ref input_request) => Some(input_request.Signal()),
+            RequestInfo::USVString(_) => None,
+        };
+        if let Some(ref init_signal) = init.signal {
+            signal = init_signal.clone();
+        }
+        if let Some(signal) = signal {
+            r.Signal().follow(&signal);
+        }
+
         // Step 27
         let mut headers_copy = r.Headers();
 
@@ -429,6 +445,7 @@ impl Request {
         *r_clone.mime_type.borrow_mut() = mime_type;
         r_clone.Headers().fill(Some(HeadersInit::Headers(r.Headers())))?;
         r_clone.Headers().set_guard(headers_guard);
+        r_clone.Signal().follow(&r.Signal());
         Ok(r_clone)
     }
 
@@ -517,6 +534,11 @@ impl RequestMethods for Request {
         self.headers.or_init(|| Headers::new(&self.global()))
     }
 
+    // https://fetch.spec.whatwg.org/#dom-request-signal
+    fn Signal(&self) -> DomRoot<AbortSignal> {
+        self.signal.or_init(|| AbortSignal::new(&self.global()))
+    }
+
     // https://fetch.spec.whatwg.org/#dom-request-destination
     fn Destination(&self) -> RequestDestination {
         self.request.borrow().destination.into()
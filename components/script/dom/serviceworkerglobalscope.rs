@@ -265,6 +265,9 @@ impl ServiceWorkerGlobalScope {
             CommonWorker(WorkerScriptMsg::Common(msg)) => {
                 self.upcast::<WorkerGlobalScope>().process_event(msg);
             },
+            CommonWorker(WorkerScriptMsg::LanguagesChange) => {
+                self.upcast::<WorkerGlobalScope>().languages_changed();
+            },
             Response(mediator) => {
                 // TODO XXXcreativcoder This will eventually use a FetchEvent interface to fire event
                 // when we have the Request and Response dom api's implemented
@@ -10,6 +10,7 @@ use dom::bindings::codegen::Bindings::FunctionBinding::Function;
 use dom::bindings::codegen::Bindings::TestBindingBinding::{self, SimpleCallback};
 use dom::bindings::codegen::Bindings::TestBindingBinding::{TestBindingMethods, TestDictionary};
 use dom::bindings::codegen::Bindings::TestBindingBinding::{TestDictionaryDefaults, TestEnum};
+use dom::bindings::codegen::Bindings::WindowBinding::WindowMethods;
 use dom::bindings::codegen::UnionTypes;
 use dom::bindings::codegen::UnionTypes::{BlobOrBoolean, BlobOrBlobSequence, LongOrLongSequenceSequence};
 use dom::bindings::codegen::UnionTypes::{BlobOrString, BlobOrUnsignedLong, EventOrString};
@@ -29,24 +30,31 @@ use dom::bindings::str::{ByteString, DOMString, USVString};
 use dom::bindings::trace::RootedTraceableBox;
 use dom::bindings::weakref::MutableWeakRef;
 use dom::blob::{Blob, BlobImpl};
+use dom::document::FireMouseEventType;
+use dom::eventtarget::EventTarget;
 use dom::globalscope::GlobalScope;
 use dom::promise::Promise;
 use dom::promisenativehandler::{PromiseNativeHandler, Callback};
 use dom::url::URL;
+use dom::window::ReflowReason;
 use dom_struct::dom_struct;
+use euclid::{Point2D, TypedScale};
 use js::jsapi::{Heap, JSContext, JSObject};
 use js::jsapi::{JS_NewPlainObject, JS_NewUint8ClampedArray};
 use js::jsval::{JSVal, NullValue};
 use js::rust::{HandleObject, HandleValue};
 use js::rust::CustomAutoRooterGuard;
 use js::typedarray;
-use script_traits::MsDuration;
-use servo_config::prefs::PREFS;
+use script_layout_interface::message::ReflowGoal;
+use script_traits::{MsDuration, ScriptMsg};
+use servo_config::prefs::{PREFS, PrefValue};
 use std::borrow::ToOwned;
+use std::path::PathBuf;
 use std::ptr;
 use std::ptr::NonNull;
 use std::rc::Rc;
 use timers::OneshotTimerCallback;
+use uuid::Uuid;
 
 #[dom_struct]
 pub struct TestBinding {
@@ -665,6 +673,14 @@ impl TestBindingMethods for TestBinding {
     fn StringMozPreference(&self, pref_name: DOMString) -> DOMString {
         PREFS.get(pref_name.as_ref()).as_string().map(|s| DOMString::from(s)).unwrap_or_else(|| DOMString::new())
     }
+    fn SetStringMozPreference(&self, pref_name: DOMString, value: DOMString) {
+        let value: String = value.into();
+        PREFS.set(pref_name.as_ref(), PrefValue::String(value.clone()));
+        if &*pref_name == "intl.accept_languages" {
+            let msg = ScriptMsg::BroadcastLanguagesChange(value);
+            self.global().script_to_constellation_chan().send(msg).unwrap();
+        }
+    }
     fn PrefControlledAttributeDisabled(&self) -> bool { false }
     fn PrefControlledAttributeEnabled(&self) -> bool { false }
     fn PrefControlledMethodDisabled(&self) {}
@@ -802,6 +818,34 @@ impl TestBindingMethods for TestBinding {
         self.global().as_window().advance_animation_clock(ms, tick);
     }
 
+    fn SetVisible(&self, visible: bool) {
+        let msg = ScriptMsg::SetVisible(visible);
+        self.global().script_to_constellation_chan().send(msg).unwrap();
+    }
+
+    fn FireMouseMove(&self, target: &EventTarget, client_x: Finite<f32>, client_y: Finite<f32>) {
+        let document = self.global().as_window().Document();
+        document.fire_mouse_event(Point2D::new(*client_x, *client_y), target, FireMouseEventType::Move);
+    }
+
+    fn SetDevicePixelRatio(&self, ratio: Finite<f32>) {
+        let window = self.global().as_window();
+        let mut size = match window.window_size() {
+            Some(size) => size,
+            None => return,
+        };
+        size.device_pixel_ratio = TypedScale::new(*ratio);
+        window.set_window_size(size);
+        window.force_reflow(ReflowGoal::Full, ReflowReason::WindowResize);
+        window.evaluate_media_queries_and_report_changes();
+    }
+
+    fn UnreadableBlob(&self) -> DomRoot<Blob> {
+        // The file manager doesn't know this id, so reading the blob fails.
+        let blob_impl = BlobImpl::new_from_file(Uuid::new_v4(), PathBuf::from("unreadable"), 0);
+        Blob::new(&self.global(), blob_impl, String::new())
+    }
+
     fn Panic(&self) { panic!("explicit panic from script") }
 
     fn EntryGlobal(&self) -> DomRoot<GlobalScope> {
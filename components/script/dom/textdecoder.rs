@@ -21,9 +21,11 @@ pub struct TextDecoder {
     encoding: &'static Encoding,
     fatal: bool,
     ignoreBOM: bool,
+    /// The decoder for the current stream. Between streaming calls to
+    /// `decode()` it holds on to any incomplete byte sequence at the end of
+    /// the input it was given.
     #[ignore_malloc_size_of = "defined in encoding_rs"]
     decoder: RefCell<Decoder>,
-    in_stream: RefCell<Vec<u8>>,
     do_not_flush: Cell<bool>,
 }
 
@@ -34,10 +36,7 @@ impl TextDecoder {
             encoding: encoding,
             fatal: fatal,
             ignoreBOM: ignoreBOM,
-            decoder: RefCell::new(
-                if ignoreBOM { encoding.new_decoder() } else { encoding.new_decoder_without_bom_handling() }
-            ),
-            in_stream: RefCell::new(Vec::new()),
+            decoder: RefCell::new(new_decoder(encoding, ignoreBOM)),
             do_not_flush: Cell::new(false),
         }
     }
@@ -66,6 +65,16 @@ impl TextDecoder {
     }
 }
 
+/// Returns a decoder that strips a leading byte order mark for `encoding`
+/// unless `ignore_bom` is set.
+fn new_decoder(encoding: &'static Encoding, ignore_bom: bool) -> Decoder {
+    if ignore_bom {
+        encoding.new_decoder_without_bom_handling()
+    } else {
+        encoding.new_decoder_with_bom_removal()
+    }
+}
+
 
 impl TextDecoderMethods for TextDecoder {
     // https://encoding.spec.whatwg.org/#dom-textdecoder-encoding
@@ -91,55 +100,41 @@ impl TextDecoderMethods for TextDecoder {
     ) -> Fallible<USVString> {
         // Step 1.
         if !self.do_not_flush.get() {
-            if self.ignoreBOM {
-                self.decoder.replace(self.encoding.new_decoder_without_bom_handling());
-            } else {
-                self.decoder.replace(self.encoding.new_decoder());
-            }
-            self.in_stream.replace(Vec::new());
+            self.decoder.replace(new_decoder(self.encoding, self.ignoreBOM));
         }
 
         // Step 2.
         self.do_not_flush.set(options.stream);
 
         // Step 3.
-        match input {
-            Some(ArrayBufferViewOrArrayBuffer::ArrayBufferView(ref a)) => {
-                self.in_stream.borrow_mut().extend_from_slice(&a.to_vec());
-            },
-            Some(ArrayBufferViewOrArrayBuffer::ArrayBuffer(ref a)) => {
-                self.in_stream.borrow_mut().extend_from_slice(&a.to_vec());
-            },
-            None => {},
+        let input = match input {
+            Some(ArrayBufferViewOrArrayBuffer::ArrayBufferView(ref a)) => a.to_vec(),
+            Some(ArrayBufferViewOrArrayBuffer::ArrayBuffer(ref a)) => a.to_vec(),
+            None => vec![],
         };
 
+        // Steps 4-5: Implemented by encoding_rs::Decoder. When streaming, an
+        // incomplete sequence at the end of the input is kept by the decoder
+        // and only emitted, or reported as an error, once the stream ends.
+        let last = !options.stream;
         let mut decoder = self.decoder.borrow_mut();
-        let (remaining, s) = {
-            let mut in_stream = self.in_stream.borrow_mut();
-
-            let (remaining, s) = if self.fatal {
-                // Step 4.
-                let mut out_stream = String::with_capacity(
-                    decoder.max_utf8_buffer_length_without_replacement(in_stream.len()).unwrap()
-                );
-                // Step 5: Implemented by encoding_rs::Decoder.
-                match decoder.decode_to_string_without_replacement(&in_stream, &mut out_stream, !options.stream) {
-                    (DecoderResult::InputEmpty, read) => {
-                        (in_stream.split_off(read), out_stream)
-                    },
-                    // Step 5.3.3.
-                    _ => return Err(Error::Type("Decoding failed".to_owned())),
-                }
-            } else {
-                // Step 4.
-                let mut out_stream = String::with_capacity(decoder.max_utf8_buffer_length(in_stream.len()).unwrap());
-                // Step 5: Implemented by encoding_rs::Decoder.
-                let (_result, read, _replaced) = decoder.decode_to_string(&in_stream, &mut out_stream, !options.stream);
-                (in_stream.split_off(read), out_stream)
-            };
-            (remaining, s)
+        let s = if self.fatal {
+            let mut out_stream = String::with_capacity(
+                decoder.max_utf8_buffer_length_without_replacement(input.len()).unwrap()
+            );
+            match decoder.decode_to_string_without_replacement(&input, &mut out_stream, last) {
+                (DecoderResult::InputEmpty, _) => out_stream,
+                // Step 5.3.3.
+                _ => {
+                    self.do_not_flush.set(false);
+                    return Err(Error::Type("Decoding failed".to_owned()));
+                },
+            }
+        } else {
+            let mut out_stream = String::with_capacity(decoder.max_utf8_buffer_length(input.len()).unwrap());
+            let (_result, _read, _replaced) = decoder.decode_to_string(&input, &mut out_stream, last);
+            out_stream
         };
-        self.in_stream.replace(remaining);
         Ok(USVString(s))
     }
 }
@@ -12,4 +12,5 @@ pub mod oestexturefloatlinear;
 pub mod oestexturehalffloat;
 pub mod oestexturehalffloatlinear;
 pub mod oesvertexarrayobject;
+pub mod webgllosecontext;
 pub mod webglvertexarrayobjectoes;
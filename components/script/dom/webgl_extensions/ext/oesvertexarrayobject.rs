@@ -47,6 +47,10 @@ impl OESVertexArrayObject {
 impl OESVertexArrayObjectMethods for OESVertexArrayObject {
     // https://www.khronos.org/registry/webgl/extensions/OES_vertex_array_object/
     fn CreateVertexArrayOES(&self) -> Option<DomRoot<WebGLVertexArrayObjectOES>> {
+        if self.ctx.is_context_lost() {
+            return None;
+        }
+
         let (sender, receiver) = webgl_channel().unwrap();
         self.ctx.send_command(WebGLCommand::CreateVertexArray(sender));
 
@@ -56,6 +60,10 @@ impl OESVertexArrayObjectMethods for OESVertexArrayObject {
 
     // https://www.khronos.org/registry/webgl/extensions/OES_vertex_array_object/
     fn DeleteVertexArrayOES(&self, vao: Option<&WebGLVertexArrayObjectOES>) {
+        if self.ctx.is_context_lost() {
+            return;
+        }
+
         if let Some(vao) = vao {
             if vao.is_deleted() {
                 return;
@@ -86,12 +94,20 @@ impl OESVertexArrayObjectMethods for OESVertexArrayObject {
 
     // https://www.khronos.org/registry/webgl/extensions/OES_vertex_array_object/
     fn IsVertexArrayOES(&self, vao: Option<&WebGLVertexArrayObjectOES>) -> bool {
+        if self.ctx.is_context_lost() {
+            return false;
+        }
+
         // Conformance tests expect false if vao never bound
         vao.map_or(false, |vao| !vao.is_deleted() && vao.ever_bound())
     }
 
     // https://www.khronos.org/registry/webgl/extensions/OES_vertex_array_object/
     fn BindVertexArrayOES(&self, vao: Option<&WebGLVertexArrayObjectOES>) {
+        if self.ctx.is_context_lost() {
+            return;
+        }
+
         if let Some(bound_vao) = self.bound_vao.get() {
             // Store buffers attached to attrib pointers
             let buffers = self.ctx.borrow_bound_attrib_buffers();
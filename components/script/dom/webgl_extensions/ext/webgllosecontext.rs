@@ -0,0 +1,63 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use dom::bindings::codegen::Bindings::WEBGLLoseContextBinding::{self, WEBGLLoseContextMethods};
+use dom::bindings::reflector::{DomObject, Reflector, reflect_dom_object};
+use dom::bindings::root::{Dom, DomRoot};
+use dom::webglrenderingcontext::WebGLRenderingContext;
+use dom_struct::dom_struct;
+use super::{WebGLExtension, WebGLExtensions, WebGLExtensionSpec};
+
+#[dom_struct]
+pub struct WEBGLLoseContext {
+    reflector_: Reflector,
+    ctx: Dom<WebGLRenderingContext>,
+}
+
+impl WEBGLLoseContext {
+    fn new_inherited(ctx: &WebGLRenderingContext) -> Self {
+        Self {
+            reflector_: Reflector::new(),
+            ctx: Dom::from_ref(ctx),
+        }
+    }
+}
+
+impl WEBGLLoseContextMethods for WEBGLLoseContext {
+    // https://www.khronos.org/registry/webgl/extensions/WEBGL_lose_context/
+    fn LoseContext(&self) {
+        self.ctx.lose_context();
+    }
+
+    // https://www.khronos.org/registry/webgl/extensions/WEBGL_lose_context/
+    fn RestoreContext(&self) {
+        self.ctx.restore_context();
+    }
+}
+
+impl WebGLExtension for WEBGLLoseContext {
+    type Extension = Self;
+
+    fn new(ctx: &WebGLRenderingContext) -> DomRoot<Self> {
+        reflect_dom_object(
+            Box::new(WEBGLLoseContext::new_inherited(ctx)),
+            &*ctx.global(),
+            WEBGLLoseContextBinding::Wrap,
+        )
+    }
+
+    fn spec() -> WebGLExtensionSpec {
+        WebGLExtensionSpec::All
+    }
+
+    fn is_supported(_ext: &WebGLExtensions) -> bool {
+        true
+    }
+
+    fn enable(_ext: &WebGLExtensions) {}
+
+    fn name() -> &'static str {
+        "WEBGL_lose_context"
+    }
+}
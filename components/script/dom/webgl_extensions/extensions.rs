@@ -118,24 +118,26 @@ impl WebGLExtensions {
         self.extensions.borrow_mut().insert(name, Box::new(TypedWebGLExtensionWrapper::<T>::new()));
     }
 
+    fn is_supported(&self, extension: &WebGLExtensionWrapper) -> bool {
+        if let WebGLExtensionSpec::Specific(version) = extension.spec() {
+            if self.webgl_version != version {
+                return false;
+            }
+        }
+        extension.is_supported(self)
+    }
+
     pub fn get_suported_extensions(&self) -> Vec<&'static str> {
         self.extensions.borrow().iter()
-                                .filter(|ref v| {
-                                    if let WebGLExtensionSpec::Specific(version) = v.1.spec() {
-                                        if self.webgl_version != version {
-                                            return false;
-                                        }
-                                    }
-                                    v.1.is_supported(&self)
-                                })
-                                .map(|ref v| v.1.name())
+                                .filter(|&(_, extension)| self.is_supported(&**extension))
+                                .map(|(_, extension)| extension.name())
                                 .collect()
     }
 
     pub fn get_or_init_extension(&self, name: &str, ctx: &WebGLRenderingContext) -> Option<NonNull<JSObject>> {
         let name = name.to_uppercase();
         self.extensions.borrow().get(&name).and_then(|extension| {
-            if extension.is_supported(self) {
+            if self.is_supported(&**extension) {
                 Some(extension.instance_or_init(ctx, self))
             } else {
                 None
@@ -243,6 +245,7 @@ impl WebGLExtensions {
         self.register::<ext::oestexturehalffloat::OESTextureHalfFloat>();
         self.register::<ext::oestexturehalffloatlinear::OESTextureHalfFloatLinear>();
         self.register::<ext::oesvertexarrayobject::OESVertexArrayObject>();
+        self.register::<ext::webgllosecontext::WEBGLLoseContext>();
     }
 
     pub fn enable_element_index_uint(&self) {
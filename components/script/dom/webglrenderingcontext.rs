@@ -21,10 +21,11 @@ use dom::bindings::codegen::UnionTypes::Int32ArrayOrLongSequence;
 use dom::bindings::conversions::ToJSValConvertible;
 use dom::bindings::error::{Error, ErrorResult};
 use dom::bindings::inheritance::Castable;
+use dom::bindings::refcounted::Trusted;
 use dom::bindings::reflector::{DomObject, Reflector, reflect_dom_object};
 use dom::bindings::root::{Dom, DomRoot, LayoutDom, MutNullableDom};
 use dom::bindings::str::DOMString;
-use dom::event::{Event, EventBubbles, EventCancelable};
+use dom::event::{Event, EventBubbles, EventCancelable, EventStatus};
 use dom::htmlcanvaselement::HTMLCanvasElement;
 use dom::htmlcanvaselement::utils as canvas_utils;
 use dom::htmliframeelement::HTMLIFrameElement;
@@ -57,6 +58,7 @@ use net_traits::image::base::PixelFormat;
 use net_traits::image_cache::ImageResponse;
 use offscreen_gl_context::{GLContextAttributes, GLLimits};
 use script_layout_interface::HTMLCanvasDataSource;
+use servo_atoms::Atom;
 use servo_config::prefs::PREFS;
 use std::cell::{Cell, Ref};
 use std::cmp;
@@ -200,6 +202,10 @@ pub struct WebGLRenderingContext {
     #[ignore_malloc_size_of = "Because it's small"]
     current_clear_color: Cell<(f32, f32, f32, f32)>,
     extension_manager: WebGLExtensions,
+    /// https://www.khronos.org/registry/webgl/specs/latest/1.0/#webgl-context-lost-flag
+    context_lost: Cell<bool>,
+    /// Whether the webglcontextlost event was canceled, allowing the context to be restored.
+    restore_allowed: Cell<bool>,
 }
 
 impl WebGLRenderingContext {
@@ -248,7 +254,9 @@ impl WebGLRenderingContext {
                 current_vertex_attrib_0: Cell::new((0f32, 0f32, 0f32, 1f32)),
                 current_scissor: Cell::new((0, 0, size.width, size.height)),
                 current_clear_color: Cell::new((0.0, 0.0, 0.0, 0.0)),
-                extension_manager: WebGLExtensions::new(webgl_version)
+                extension_manager: WebGLExtensions::new(webgl_version),
+                context_lost: Cell::new(false),
+                restore_allowed: Cell::new(false),
             }
         })
     }
@@ -335,24 +343,29 @@ impl WebGLRenderingContext {
             return;
         };
 
+        // The new GL context needs the state below even while this context
+        // is lost, so that it matches the DOM side once restored. Bypass
+        // `send_command`, which drops commands while lost.
+        let send_command = |command| self.webgl_sender.send(command).unwrap();
+
         // ClearColor needs to be restored because after a resize the GLContext is recreated
         // and the framebuffer is cleared using the default black transparent color.
         let color = self.current_clear_color.get();
-        self.send_command(WebGLCommand::ClearColor(color.0, color.1, color.2, color.3));
+        send_command(WebGLCommand::ClearColor(color.0, color.1, color.2, color.3));
 
         // WebGL Spec: Scissor rect must not change if the canvas is resized.
         // See: webgl/conformance-1.0.3/conformance/rendering/gl-scissor-canvas-dimensions.html
         // NativeContext handling library changes the scissor after a resize, so we need to reset the
         // default scissor when the canvas was created or the last scissor that the user set.
         let rect = self.current_scissor.get();
-        self.send_command(WebGLCommand::Scissor(rect.0, rect.1, rect.2, rect.3));
+        send_command(WebGLCommand::Scissor(rect.0, rect.1, rect.2, rect.3));
 
         // Bound texture must not change when the canvas is resized.
         // Right now offscreen_gl_context generates a new FBO and the bound texture is changed
         // in order to create a new render to texture attachment.
         // Send a command to re-bind the TEXTURE_2D, if any.
         if let Some(texture) = self.bound_texture(constants::TEXTURE_2D) {
-            self.send_command(WebGLCommand::BindTexture(constants::TEXTURE_2D, Some(texture.id())));
+            send_command(WebGLCommand::BindTexture(constants::TEXTURE_2D, Some(texture.id())));
         }
 
         // Bound framebuffer must not change when the canvas is resized.
@@ -360,7 +373,7 @@ impl WebGLRenderingContext {
         // Send a command to re-bind the framebuffer, if any.
         if let Some(fbo) = self.bound_framebuffer.get() {
             let id = WebGLFramebufferBindingRequest::Explicit(fbo.id());
-            self.send_command(WebGLCommand::BindFramebuffer(constants::FRAMEBUFFER, id));
+            send_command(WebGLCommand::BindFramebuffer(constants::FRAMEBUFFER, id));
         }
     }
 
@@ -370,6 +383,10 @@ impl WebGLRenderingContext {
 
     #[inline]
     pub fn send_command(&self, command: WebGLCommand) {
+        // GL calls are no-ops while the context is lost.
+        if self.context_lost.get() {
+            return;
+        }
         self.webgl_sender.send(command).unwrap();
     }
 
@@ -378,6 +395,71 @@ impl WebGLRenderingContext {
         self.webgl_sender.send_vr(command).unwrap();
     }
 
+    pub fn is_context_lost(&self) -> bool {
+        self.context_lost.get()
+    }
+
+    /// https://www.khronos.org/registry/webgl/specs/latest/1.0/#CONTEXT_LOST
+    pub fn lose_context(&self) {
+        // Step 2.
+        if self.context_lost.get() {
+            return;
+        }
+
+        // Step 3.
+        self.context_lost.set(true);
+        self.restore_allowed.set(false);
+
+        // Step 5.
+        self.last_error.set(Some(WebGLError::ContextLost));
+
+        // Steps 6-8.
+        let window = window_from_node(&*self.canvas);
+        let this = Trusted::new(self);
+        window.dom_manipulation_task_source().queue(
+            task!(fire_webglcontextlost: move || {
+                let context = this.root();
+                let status = context.fire_context_event(atom!("webglcontextlost"), EventCancelable::Cancelable);
+                if status == EventStatus::Canceled {
+                    context.restore_allowed.set(true);
+                }
+            }),
+            window.upcast(),
+        ).unwrap();
+    }
+
+    /// https://www.khronos.org/registry/webgl/specs/latest/1.0/#CONTEXT_RESTORED
+    pub fn restore_context(&self) {
+        if !self.context_lost.get() || !self.restore_allowed.get() {
+            return self.webgl_error(InvalidOperation);
+        }
+        self.restore_allowed.set(false);
+
+        let window = window_from_node(&*self.canvas);
+        let this = Trusted::new(self);
+        window.dom_manipulation_task_source().queue(
+            task!(fire_webglcontextrestored: move || {
+                let context = this.root();
+                // The underlying GL context is kept alive while lost, so
+                // restoring only needs to re-enable GL calls.
+                context.context_lost.set(false);
+                context.last_error.set(None);
+                context.mark_as_dirty();
+                context.fire_context_event(atom!("webglcontextrestored"), EventCancelable::NotCancelable);
+            }),
+            window.upcast(),
+        ).unwrap();
+    }
+
+    fn fire_context_event(&self, name: Atom, cancelable: EventCancelable) -> EventStatus {
+        let event = WebGLContextEvent::new(&window_from_node(&*self.canvas),
+                                           name,
+                                           EventBubbles::DoesNotBubble,
+                                           cancelable,
+                                           DOMString::new());
+        event.upcast::<Event>().fire(self.canvas.upcast())
+    }
+
     pub fn get_extension_manager<'a>(&'a self) -> &'a WebGLExtensions {
         &self.extension_manager
     }
@@ -1156,6 +1238,10 @@ impl WebGLRenderingContext {
     //
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#2.2
     pub fn get_image_data(&self, mut width: u32, mut height: u32) -> Option<Vec<u8>> {
+        if self.context_lost.get() {
+            return None;
+        }
+
         if !self.validate_framebuffer_complete() {
             return None;
         }
@@ -1222,6 +1308,10 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
 
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.11
     fn Finish(&self) {
+        if self.context_lost.get() {
+            return;
+        }
+
         let (sender, receiver) = webgl_channel().unwrap();
         self.send_command(WebGLCommand::Finish(sender));
         receiver.recv().unwrap()
@@ -1230,14 +1320,16 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.1
     fn DrawingBufferWidth(&self) -> i32 {
         let (sender, receiver) = webgl_channel().unwrap();
-        self.send_command(WebGLCommand::DrawingBufferWidth(sender));
+        // The drawing buffer size is still available while the context is lost.
+        self.webgl_sender.send(WebGLCommand::DrawingBufferWidth(sender)).unwrap();
         receiver.recv().unwrap()
     }
 
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.1
     fn DrawingBufferHeight(&self) -> i32 {
         let (sender, receiver) = webgl_channel().unwrap();
-        self.send_command(WebGLCommand::DrawingBufferHeight(sender));
+        // The drawing buffer size is still available while the context is lost.
+        self.webgl_sender.send(WebGLCommand::DrawingBufferHeight(sender)).unwrap();
         receiver.recv().unwrap()
     }
 
@@ -1268,6 +1360,10 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
     #[allow(unsafe_code)]
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.3
     unsafe fn GetParameter(&self, cx: *mut JSContext, parameter: u32) -> JSVal {
+        if self.context_lost.get() {
+            return NullValue();
+        }
+
         // Handle the GL_*_BINDING without going all the way
         // to the GL, since we would just need to map back from GL's
         // returned ID to the WebGL* object we're tracking.
@@ -1375,6 +1471,10 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
     #[allow(unsafe_code)]
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.8
     unsafe fn GetTexParameter(&self, _cx: *mut JSContext, target: u32, pname: u32) -> JSVal {
+        if self.context_lost.get() {
+            return NullValue();
+        }
+
         let target_matches = match target {
             constants::TEXTURE_2D |
             constants::TEXTURE_CUBE_MAP => true,
@@ -1411,6 +1511,11 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
         }
     }
 
+    // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.13
+    fn IsContextLost(&self) -> bool {
+        self.context_lost.get()
+    }
+
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.3
     fn GetError(&self) -> u32 {
         let error_code = if let Some(error) = self.last_error.get() {
@@ -1431,6 +1536,10 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
 
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.2
     fn GetContextAttributes(&self) -> Option<WebGLContextAttributes> {
+        if self.context_lost.get() {
+            return None;
+        }
+
         let (sender, receiver) = webgl_channel().unwrap();
 
         // If the send does not succeed, assume context lost
@@ -1454,6 +1563,10 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
 
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.14
     fn GetSupportedExtensions(&self) -> Option<Vec<DOMString>> {
+        if self.context_lost.get() {
+            return None;
+        }
+
         self.extension_manager.init_once(|| {
             self.get_gl_extensions()
         });
@@ -1465,6 +1578,10 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.14
     unsafe fn GetExtension(&self, _cx: *mut JSContext, name: DOMString)
                     -> Option<NonNull<JSObject>> {
+        if self.context_lost.get() {
+            return None;
+        }
+
         self.extension_manager.init_once(|| {
             self.get_gl_extensions()
         });
@@ -1863,6 +1980,10 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
 
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.11
     fn Clear(&self, mask: u32) {
+        if self.context_lost.get() {
+            return;
+        }
+
         if !self.validate_framebuffer_complete() {
             return;
         }
@@ -1963,31 +2084,55 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
     // generated objects, either here or in the webgl thread
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.5
     fn CreateBuffer(&self) -> Option<DomRoot<WebGLBuffer>> {
+        if self.context_lost.get() {
+            return None;
+        }
+
         WebGLBuffer::maybe_new(self.global().as_window(), self.webgl_sender.clone())
     }
 
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.6
     fn CreateFramebuffer(&self) -> Option<DomRoot<WebGLFramebuffer>> {
+        if self.context_lost.get() {
+            return None;
+        }
+
         WebGLFramebuffer::maybe_new(self.global().as_window(), self.webgl_sender.clone())
     }
 
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.7
     fn CreateRenderbuffer(&self) -> Option<DomRoot<WebGLRenderbuffer>> {
+        if self.context_lost.get() {
+            return None;
+        }
+
         WebGLRenderbuffer::maybe_new(self.global().as_window(), self.webgl_sender.clone())
     }
 
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.8
     fn CreateTexture(&self) -> Option<DomRoot<WebGLTexture>> {
+        if self.context_lost.get() {
+            return None;
+        }
+
         WebGLTexture::maybe_new(self.global().as_window(), self.webgl_sender.clone())
     }
 
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.9
     fn CreateProgram(&self) -> Option<DomRoot<WebGLProgram>> {
+        if self.context_lost.get() {
+            return None;
+        }
+
         WebGLProgram::maybe_new(self.global().as_window(), self.webgl_sender.clone())
     }
 
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.9
     fn CreateShader(&self, shader_type: u32) -> Option<DomRoot<WebGLShader>> {
+        if self.context_lost.get() {
+            return None;
+        }
+
         match shader_type {
             constants::VERTEX_SHADER | constants::FRAGMENT_SHADER => {},
             _ => {
@@ -2121,6 +2266,10 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
 
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.11
     fn DrawArrays(&self, mode: u32, first: i32, count: i32) {
+        if self.context_lost.get() {
+            return;
+        }
+
         match mode {
             constants::POINTS | constants::LINE_STRIP |
             constants::LINE_LOOP | constants::LINES |
@@ -2151,6 +2300,10 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
 
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.11
     fn DrawElements(&self, mode: u32, count: i32, type_: u32, offset: i64) {
+        if self.context_lost.get() {
+            return;
+        }
+
         match mode {
             constants::POINTS | constants::LINE_STRIP |
             constants::LINE_LOOP | constants::LINES |
@@ -2270,6 +2423,10 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
         attachment: u32,
         pname: u32
     ) -> JSVal {
+        if self.context_lost.get() {
+            return NullValue();
+        }
+
         // Check if currently bound framebuffer is non-zero as per spec.
         if self.bound_framebuffer.get().is_none() {
             self.webgl_error(InvalidOperation);
@@ -2356,6 +2513,10 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
         target: u32,
         pname: u32
     ) -> JSVal {
+        if self.context_lost.get() {
+            return NullValue();
+        }
+
         let target_matches = target == constants::RENDERBUFFER;
 
         let pname_matches = match pname {
@@ -2439,6 +2600,10 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
         shader_type: u32,
         precision_type: u32
     ) -> Option<DomRoot<WebGLShaderPrecisionFormat>> {
+        if self.context_lost.get() {
+            return None;
+        }
+
         match precision_type {
             constants::LOW_FLOAT |
             constants::MEDIUM_FLOAT |
@@ -2475,6 +2640,10 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
     #[allow(unsafe_code)]
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.9
     unsafe fn GetVertexAttrib(&self, cx: *mut JSContext, index: u32, pname: u32) -> JSVal {
+        if self.context_lost.get() {
+            return NullValue();
+        }
+
         if index == 0 && pname == constants::CURRENT_VERTEX_ATTRIB {
             rooted!(in(cx) let mut result = UndefinedValue());
             let (x, y, z, w) = self.current_vertex_attrib_0.get();
@@ -2510,6 +2679,10 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
 
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.10
     fn GetVertexAttribOffset(&self, index: u32, pname: u32) -> i64 {
+        if self.context_lost.get() {
+            return 0;
+        }
+
         if pname != constants::VERTEX_ATTRIB_ARRAY_POINTER {
             self.webgl_error(InvalidEnum);
             return 0;
@@ -2545,6 +2718,10 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
     // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.3
     // FIXME: https://github.com/servo/servo/issues/20534
     fn IsEnabled(&self, cap: u32) -> bool {
+        if self.context_lost.get() {
+            return false;
+        }
+
         if self.validate_feature_enum(cap) {
             let (sender, receiver) = webgl_channel().unwrap();
             self.send_command(WebGLCommand::IsEnabled(cap, sender));
@@ -2648,6 +2825,10 @@ impl WebGLRenderingContextMethods for WebGLRenderingContext {
     #[allow(unsafe_code)]
     fn ReadPixels(&self, x: i32, y: i32, width: i32, height: i32, format: u32, pixel_type: u32,
                   mut pixels: CustomAutoRooterGuard<Option<ArrayBufferView>>) {
+        if self.context_lost.get() {
+            return;
+        }
+
         let (array_type, data) = match *pixels {
             // Spec: If data is null then an INVALID_VALUE error is generated.
             None => return self.webgl_error(InvalidValue),
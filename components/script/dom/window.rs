@@ -8,12 +8,15 @@ use bluetooth_traits::BluetoothRequest;
 use canvas_traits::webgl::WebGLChan;
 use cssparser::{Parser, ParserInput};
 use devtools_traits::{ScriptToDevtoolsControlMsg, TimelineMarker, TimelineMarkerType};
+use dom::bindings::callback::ExceptionHandling;
 use dom::bindings::cell::DomRefCell;
 use dom::bindings::codegen::Bindings::DocumentBinding::{DocumentMethods, DocumentReadyState};
 use dom::bindings::codegen::Bindings::FunctionBinding::Function;
+use dom::bindings::codegen::Bindings::PerformanceBinding::PerformanceMethods;
 use dom::bindings::codegen::Bindings::PermissionStatusBinding::PermissionState;
 use dom::bindings::codegen::Bindings::RequestBinding::RequestInit;
 use dom::bindings::codegen::Bindings::WindowBinding::{self, FrameRequestCallback, WindowMethods};
+use dom::bindings::codegen::Bindings::WindowBinding::{IdleRequestCallback, IdleRequestOptions};
 use dom::bindings::codegen::Bindings::WindowBinding::{ScrollBehavior, ScrollToOptions};
 use dom::bindings::codegen::UnionTypes::RequestOrUSVString;
 use dom::bindings::error::{Error, ErrorResult, Fallible};
@@ -32,8 +35,11 @@ use dom::cssstyledeclaration::{CSSModificationAccess, CSSStyleDeclaration, CSSSt
 use dom::customelementregistry::CustomElementRegistry;
 use dom::document::{AnimationFrameCallback, Document};
 use dom::element::Element;
+use dom::event::Event;
+use dom::eventtarget::EventTarget;
 use dom::globalscope::GlobalScope;
 use dom::history::History;
+use dom::idledeadline::IdleDeadline;
 use dom::location::Location;
 use dom::mediaquerylist::{MediaQueryList, WeakMediaQueryListVec};
 use dom::messageevent::MessageEvent;
@@ -75,7 +81,7 @@ use script_layout_interface::rpc::{NodeScrollIdResponse, ResolvedStyleResponse,
 use script_runtime::{CommonScriptMsg, ScriptChan, ScriptPort, ScriptThreadEventCategory, Runtime};
 use script_thread::{ImageCacheMsg, MainThreadScriptChan, MainThreadScriptMsg};
 use script_thread::{ScriptThread, SendableMainThreadScriptChan};
-use script_traits::{ConstellationControlMsg, DocumentState, LoadData};
+use script_traits::{ConstellationControlMsg, DocumentState, LoadData, MsDuration};
 use script_traits::{ScriptToConstellationChan, ScriptMsg, ScrollState, TimerEvent, TimerEventId};
 use script_traits::{TimerSchedulerMsg, UntrustedNodeAddress, WindowSizeData, WindowSizeType};
 use script_traits::webdriver_msg::{WebDriverJSError, WebDriverJSResult};
@@ -113,7 +119,7 @@ use task_source::networking::NetworkingTaskSource;
 use task_source::performance_timeline::PerformanceTimelineTaskSource;
 use task_source::user_interaction::UserInteractionTaskSource;
 use time;
-use timers::{IsInterval, TimerCallback};
+use timers::{IsInterval, OneshotTimerCallback, TimerCallback};
 #[cfg(any(target_os = "macos", target_os = "linux", target_os = "windows"))]
 use tinyfiledialogs::{self, MessageBoxIcon};
 use url::Position;
@@ -121,6 +127,15 @@ use webdriver_handlers::jsval_to_webdriver;
 use webrender_api::{ExternalScrollId, DeviceIntPoint, DeviceUintSize, DocumentId};
 use webvr_traits::WebVRMsg;
 
+/// The longest an idle period may last, so that user input arriving during
+/// it is still handled promptly.
+/// <https://w3c.github.io/requestidlecallback/#why50>
+const MAX_IDLE_PERIOD_MS: f64 = 50.;
+
+/// How long an idle period may last when animation frame callbacks are
+/// waiting for the next rendering opportunity, assuming 60 frames per second.
+const FRAME_INTERVAL_MS: f64 = 1000. / 60.;
+
 /// Current state of the window object
 #[derive(Clone, Copy, Debug, JSTraceable, MallocSizeOf, PartialEq)]
 enum WindowState {
@@ -290,6 +305,17 @@ pub struct Window {
 
     /// Flag to identify whether mutation observers are present(true)/absent(false)
     exists_mut_observer: Cell<bool>,
+
+    /// <https://w3c.github.io/requestidlecallback/#dfn-idle-callback-identifier>
+    idle_callback_ident: Cell<u32>,
+    /// <https://w3c.github.io/requestidlecallback/#dfn-list-of-idle-request-callbacks>
+    #[ignore_malloc_size_of = "Rc is hard"]
+    idle_request_callbacks: DomRefCell<Vec<(u32, Rc<IdleRequestCallback>)>>,
+    /// <https://w3c.github.io/requestidlecallback/#dfn-list-of-runnable-idle-callbacks>
+    #[ignore_malloc_size_of = "Rc is hard"]
+    runnable_idle_callbacks: DomRefCell<Vec<(u32, Rc<IdleRequestCallback>)>>,
+    /// Whether a timer has been set up to start the next idle period.
+    idle_period_scheduled: Cell<bool>,
 }
 
 impl Window {
@@ -757,6 +783,36 @@ impl WindowMethods for Window {
         doc.cancel_animation_frame(ident);
     }
 
+    // https://w3c.github.io/requestidlecallback/#the-requestidlecallback-method
+    fn RequestIdleCallback(&self,
+                           callback: Rc<IdleRequestCallback>,
+                           options: &IdleRequestOptions) -> u32 {
+        // Steps 2-3.
+        let handle = self.idle_callback_ident.get() + 1;
+        self.idle_callback_ident.set(handle);
+        // Step 4.
+        self.idle_request_callbacks.borrow_mut().push((handle, callback));
+        self.schedule_idle_period();
+        // Step 5.
+        if options.timeout > 0 {
+            let callback = IdleCallbackTimeoutCallback {
+                window: Trusted::new(self),
+                handle: handle,
+            };
+            self.upcast::<GlobalScope>()
+                .schedule_callback(OneshotTimerCallback::IdleCallbackTimeout(callback),
+                                   MsDuration::new(options.timeout as u64));
+        }
+        // Step 6.
+        handle
+    }
+
+    // https://w3c.github.io/requestidlecallback/#the-cancelidlecallback-method
+    fn CancelIdleCallback(&self, handle: u32) {
+        self.idle_request_callbacks.borrow_mut().retain(|&(h, _)| h != handle);
+        self.runnable_idle_callbacks.borrow_mut().retain(|&(h, _)| h != handle);
+    }
+
     #[allow(unsafe_code)]
     // https://html.spec.whatwg.org/multipage/#dom-window-postmessage
     unsafe fn PostMessage(&self,
@@ -764,26 +820,29 @@ impl WindowMethods for Window {
                    message: HandleValue,
                    origin: DOMString)
                    -> ErrorResult {
+        // Step 2.
+        let incumbent = match GlobalScope::incumbent() {
+            None => return Err(Error::InvalidState),
+            Some(incumbent) => incumbent,
+        };
+        let source_origin = incumbent.origin().immutable().clone();
+
         // Step 3-5.
         let origin = match &origin[..] {
             "*" => None,
-            "/" => {
-                // TODO(#12715): Should be the origin of the incumbent settings
-                //               object, not self's.
-                Some(self.Document().origin().immutable().clone())
-            },
+            "/" => Some(source_origin.clone()),
             url => match ServoUrl::parse(&url) {
                 Ok(url) => Some(url.origin().clone()),
                 Err(_) => return Err(Error::Syntax),
             }
         };
 
-        // Step 1-2, 6-8.
+        // Step 1, 6-8.
         // TODO(#12717): Should implement the `transfer` argument.
         let data = StructuredCloneData::write(cx, message)?;
 
         // Step 9.
-        self.post_message(origin, data);
+        self.post_message(origin, source_origin, data);
         Ok(())
     }
 
@@ -841,6 +900,14 @@ impl WindowMethods for Window {
                 Some(PseudoElement::Before),
             Some(ref pseudo) if pseudo == ":after" || pseudo == "::after" =>
                 Some(PseudoElement::After),
+            Some(ref pseudo) if pseudo.starts_with(':') => {
+                // An unsupported pseudo-element has no style, so the
+                // declarations are empty.
+                return CSSStyleDeclaration::new(self,
+                                                CSSStyleOwner::Null(Dom::from_ref(self)),
+                                                None,
+                                                CSSModificationAccess::Readonly);
+            },
             _ => None
         };
 
@@ -1013,6 +1080,11 @@ impl WindowMethods for Window {
                                                       quirks_mode);
         let media_query_list = media_queries::parse_media_query_list(&context, &mut parser,
                                                                      self.css_error_reporter());
+        // Queries that serialize the same way share a MediaQueryList, and
+        // so its change listeners.
+        if let Some(mql) = self.media_query_lists.find(&media_query_list) {
+            return mql;
+        }
         let document = self.Document();
         let mql = MediaQueryList::new(&document, media_query_list);
         self.media_query_lists.push(&*mql);
@@ -1853,6 +1925,10 @@ impl Window {
             paint_worklet: Default::default(),
             webrender_document,
             exists_mut_observer: Cell::new(false),
+            idle_callback_ident: Cell::new(0),
+            idle_request_callbacks: Default::default(),
+            runnable_idle_callbacks: Default::default(),
+            idle_period_scheduled: Cell::new(false),
         });
 
         unsafe {
@@ -1934,6 +2010,7 @@ impl Window {
     pub fn post_message(
         &self,
         target_origin: Option<ImmutableOrigin>,
+        source_origin: ImmutableOrigin,
         serialize_with_transfer_result: StructuredCloneData,
     ) {
         let this = Trusted::new(self);
@@ -1961,12 +2038,17 @@ impl Window {
             // TODO: MessagePort array.
 
             // Step 7.7.
-            // TODO(#12719): Set the other attributes.
-            MessageEvent::dispatch_jsval(
-                this.upcast(),
+            // TODO(#12719): Set the source attribute.
+            let event = MessageEvent::new(
                 this.upcast(),
+                atom!("message"),
+                false,
+                false,
                 message_clone.handle(),
+                DOMString::from(source_origin.ascii_serialization()),
+                DOMString::new(),
             );
+            event.upcast::<Event>().fire(this.upcast());
         });
         // FIXME(nox): Why are errors silenced here?
         // TODO(#12718): Use the "posted message task source".
@@ -1976,4 +2058,126 @@ impl Window {
             self.pipeline_id()
         ));
     }
+
+    /// Sets up a timer to start an idle period once the event loop gets to it,
+    /// unless one is already pending.
+    ///
+    /// This approximates the spec, which starts idle periods once the event
+    /// loop has no tasks left to run: the script thread does not expose when
+    /// its queues drain, so a 0ms timer is used instead, which only fires
+    /// after the tasks queued ahead of it. `start_idle_period` makes up for it
+    /// by ending the period before any work known to be pending.
+    fn schedule_idle_period(&self) {
+        if self.idle_period_scheduled.get() {
+            return;
+        }
+        self.idle_period_scheduled.set(true);
+        let callback = IdlePeriodCallback {
+            window: Trusted::new(self),
+        };
+        self.upcast::<GlobalScope>()
+            .schedule_callback(OneshotTimerCallback::IdlePeriod(callback), MsDuration::new(0));
+    }
+
+    /// <https://w3c.github.io/requestidlecallback/#start-an-idle-period-algorithm>
+    pub fn start_idle_period(&self) {
+        self.idle_period_scheduled.set(false);
+
+        // Steps 1-3. The period ends before the next timer is due, and before
+        // the next rendering opportunity if animation frame callbacks are
+        // waiting for it.
+        let now = *self.Performance().Now();
+        let mut idle_period = MAX_IDLE_PERIOD_MS;
+        if let Some(next_timer) = self.upcast::<GlobalScope>().time_until_next_timer() {
+            idle_period = idle_period.min(next_timer.get() as f64);
+        }
+        if self.Document().has_pending_animation_frame_callbacks() {
+            idle_period = idle_period.min(FRAME_INTERVAL_MS);
+        }
+        let deadline = now + idle_period;
+
+        // Steps 4-6.
+        let pending = mem::replace(&mut *self.idle_request_callbacks.borrow_mut(), vec![]);
+        self.runnable_idle_callbacks.borrow_mut().extend(pending);
+
+        // https://w3c.github.io/requestidlecallback/#invoke-idle-callbacks-algorithm
+        while *self.Performance().Now() < deadline {
+            let (_, callback) = {
+                let mut runnable = self.runnable_idle_callbacks.borrow_mut();
+                if runnable.is_empty() {
+                    break;
+                }
+                runnable.remove(0)
+            };
+            let idle_deadline = IdleDeadline::new(self, deadline, false);
+            let _ = callback.Call__(&idle_deadline, ExceptionHandling::Report);
+        }
+
+        // Whatever did not fit in this idle period waits for the next one.
+        if !self.runnable_idle_callbacks.borrow().is_empty() {
+            self.schedule_idle_period();
+        }
+    }
+
+    /// <https://w3c.github.io/requestidlecallback/#invoke-idle-callback-timeout-algorithm>
+    pub fn invoke_idle_callback_timeout(&self, handle: u32) {
+        // Steps 1-2.
+        let callback = {
+            let mut pending = self.idle_request_callbacks.borrow_mut();
+            let mut runnable = self.runnable_idle_callbacks.borrow_mut();
+            if let Some(index) = pending.iter().position(|&(h, _)| h == handle) {
+                Some(pending.remove(index).1)
+            } else if let Some(index) = runnable.iter().position(|&(h, _)| h == handle) {
+                Some(runnable.remove(index).1)
+            } else {
+                None
+            }
+        };
+
+        // Steps 3-5.
+        if let Some(callback) = callback {
+            let now = *self.Performance().Now();
+            let idle_deadline = IdleDeadline::new(self, now, true);
+            let _ = callback.Call__(&idle_deadline, ExceptionHandling::Report);
+        }
+    }
+
+    // https://html.spec.whatwg.org/multipage/#dom-navigator-languages
+    pub fn queue_languagechange_event(&self) {
+        let this = Trusted::new(self);
+        self.dom_manipulation_task_source().queue(
+            task!(fire_languagechange: move || {
+                let this = this.root();
+                this.upcast::<EventTarget>().fire_event(atom!("languagechange"));
+            }),
+            self.upcast(),
+        ).unwrap();
+    }
+}
+
+/// Starts an idle period for a window that has idle callbacks waiting.
+#[derive(JSTraceable, MallocSizeOf)]
+pub struct IdlePeriodCallback {
+    #[ignore_malloc_size_of = "non-owning"]
+    window: Trusted<Window>,
+}
+
+impl IdlePeriodCallback {
+    pub fn invoke(self) {
+        self.window.root().start_idle_period();
+    }
+}
+
+/// Forces an idle callback to run once its `timeout` option has elapsed.
+#[derive(JSTraceable, MallocSizeOf)]
+pub struct IdleCallbackTimeoutCallback {
+    #[ignore_malloc_size_of = "non-owning"]
+    window: Trusted<Window>,
+    handle: u32,
+}
+
+impl IdleCallbackTimeoutCallback {
+    pub fn invoke(self) {
+        self.window.root().invoke_idle_callback_timeout(self.handle);
+    }
 }
@@ -21,9 +21,9 @@ use dom::messageevent::MessageEvent;
 use dom::workerglobalscope::prepare_workerscope_init;
 use dom_struct::dom_struct;
 use ipc_channel::ipc;
-use js::jsapi::{JSAutoCompartment, JSContext};
+use js::jsapi::{JSAutoCompartment, JSContext, JSObject};
 use js::jsval::UndefinedValue;
-use js::rust::HandleValue;
+use js::rust::{CustomAutoRooterGuard, HandleValue};
 use script_traits::WorkerScriptLoadOrigin;
 use std::cell::Cell;
 use std::sync::{Arc, Mutex};
@@ -80,7 +80,7 @@ impl Worker {
         let (sender, receiver) = channel();
         let closing = Arc::new(AtomicBool::new(false));
         let worker = Worker::new(global, sender.clone(), closing.clone());
-        global.track_worker(closing.clone());
+        global.track_worker(closing.clone(), &worker);
         let worker_ref = Trusted::new(&*worker);
 
         let worker_load_origin = WorkerScriptLoadOrigin {
@@ -140,13 +140,28 @@ impl Worker {
         let worker = address.root();
         worker.upcast().fire_event(atom!("error"));
     }
+
+    /// Tells the worker's global scope that the user's preferred languages
+    /// have changed.
+    pub fn languages_changed(&self) {
+        if self.is_closing() {
+            return;
+        }
+        let address = Trusted::new(self);
+        let _ = self.sender.send((address, WorkerScriptMsg::LanguagesChange));
+    }
 }
 
 impl WorkerMethods for Worker {
     #[allow(unsafe_code)]
     // https://html.spec.whatwg.org/multipage/#dom-worker-postmessage
-    unsafe fn PostMessage(&self, cx: *mut JSContext, message: HandleValue) -> ErrorResult {
-        let data = StructuredCloneData::write(cx, message)?;
+    unsafe fn PostMessage(&self,
+                          cx: *mut JSContext,
+                          message: HandleValue,
+                          transfer: CustomAutoRooterGuard<Option<Vec<*mut JSObject>>>)
+                          -> ErrorResult {
+        let transfer = transfer.as_ref().map_or(&[][..], |transfer| &transfer[..]);
+        let data = StructuredCloneData::write_with_transfer(cx, message, transfer)?;
         let address = Trusted::new(self);
 
         // NOTE: step 9 of https://html.spec.whatwg.org/multipage/#dom-messageport-postmessage
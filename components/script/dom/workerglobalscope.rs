@@ -16,6 +16,7 @@ use dom::bindings::str::{DOMString, USVString};
 use dom::bindings::trace::RootedTraceableBox;
 use dom::crypto::Crypto;
 use dom::dedicatedworkerglobalscope::DedicatedWorkerGlobalScope;
+use dom::eventtarget::EventTarget;
 use dom::globalscope::GlobalScope;
 use dom::performance::Performance;
 use dom::promise::Promise;
@@ -189,6 +190,9 @@ impl WorkerGlobalScopeMethods for WorkerGlobalScope {
     // https://html.spec.whatwg.org/multipage/#handler-workerglobalscope-onerror
     error_event_handler!(error, GetOnerror, SetOnerror);
 
+    // https://html.spec.whatwg.org/multipage/#handler-workerglobalscope-onlanguagechange
+    event_handler!(languagechange, GetOnlanguagechange, SetOnlanguagechange);
+
     // https://html.spec.whatwg.org/multipage/#dom-workerglobalscope-importscripts
     fn ImportScripts(&self, url_strings: Vec<DOMString>) -> ErrorResult {
         let mut urls = Vec::with_capacity(url_strings.len());
@@ -410,6 +414,14 @@ impl WorkerGlobalScope {
         // FIXME(jdm): Should we do a microtask checkpoint here?
     }
 
+    /// Fires `languagechange` at this global and passes the change on to the
+    /// dedicated workers it created.
+    /// https://html.spec.whatwg.org/multipage/#dom-navigator-languages
+    pub fn languages_changed(&self) {
+        self.upcast::<EventTarget>().fire_event(atom!("languagechange"));
+        self.upcast::<GlobalScope>().notify_workers_of_languages_change();
+    }
+
     pub fn handle_fire_timer(&self, timer_id: TimerEventId) {
         self.upcast::<GlobalScope>().fire_timer(timer_id);
     }
@@ -7,16 +7,19 @@ use dom::bindings::codegen::Bindings::WorkerNavigatorBinding::WorkerNavigatorMet
 use dom::bindings::reflector::{DomObject, Reflector, reflect_dom_object};
 use dom::bindings::root::{DomRoot, MutNullableDom};
 use dom::bindings::str::DOMString;
-use dom::navigatorinfo;
+use dom::navigatorinfo::{self, LanguagesArray};
 use dom::permissions::Permissions;
 use dom::workerglobalscope::WorkerGlobalScope;
 use dom_struct::dom_struct;
+use js::jsapi::JSContext;
+use js::jsval::JSVal;
 
 // https://html.spec.whatwg.org/multipage/#workernavigator
 #[dom_struct]
 pub struct WorkerNavigator {
     reflector_: Reflector,
     permissions: MutNullableDom<Permissions>,
+    languages: LanguagesArray,
 }
 
 impl WorkerNavigator {
@@ -24,6 +27,7 @@ impl WorkerNavigator {
         WorkerNavigator {
             reflector_: Reflector::new(),
             permissions: Default::default(),
+            languages: LanguagesArray::new(),
         }
     }
 
@@ -75,6 +79,12 @@ impl WorkerNavigatorMethods for WorkerNavigator {
         navigatorinfo::Language()
     }
 
+    // https://html.spec.whatwg.org/multipage/#dom-navigator-languages
+    #[allow(unsafe_code)]
+    unsafe fn Languages(&self, cx: *mut JSContext) -> JSVal {
+        self.languages.get(cx)
+    }
+
     // https://w3c.github.io/permissions/#navigator-and-workernavigator-extension
     fn Permissions(&self) -> DomRoot<Permissions> {
         self.permissions.or_init(|| Permissions::new(&self.global()))
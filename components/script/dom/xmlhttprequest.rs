@@ -140,7 +140,6 @@ pub struct XMLHttpRequest {
     response_headers: DomRefCell<Headers>,
     #[ignore_malloc_size_of = "Defined in hyper"]
     override_mime_type: DomRefCell<Option<Mime>>,
-    override_charset: DomRefCell<Option<&'static Encoding>>,
 
     // Associated concepts
     #[ignore_malloc_size_of = "Defined in hyper"]
@@ -154,7 +153,8 @@ pub struct XMLHttpRequest {
     send_flag: Cell<bool>,
 
     timeout_cancel: DomRefCell<Option<OneshotTimerHandle>>,
-    fetch_time: Cell<i64>,
+    /// The time `send()` started the fetch, in milliseconds.
+    fetch_time: Cell<u64>,
     generation_id: Cell<GenerationId>,
     response_status: Cell<Result<(), ()>>,
     referrer_url: Option<ServoUrl>,
@@ -189,7 +189,6 @@ impl XMLHttpRequest {
             response_json: Heap::default(),
             response_headers: DomRefCell::new(Headers::new()),
             override_mime_type: DomRefCell::new(None),
-            override_charset: DomRefCell::new(None),
 
             request_method: DomRefCell::new(Method::Get),
             request_url: DomRefCell::new(None),
@@ -349,8 +348,7 @@ impl XMLHttpRequestMethods for XMLHttpRequest {
                 }
 
                 // Step 10
-                if !async {
-                    // FIXME: This should only happen if the global environment is a document environment
+                if !async && self.global().is::<Window>() {
                     if self.timeout.get() != 0 || self.response_type.get() != XMLHttpRequestResponseType::_empty {
                         return Err(Error::InvalidAccess)
                     }
@@ -455,9 +453,9 @@ impl XMLHttpRequestMethods for XMLHttpRequest {
                 self.cancel_timeout();
                 return Ok(());
             }
-            let progress = time::now().to_timespec().sec - self.fetch_time.get();
-            if timeout > (progress * 1000) as u32 {
-                self.set_timeout(timeout - (progress * 1000) as u32);
+            let elapsed = time::precise_time_ns() / 1_000_000 - self.fetch_time.get();
+            if timeout as u64 > elapsed {
+                self.set_timeout(timeout - elapsed as u32);
             } else {
                 // Immediately execute the timeout steps
                 self.set_timeout(0);
@@ -637,7 +635,7 @@ impl XMLHttpRequestMethods for XMLHttpRequest {
 
         debug!("request.headers = {:?}", request.headers);
 
-        self.fetch_time.set(time::now().to_timespec().sec);
+        self.fetch_time.set(time::precise_time_ns() / 1_000_000);
 
         let rv = self.fetch(request, &self.global());
         // Step 10
@@ -709,16 +707,11 @@ impl XMLHttpRequestMethods for XMLHttpRequest {
             XMLHttpRequestState::Loading | XMLHttpRequestState::Done => return Err(Error::InvalidState),
             _ => {},
         }
-        // Step 2
-        let override_mime = mime.parse::<Mime>().map_err(|_| Error::Syntax)?;
-        // Step 3
-        let mime_no_params = Mime(override_mime.clone().0, override_mime.clone().1, vec![]);
-        *self.override_mime_type.borrow_mut() = Some(mime_no_params);
-        // Step 4
-        let value = override_mime.get_param(mime::Attr::Charset);
-        *self.override_charset.borrow_mut() = value.and_then(|value| {
-            Encoding::for_label(value.as_bytes())
+        // Step 2, 3
+        let override_mime = mime.parse::<Mime>().unwrap_or_else(|_| {
+            Mime(mime::TopLevel::Application, mime::SubLevel::Ext("octet-stream".to_owned()), vec![])
         });
+        *self.override_mime_type.borrow_mut() = Some(override_mime);
         Ok(())
     }
 
@@ -991,6 +984,7 @@ impl XMLHttpRequest {
                 self.discard_subsequent_responses();
                 self.send_flag.set(false);
                 // XXXManishearth set response to NetworkError
+                *self.response.borrow_mut() = ByteString::new(vec![]);
                 self.change_ready_state(XMLHttpRequestState::Done);
                 return_if_fetch_was_terminated!();
 
@@ -1000,17 +994,19 @@ impl XMLHttpRequest {
                     _ => "error",
                 };
 
+                // https://xhr.spec.whatwg.org/#request-error-steps
+                // Progress events for a failed request report 0 and 0.
                 let upload_complete = &self.upload_complete;
                 if !upload_complete.get() {
                     upload_complete.set(true);
-                    self.dispatch_upload_progress_event(Atom::from(errormsg), None);
+                    self.dispatch_progress_event(true, Atom::from(errormsg), 0, None);
                     return_if_fetch_was_terminated!();
-                    self.dispatch_upload_progress_event(atom!("loadend"), None);
+                    self.dispatch_progress_event(true, atom!("loadend"), 0, None);
                     return_if_fetch_was_terminated!();
                 }
-                self.dispatch_response_progress_event(Atom::from(errormsg));
+                self.dispatch_progress_event(false, Atom::from(errormsg), 0, None);
                 return_if_fetch_was_terminated!();
-                self.dispatch_response_progress_event(atom!("loadend"));
+                self.dispatch_progress_event(false, atom!("loadend"), 0, None);
             }
         }
     }
@@ -1344,20 +1340,21 @@ impl XMLHttpRequest {
         Ok(())
     }
 
+    // https://xhr.spec.whatwg.org/#final-charset
     fn final_charset(&self) -> Option<&'static Encoding> {
-        if self.override_charset.borrow().is_some() {
-            self.override_charset.borrow().clone()
-        } else {
-            match self.response_headers.borrow().get() {
-                Some(&ContentType(ref mime)) => {
-                    let value = mime.get_param(mime::Attr::Charset);
-                    value.and_then(|value|{
-                        Encoding::for_label(value.as_bytes())
-                    })
-                }
-                None => { None }
+        // Step 1, 2, 3
+        let mut label = match self.response_headers.borrow().get() {
+            Some(&ContentType(ref mime)) => mime.get_param(mime::Attr::Charset).map(|v| v.to_string()),
+            None => None,
+        };
+        // Step 4
+        if let Some(ref mime) = *self.override_mime_type.borrow() {
+            if let Some(value) = mime.get_param(mime::Attr::Charset) {
+                label = Some(value.to_string());
             }
         }
+        // Step 5, 6
+        label.and_then(|label| Encoding::for_label(label.as_bytes()))
     }
 
     fn final_mime_type(&self) -> Option<Mime> {
@@ -2,8 +2,11 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use dom::abortsignal::{AbortAlgorithm, AbortAlgorithmId, AbortSignal};
+use dom::bindings::codegen::Bindings::AbortSignalBinding::AbortSignalMethods;
 use dom::bindings::codegen::Bindings::RequestBinding::RequestInfo;
 use dom::bindings::codegen::Bindings::RequestBinding::RequestInit;
+use dom::bindings::codegen::Bindings::RequestBinding::RequestMethods;
 use dom::bindings::codegen::Bindings::ResponseBinding::ResponseBinding::ResponseMethods;
 use dom::bindings::codegen::Bindings::ResponseBinding::ResponseType as DOMResponseType;
 use dom::bindings::error::Error;
@@ -21,6 +24,7 @@ use dom::serviceworkerglobalscope::ServiceWorkerGlobalScope;
 use ipc_channel::ipc;
 use ipc_channel::router::ROUTER;
 use js::jsapi::JSAutoCompartment;
+use js::rust::HandleValue;
 use net_traits::{FetchChannels, FetchResponseListener, NetworkError};
 use net_traits::{FilteredMetadata, FetchMetadata, Metadata};
 use net_traits::CoreResourceMsg::Fetch as NetTraitsFetch;
@@ -36,6 +40,17 @@ struct FetchContext {
     fetch_promise: Option<TrustedPromise>,
     response_object: Trusted<Response>,
     body: Vec<u8>,
+    /// The algorithm added to the request's signal, removed again once the
+    /// fetch is done so that the signal no longer keeps this context alive.
+    abort_algorithm: Option<(Trusted<AbortSignal>, AbortAlgorithmId)>,
+}
+
+impl FetchContext {
+    fn remove_abort_algorithm(&mut self) {
+        if let Some((signal, id)) = self.abort_algorithm.take() {
+            signal.root().remove_algorithm(id);
+        }
+    }
 }
 
 /// RAII fetch canceller object. By default initialized to not having a canceller
@@ -90,6 +105,39 @@ impl Drop for FetchCanceller {
     }
 }
 
+/// The abort algorithm that `fetch()` adds to its request's signal.
+/// <https://fetch.spec.whatwg.org/#dom-global-fetch>
+#[derive(MallocSizeOf)]
+pub struct FetchAbortAlgorithm {
+    #[ignore_malloc_size_of = "Arc"]
+    context: Arc<Mutex<FetchContext>>,
+    #[ignore_malloc_size_of = "channels are hard"]
+    cancel_chan: ipc::IpcSender<()>,
+}
+
+unsafe_no_jsmanaged_fields!(FetchAbortAlgorithm);
+
+impl FetchAbortAlgorithm {
+    #[allow(unrooted_must_root, unsafe_code)]
+    pub fn run(&self, reason: HandleValue) {
+        // Terminate the ongoing fetch. The other side may already be done,
+        // in which case there is nobody left to hear us.
+        let _ = self.cancel_chan.send(());
+
+        // Reject the promise with the abort reason, unless it already settled.
+        let promise = match self.context.lock().unwrap().fetch_promise.take() {
+            Some(promise) => promise.root(),
+            None => return,
+        };
+        let cx = promise.global().get_cx();
+        let _ac = JSAutoCompartment::new(cx, promise.reflector().get_jsobject().get());
+        unsafe {
+            promise.reject(cx, reason);
+        }
+        // TODO: error the response body once it is exposed as a stream.
+    }
+}
+
 fn from_referrer_to_referrer_url(request: &NetTraitsRequest) -> Option<ServoUrl> {
     request.referrer.to_url().map(|url| url.clone())
 }
@@ -118,7 +166,7 @@ fn request_init_from_request(request: NetTraitsRequest) -> NetTraitsRequestInit
 }
 
 // https://fetch.spec.whatwg.org/#fetch-method
-#[allow(unrooted_must_root)]
+#[allow(unrooted_must_root, unsafe_code)]
 pub fn Fetch(global: &GlobalScope, input: RequestInfo, init: RootedTraceableBox<RequestInit>) -> Rc<Promise> {
     let core_resource_thread = global.core_resource_thread();
 
@@ -127,13 +175,26 @@ pub fn Fetch(global: &GlobalScope, input: RequestInfo, init: RootedTraceableBox<
     let response = Response::new(global);
 
     // Step 2
-    let request = match Request::Constructor(global, input, init) {
+    let request_object = match Request::Constructor(global, input, init) {
         Err(e) => {
             promise.reject_error(e);
             return promise;
         },
-        Ok(r) => r.get_request(),
+        Ok(r) => r,
     };
+    let request = request_object.get_request();
+
+    // Reject right away if the request's signal is already aborted.
+    let signal = request_object.Signal();
+    if signal.aborted() {
+        let cx = global.get_cx();
+        rooted!(in(cx) let reason = unsafe { signal.Reason(cx) });
+        unsafe {
+            promise.reject(cx, reason.handle());
+        }
+        return promise;
+    }
+
     let mut request_init = request_init_from_request(request);
 
     // Step 3
@@ -150,7 +211,17 @@ pub fn Fetch(global: &GlobalScope, input: RequestInfo, init: RootedTraceableBox<
         fetch_promise: Some(TrustedPromise::new(promise.clone())),
         response_object: Trusted::new(&*response),
         body: vec![],
+        abort_algorithm: None,
     }));
+
+    // Abort the fetch when the request's signal is aborted.
+    let (cancel_sender, cancel_receiver) = ipc::channel().unwrap();
+    let abort_algorithm = signal.add_algorithm(AbortAlgorithm::Fetch(FetchAbortAlgorithm {
+        context: fetch_context.clone(),
+        cancel_chan: cancel_sender,
+    }));
+    fetch_context.lock().unwrap().abort_algorithm = Some((Trusted::new(&*signal), abort_algorithm));
+
     let listener = NetworkListener {
         context: fetch_context,
         task_source: global.networking_task_source(),
@@ -161,7 +232,7 @@ pub fn Fetch(global: &GlobalScope, input: RequestInfo, init: RootedTraceableBox<
         listener.notify_fetch(message.to().unwrap());
     }));
     core_resource_thread.send(
-        NetTraitsFetch(request_init, FetchChannels::ResponseMsg(action_sender, None))).unwrap();
+        NetTraitsFetch(request_init, FetchChannels::ResponseMsg(action_sender, Some(cancel_receiver)))).unwrap();
 
     promise
 }
@@ -179,7 +250,11 @@ impl FetchResponseListener for FetchContext {
 
     #[allow(unrooted_must_root)]
     fn process_response(&mut self, fetch_metadata: Result<FetchMetadata, NetworkError>) {
-        let promise = self.fetch_promise.take().expect("fetch promise is missing").root();
+        // The promise is gone if the fetch was aborted.
+        let promise = match self.fetch_promise.take() {
+            Some(promise) => promise.root(),
+            None => return,
+        };
 
         // JSAutoCompartment needs to be manually made.
         // Otherwise, Servo will crash.
@@ -191,6 +266,7 @@ impl FetchResponseListener for FetchContext {
                 promise.reject_error(Error::Type("Network error occurred".to_string()));
                 self.fetch_promise = Some(TrustedPromise::new(promise));
                 self.response_object.root().set_type(DOMResponseType::Error);
+                self.remove_abort_algorithm();
                 return;
             },
             // Step 4.2
@@ -227,6 +303,7 @@ impl FetchResponseListener for FetchContext {
     }
 
     fn process_response_eof(&mut self, _response: Result<(), NetworkError>) {
+        self.remove_abort_algorithm();
         let response = self.response_object.root();
         let global = response.global();
         let cx = global.get_cx();
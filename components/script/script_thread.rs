@@ -47,7 +47,7 @@ use dom::element::Element;
 use dom::event::{Event, EventBubbles, EventCancelable};
 use dom::globalscope::GlobalScope;
 use dom::htmlanchorelement::HTMLAnchorElement;
-use dom::htmliframeelement::{HTMLIFrameElement, NavigationType};
+use dom::htmliframeelement::{BrowsingContextContainerRoot, NavigationType};
 use dom::mutationobserver::MutationObserver;
 use dom::node::{Node, NodeDamage, window_from_node, from_untrusted_node_address};
 use dom::performanceentry::PerformanceEntry;
@@ -101,6 +101,7 @@ use script_traits::webdriver_msg::WebDriverScriptCommand;
 use serviceworkerjob::{Job, JobQueue};
 use servo_atoms::Atom;
 use servo_config::opts;
+use servo_config::prefs::{PREFS, PrefValue};
 use servo_url::{ImmutableOrigin, MutableOrigin, ServoUrl};
 use std::cell::Cell;
 use std::collections::{hash_map, HashMap, HashSet};
@@ -172,6 +173,8 @@ struct InProgressLoad {
     navigation_start_precise: u64,
     /// For cancelling the fetch
     canceller: FetchCanceller,
+    /// Whether the browsing context name must be reset when this load's document becomes active.
+    reset_browsing_context_name: bool,
 }
 
 impl InProgressLoad {
@@ -201,6 +204,7 @@ impl InProgressLoad {
             navigation_start: (current_time.sec * 1000 + current_time.nsec as i64 / 1000000) as u64,
             navigation_start_precise: navigation_start_precise,
             canceller: Default::default(),
+            reset_browsing_context_name: false,
         }
     }
 }
@@ -360,10 +364,10 @@ impl Documents {
         self.find_window(pipeline_id).map(|window| DomRoot::from_ref(window.upcast()))
     }
 
-    pub fn find_iframe(&self, pipeline_id: PipelineId, browsing_context_id: BrowsingContextId)
-                       -> Option<DomRoot<HTMLIFrameElement>>
+    pub fn find_browsing_context_container(&self, pipeline_id: PipelineId, browsing_context_id: BrowsingContextId)
+                                           -> Option<BrowsingContextContainerRoot>
     {
-        self.find_document(pipeline_id).and_then(|doc| doc.find_iframe(browsing_context_id))
+        self.find_document(pipeline_id).and_then(|doc| doc.find_browsing_context_container(browsing_context_id))
     }
 
     pub fn iter<'a>(&'a self) -> DocumentsIter<'a> {
@@ -1179,6 +1183,7 @@ impl ScriptThread {
                     WebFontLoaded(id) => Some(id),
                     DispatchIFrameLoadEvent { target: _, parent: id, child: _ } => Some(id),
                     DispatchStorageEvent(id, ..) => Some(id),
+                    DispatchLanguagesChange(id, ..) => Some(id),
                     ReportCSSError(id, ..) => Some(id),
                     Reload(id, ..) => Some(id),
                     WebVREvents(id, ..) => Some(id),
@@ -1287,8 +1292,8 @@ impl ScriptThread {
                 self.handle_visibility_change_msg(pipeline_id, visible),
             ConstellationControlMsg::NotifyVisibilityChange(parent_pipeline_id, browsing_context_id, visible) =>
                 self.handle_visibility_change_complete_msg(parent_pipeline_id, browsing_context_id, visible),
-            ConstellationControlMsg::PostMessage(pipeline_id, origin, data) =>
-                self.handle_post_message_msg(pipeline_id, origin, data),
+            ConstellationControlMsg::PostMessage(pipeline_id, origin, source_origin, data) =>
+                self.handle_post_message_msg(pipeline_id, origin, source_origin, data),
             ConstellationControlMsg::UpdatePipelineId(parent_pipeline_id,
                                                       browsing_context_id,
                                                       new_pipeline_id,
@@ -1316,6 +1321,8 @@ impl ScriptThread {
                 self.handle_iframe_load_event(parent_id, browsing_context_id, child_id),
             ConstellationControlMsg::DispatchStorageEvent(pipeline_id, storage, url, key, old_value, new_value) =>
                 self.handle_storage_event(pipeline_id, storage, url, key, old_value, new_value),
+            ConstellationControlMsg::DispatchLanguagesChange(pipeline_id, languages) =>
+                self.handle_languages_change(pipeline_id, languages),
             ConstellationControlMsg::ReportCSSError(pipeline_id, filename, line, column, msg) =>
                 self.handle_css_error_reporting(pipeline_id, filename, line, column, msg),
             ConstellationControlMsg::Reload(pipeline_id) =>
@@ -1536,6 +1543,7 @@ impl ScriptThread {
             pipeline_port,
             content_process_shutdown_chan,
             layout_threads,
+            reset_browsing_context_name,
         } = new_layout_info;
 
         let layout_pair = channel();
@@ -1571,14 +1579,15 @@ impl ScriptThread {
         };
 
         // Kick off the fetch for the new resource.
-        let new_load = InProgressLoad::new(new_pipeline_id,
-                                           browsing_context_id,
-                                           top_level_browsing_context_id,
-                                           parent_info,
-                                           layout_chan,
-                                           window_size,
-                                           load_data.url.clone(),
-                                           origin);
+        let mut new_load = InProgressLoad::new(new_pipeline_id,
+                                               browsing_context_id,
+                                               top_level_browsing_context_id,
+                                               parent_info,
+                                               layout_chan,
+                                               window_size,
+                                               load_data.url.clone(),
+                                               origin);
+        new_load.reset_browsing_context_name = reset_browsing_context_name;
         if load_data.url.as_str() == "about:blank" {
             self.start_page_load_about_blank(new_load, load_data.js_eval_result);
         } else {
@@ -1602,9 +1611,10 @@ impl ScriptThread {
                                              browsing_context_id: BrowsingContextId,
                                              visible: bool)
     {
-        let iframe = self.documents.borrow().find_iframe(parent_pipeline_id, browsing_context_id);
-        if let Some(iframe) = iframe {
-            iframe.change_visibility_status(visible);
+        let container = self.documents.borrow().find_browsing_context_container(parent_pipeline_id,
+                                                                                browsing_context_id);
+        if let Some(container) = container {
+            container.change_visibility_status(visible);
         }
     }
 
@@ -1618,6 +1628,7 @@ impl ScriptThread {
         match window {
             Some(window) => {
                 window.alter_resource_utilization(visible);
+                window.Document().update_visibility_state(visible);
                 return;
             }
             None => {
@@ -1652,19 +1663,23 @@ impl ScriptThread {
                                parent_pipeline_id: PipelineId,
                                browsing_context_id: BrowsingContextId) {
         let doc = self.documents.borrow().find_document(parent_pipeline_id).unwrap();
-        let frame_element = doc.find_iframe(browsing_context_id);
+        let frame_element = doc.find_browsing_context_container(browsing_context_id);
 
         if let Some(ref frame_element) = frame_element {
             doc.begin_focus_transaction();
-            doc.request_focus(frame_element.upcast());
+            doc.request_focus(frame_element.container_element().upcast());
             doc.commit_focus_transaction(FocusType::Parent);
         }
     }
 
-    fn handle_post_message_msg(&self, pipeline_id: PipelineId, origin: Option<ImmutableOrigin>, data: Vec<u8>) {
+    fn handle_post_message_msg(&self,
+                               pipeline_id: PipelineId,
+                               origin: Option<ImmutableOrigin>,
+                               source_origin: ImmutableOrigin,
+                               data: Vec<u8>) {
         match { self.documents.borrow().find_window(pipeline_id) } {
             None => return warn!("postMessage after pipeline {} closed.", pipeline_id),
-            Some(window) => window.post_message(origin, StructuredCloneData::Vector(data)),
+            Some(window) => window.post_message(origin, source_origin, StructuredCloneData::Vector(data)),
         }
     }
 
@@ -1673,7 +1688,8 @@ impl ScriptThread {
                                  browsing_context_id: BrowsingContextId,
                                  new_pipeline_id: PipelineId,
                                  reason: UpdatePipelineIdReason) {
-        let frame_element = self.documents.borrow().find_iframe(parent_pipeline_id, browsing_context_id);
+        let frame_element = self.documents.borrow().find_browsing_context_container(parent_pipeline_id,
+                                                                                    browsing_context_id);
         if let Some(frame_element) = frame_element {
             frame_element.update_pipeline_id(new_pipeline_id, reason);
         }
@@ -1976,14 +1992,30 @@ impl ScriptThread {
         storage.queue_storage_event(url, key, old_value, new_value);
     }
 
+    /// Notify a window and its workers that the user's preferred languages have changed.
+    /// https://html.spec.whatwg.org/multipage/#dom-navigator-languages
+    fn handle_languages_change(&self, pipeline_id: PipelineId, languages: String) {
+        let window = match { self.documents.borrow().find_window(pipeline_id) } {
+            None => return warn!("Languages change sent to closed pipeline {}.", pipeline_id),
+            Some(window) => window,
+        };
+
+        // The pipeline may live in a different content process than the one
+        // the preference was changed in.
+        PREFS.set("intl.accept_languages", PrefValue::String(languages));
+
+        window.queue_languagechange_event();
+        window.upcast::<GlobalScope>().notify_workers_of_languages_change();
+    }
+
     /// Notify the containing document of a child iframe that has completed loading.
     fn handle_iframe_load_event(&self,
                                 parent_id: PipelineId,
                                 browsing_context_id: BrowsingContextId,
                                 child_id: PipelineId) {
-        let iframe = self.documents.borrow().find_iframe(parent_id, browsing_context_id);
-        match iframe {
-            Some(iframe) => iframe.iframe_load_event_steps(child_id),
+        let container = self.documents.borrow().find_browsing_context_container(parent_id, browsing_context_id);
+        match container {
+            Some(container) => container.iframe_load_event_steps(child_id),
             None => warn!("Message sent to closed pipeline {}.", parent_id),
         }
     }
@@ -2039,18 +2071,25 @@ impl ScriptThread {
                           window: &Window,
                           browsing_context_id: BrowsingContextId,
                           top_level_browsing_context_id: TopLevelBrowsingContextId,
-                          parent_info: Option<PipelineId>)
+                          parent_info: Option<PipelineId>,
+                          reset_name: bool)
                           -> DomRoot<WindowProxy>
     {
         if let Some(window_proxy) = self.window_proxies.borrow().get(&browsing_context_id) {
+            // https://html.spec.whatwg.org/multipage/#resetBCName
+            // The constellation decides this, since the previous document
+            // may have lived in another script thread.
+            if reset_name {
+                window_proxy.set_name(DOMString::new());
+            }
             window_proxy.set_currently_active(&*window);
             return DomRoot::from_ref(window_proxy);
         }
         let iframe = parent_info.and_then(|parent_id| {
-            self.documents.borrow().find_iframe(parent_id, browsing_context_id)
+            self.documents.borrow().find_browsing_context_container(parent_id, browsing_context_id)
         });
         let parent = match (parent_info, iframe.as_ref()) {
-            (_, Some(iframe)) => Some(window_from_node(&**iframe).window_proxy()),
+            (_, Some(iframe)) => Some(window_from_node(iframe.container_element()).window_proxy()),
             (Some(parent_id), _) => self.remote_window_proxy(window.upcast(),
                                                               top_level_browsing_context_id,
                                                               parent_id),
@@ -2059,7 +2098,7 @@ impl ScriptThread {
         let window_proxy = WindowProxy::new(&window,
                                             browsing_context_id,
                                             top_level_browsing_context_id,
-                                            iframe.r().map(Castable::upcast),
+                                            iframe.as_ref().map(|iframe| iframe.container_element().upcast()),
                                             parent.r());
         self.window_proxies.borrow_mut().insert(browsing_context_id, Dom::from_ref(&*window_proxy));
         window_proxy
@@ -2138,7 +2177,8 @@ impl ScriptThread {
         let window_proxy = self.local_window_proxy(&window,
                                                    incomplete.browsing_context_id,
                                                    incomplete.top_level_browsing_context_id,
-                                                   incomplete.parent_info);
+                                                   incomplete.parent_info,
+                                                   incomplete.reset_browsing_context_name);
         window.init_window_proxy(&window_proxy);
 
         let last_modified = metadata.headers.as_ref().and_then(|headers| {
@@ -2218,6 +2258,7 @@ impl ScriptThread {
 
         if !incomplete.is_visible {
             window.alter_resource_utilization(false);
+            document.set_initial_visibility_state(false);
         }
 
         document.get_current_parser().unwrap()
@@ -2416,9 +2457,12 @@ impl ScriptThread {
 
         match browsing_context_id {
             Some(browsing_context_id) => {
-                let iframe = self.documents.borrow().find_iframe(parent_pipeline_id, browsing_context_id);
-                if let Some(iframe) = iframe {
-                    iframe.navigate_or_reload_child_browsing_context(Some(load_data), NavigationType::Regular, replace);
+                let container = self.documents.borrow().find_browsing_context_container(parent_pipeline_id,
+                                                                                        browsing_context_id);
+                if let Some(container) = container {
+                    container.navigate_or_reload_child_browsing_context(Some(load_data),
+                                                                        NavigationType::Regular,
+                                                                        replace);
                 }
             }
             None => {
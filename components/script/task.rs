@@ -68,6 +68,7 @@ impl fmt::Debug for TaskBox {
 }
 
 /// Encapsulated state required to create cancellable tasks from non-script threads.
+#[derive(JSTraceable)]
 pub struct TaskCanceller {
     pub cancelled: Option<Arc<AtomicBool>>,
 }
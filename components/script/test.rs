@@ -19,6 +19,10 @@ pub mod sizes {
     pub use dom::htmlimageelement::{parse_a_sizes_attribute, Size};
 }
 
+pub mod time {
+    pub use dom::htmltimeelement::{DateTimeKind, parse_a_datetime_value};
+}
+
 pub mod size_of {
     use dom::characterdata::CharacterData;
     use dom::element::Element;
@@ -63,6 +67,11 @@ pub mod size_of {
     }
 }
 
+pub mod frameset {
+    pub use dom::htmlframesetelement::{Dimension, TrackSize};
+    pub use dom::htmlframesetelement::{dimensions_to_track_sizes, parse_a_list_of_dimensions};
+}
+
 pub mod srcset {
     pub use dom::htmlimageelement::{parse_a_srcset_attribute, ImageSource, Descriptor};
 }
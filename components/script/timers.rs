@@ -2,6 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use dom::abortsignal::AbortSignalTimeoutCallback;
 use dom::bindings::callback::ExceptionHandling::Report;
 use dom::bindings::cell::DomRefCell;
 use dom::bindings::codegen::Bindings::FunctionBinding::Function;
@@ -11,6 +12,7 @@ use dom::document::FakeRequestAnimationFrameCallback;
 use dom::eventsource::EventSourceTimeoutCallback;
 use dom::globalscope::GlobalScope;
 use dom::testbinding::TestBindingCallback;
+use dom::window::{IdleCallbackTimeoutCallback, IdlePeriodCallback};
 use dom::xmlhttprequest::XHRTimeoutCallback;
 use euclid::Length;
 use ipc_channel::ipc::IpcSender;
@@ -72,6 +74,9 @@ pub enum OneshotTimerCallback {
     JsTimer(JsTimerTask),
     TestBindingCallback(TestBindingCallback),
     FakeRequestAnimationFrame(FakeRequestAnimationFrameCallback),
+    IdlePeriod(IdlePeriodCallback),
+    IdleCallbackTimeout(IdleCallbackTimeoutCallback),
+    AbortSignalTimeout(AbortSignalTimeoutCallback),
 }
 
 impl OneshotTimerCallback {
@@ -82,6 +87,9 @@ impl OneshotTimerCallback {
             OneshotTimerCallback::JsTimer(task) => task.invoke(this, js_timers),
             OneshotTimerCallback::TestBindingCallback(callback) => callback.invoke(),
             OneshotTimerCallback::FakeRequestAnimationFrame(callback) => callback.invoke(),
+            OneshotTimerCallback::IdlePeriod(callback) => callback.invoke(),
+            OneshotTimerCallback::IdleCallbackTimeout(callback) => callback.invoke(),
+            OneshotTimerCallback::AbortSignalTimeout(callback) => callback.invoke(),
         }
     }
 }
@@ -211,6 +219,13 @@ impl OneshotTimers {
         self.schedule_timer_call();
     }
 
+    /// How long until the next timer is due, if any is scheduled.
+    pub fn time_until_next_timer(&self) -> Option<MsDuration> {
+        self.timers.borrow().last().map(|timer| {
+            Length::new(timer.scheduled_for.get().saturating_sub(self.base_time().get()))
+        })
+    }
+
     fn base_time(&self) -> MsDuration {
         let offset = self.suspension_offset.get();
 
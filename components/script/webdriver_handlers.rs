@@ -18,7 +18,7 @@ use dom::bindings::str::DOMString;
 use dom::element::Element;
 use dom::globalscope::GlobalScope;
 use dom::htmlelement::HTMLElement;
-use dom::htmliframeelement::HTMLIFrameElement;
+use dom::htmliframeelement::BrowsingContextContainerRoot;
 use dom::htmlinputelement::HTMLInputElement;
 use dom::htmloptionelement::HTMLOptionElement;
 use dom::node::{Node, window_from_node};
@@ -120,7 +120,8 @@ pub fn handle_get_browsing_context_id(documents: &Documents,
         },
         WebDriverFrameId::Element(x) => {
             find_node_by_unique_id(documents, pipeline, x)
-                .and_then(|node| node.downcast::<HTMLIFrameElement>().and_then(|elem| elem.browsing_context_id()))
+                .and_then(|node| node.downcast::<HTMLElement>().and_then(BrowsingContextContainerRoot::from_element))
+                .and_then(|container| container.browsing_context_id())
                 .ok_or(())
         },
         WebDriverFrameId::Parent => {
@@ -110,6 +110,7 @@ pub enum LayoutElementType {
     Element,
     HTMLBRElement,
     HTMLCanvasElement,
+    HTMLFrameElement,
     HTMLIFrameElement,
     HTMLImageElement,
     HTMLInputElement,
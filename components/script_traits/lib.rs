@@ -202,6 +202,9 @@ pub struct NewLayoutInfo {
     pub content_process_shutdown_chan: Option<IpcSender<()>>,
     /// Number of threads to use for layout.
     pub layout_threads: usize,
+    /// Whether the browsing context name must be reset when the new document becomes active.
+    /// <https://html.spec.whatwg.org/multipage/#resetBCName>
+    pub reset_browsing_context_name: bool,
 }
 
 /// When a pipeline is closed, should its browsing context be discarded too?
@@ -284,8 +287,9 @@ pub enum ConstellationControlMsg {
     /// Notifies script thread that a url should be loaded in this iframe.
     /// PipelineId is for the parent, BrowsingContextId is for the nested browsing context
     Navigate(PipelineId, BrowsingContextId, LoadData, bool),
-    /// Post a message to a given window.
-    PostMessage(PipelineId, Option<ImmutableOrigin>, Vec<u8>),
+    /// Post a message to a given window, with the target origin it must match
+    /// (if any) and the origin of the sender.
+    PostMessage(PipelineId, Option<ImmutableOrigin>, ImmutableOrigin, Vec<u8>),
     /// Updates the current pipeline ID of a given iframe.
     /// First PipelineId is for the parent, second is the new PipelineId for the frame.
     UpdatePipelineId(PipelineId, BrowsingContextId, PipelineId, UpdatePipelineIdReason),
@@ -317,6 +321,10 @@ pub enum ConstellationControlMsg {
     /// Cause a `storage` event to be dispatched at the appropriate window.
     /// The strings are key, old value and new value.
     DispatchStorageEvent(PipelineId, StorageType, ServoUrl, Option<String>, Option<String>, Option<String>),
+    /// Cause a `languagechange` event to be dispatched at the appropriate window
+    /// and its workers. The string is the new value of the `intl.accept_languages`
+    /// preference.
+    DispatchLanguagesChange(PipelineId, String),
     /// Report an error from a CSS parser for the given pipeline
     ReportCSSError(PipelineId, String, u32, u32, String),
     /// Reload the given page.
@@ -356,6 +364,7 @@ impl fmt::Debug for ConstellationControlMsg {
             WebFontLoaded(..) => "WebFontLoaded",
             DispatchIFrameLoadEvent { .. } => "DispatchIFrameLoadEvent",
             DispatchStorageEvent(..) => "DispatchStorageEvent",
+            DispatchLanguagesChange(..) => "DispatchLanguagesChange",
             ReportCSSError(..) => "ReportCSSError",
             Reload(..) => "Reload",
             WebVREvents(..) => "WebVREvents",
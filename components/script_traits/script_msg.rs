@@ -75,6 +75,9 @@ pub enum ScriptMsg {
     /// Broadcast a storage event to every same-origin pipeline.
     /// The strings are key, old value and new value.
     BroadcastStorageEvent(StorageType, ServoUrl, Option<String>, Option<String>, Option<String>),
+    /// Broadcast a change of the user's preferred languages to every pipeline.
+    /// The string is the new value of the `intl.accept_languages` preference.
+    BroadcastLanguagesChange(String),
     /// Indicates whether this pipeline is currently running animations.
     ChangeRunningAnimationsState(AnimationState),
     /// Requests that a new 2D canvas thread be created. (This is done in the constellation because
@@ -100,8 +103,9 @@ pub enum ScriptMsg {
     LoadUrl(LoadData, bool),
     /// Abort loading after sending a LoadUrl message.
     AbortLoadUrl,
-    /// Post a message to the currently active window of a given browsing context.
-    PostMessage(BrowsingContextId, Option<ImmutableOrigin>, Vec<u8>),
+    /// Post a message to the currently active window of a given browsing context,
+    /// with the target origin it must match (if any) and the origin of the sender.
+    PostMessage(BrowsingContextId, Option<ImmutableOrigin>, ImmutableOrigin, Vec<u8>),
     /// HTMLIFrameElement Forward or Back traversal.
     TraverseHistory(TraversalDirection),
     /// Inform the constellation of a pushed history state.
@@ -303,6 +303,13 @@ impl Browser {
                     }
                     platform_get_selected_files(patterns, multiple_files, sender);
                 }
+                EmbedderMsg::SelectDirectory(sender) => {
+                    if opts::get().headless {
+                        let _ = sender.send(None);
+                    } else {
+                        platform_get_selected_directory(sender);
+                    }
+                },
                 EmbedderMsg::ShowIME(_browser_id, _kind) => {
                     debug!("ShowIME received");
                 }
@@ -382,6 +389,22 @@ fn platform_get_selected_files(_patterns: Vec<FilterPattern>,
     let _ = sender.send(None);
 }
 
+#[cfg(any(target_os = "macos", target_os = "linux", target_os = "windows"))]
+fn platform_get_selected_directory(sender: IpcSender<Option<String>>) {
+    let picker_name = "Pick a directory";
+
+    thread::Builder::new().name(picker_name.to_owned()).spawn(move || {
+        let directory = tinyfiledialogs::select_folder_dialog(picker_name, "");
+        let _ = sender.send(directory);
+    }).expect("Thread spawning failed");
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+fn platform_get_selected_directory(sender: IpcSender<Option<String>>) {
+    warn!("Directory picker not implemented");
+    let _ = sender.send(None);
+}
+
 fn sanitize_url(request: &str) -> Option<ServoUrl> {
     let request = request.trim();
     ServoUrl::parse(&request).ok()
@@ -0,0 +1,33 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use script::test::frameset::{Dimension, TrackSize};
+use script::test::frameset::{dimensions_to_track_sizes, parse_a_list_of_dimensions};
+
+#[test]
+fn parse_dimensions() {
+    assert_eq!(parse_a_list_of_dimensions("50%,50%"),
+               vec![Dimension::Percentage(50.), Dimension::Percentage(50.)]);
+    assert_eq!(parse_a_list_of_dimensions(" 100 , * , 2* ,"),
+               vec![Dimension::Absolute(100.), Dimension::Relative(1.), Dimension::Relative(2.)]);
+    assert_eq!(parse_a_list_of_dimensions("12.5%,0*"),
+               vec![Dimension::Percentage(12.5), Dimension::Relative(0.)]);
+    assert_eq!(parse_a_list_of_dimensions(""), vec![Dimension::Relative(1.)]);
+}
+
+#[test]
+fn relative_dimensions_share_the_remaining_space() {
+    let sizes = dimensions_to_track_sizes(&[
+        Dimension::Absolute(100.),
+        Dimension::Percentage(20.),
+        Dimension::Relative(1.),
+        Dimension::Relative(3.),
+    ]);
+    assert_eq!(sizes, vec![
+        TrackSize { percentage: 0., pixels: 100. },
+        TrackSize { percentage: 20., pixels: 0. },
+        TrackSize { percentage: 20., pixels: -25. },
+        TrackSize { percentage: 60., pixels: -75. },
+    ]);
+}
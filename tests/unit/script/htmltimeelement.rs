@@ -0,0 +1,65 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use script::test::time::{DateTimeKind, parse_a_datetime_value};
+
+fn parse(value: &str) -> Option<(DateTimeKind, String)> {
+    parse_a_datetime_value(value)
+}
+
+#[test]
+fn valid_dates() {
+    assert_eq!(parse("2011-11"), Some((DateTimeKind::Month, "2011-11".to_owned())));
+    assert_eq!(parse("2011-11-18"), Some((DateTimeKind::Date, "2011-11-18".to_owned())));
+    assert_eq!(parse("--02-29"), Some((DateTimeKind::YearlessDate, "02-29".to_owned())));
+    assert_eq!(parse("11-18"), Some((DateTimeKind::YearlessDate, "11-18".to_owned())));
+    assert_eq!(parse("2011-W47"), Some((DateTimeKind::Week, "2011-W47".to_owned())));
+    assert_eq!(parse("0001"), Some((DateTimeKind::Year, "0001".to_owned())));
+}
+
+#[test]
+fn valid_times() {
+    assert_eq!(parse("14:54"), Some((DateTimeKind::Time, "14:54".to_owned())));
+    assert_eq!(parse("14:54:00"), Some((DateTimeKind::Time, "14:54".to_owned())));
+    assert_eq!(parse("14:54:39.920"), Some((DateTimeKind::Time, "14:54:39.92".to_owned())));
+    assert_eq!(parse("2011-11-18 14:54:39"),
+               Some((DateTimeKind::LocalDateAndTime, "2011-11-18T14:54:39".to_owned())));
+    assert_eq!(parse("-0800"), Some((DateTimeKind::TimeZoneOffset, "-08:00".to_owned())));
+    assert_eq!(parse("+00:00"), Some((DateTimeKind::TimeZoneOffset, "Z".to_owned())));
+}
+
+#[test]
+fn global_dates_are_normalized_to_utc() {
+    assert_eq!(parse("2011-11-18T14:54:39.929Z"),
+               Some((DateTimeKind::GlobalDateAndTime, "2011-11-18T14:54:39.929Z".to_owned())));
+    assert_eq!(parse("2011-11-18T23:30-01:00"),
+               Some((DateTimeKind::GlobalDateAndTime, "2011-11-19T00:30Z".to_owned())));
+    assert_eq!(parse("2011-11-18 14:54+0530"),
+               Some((DateTimeKind::GlobalDateAndTime, "2011-11-18T09:24Z".to_owned())));
+}
+
+#[test]
+fn valid_durations() {
+    assert_eq!(parse("PT4H18M3S"), Some((DateTimeKind::Duration, "PT15483S".to_owned())));
+    assert_eq!(parse("P2D"), Some((DateTimeKind::Duration, "PT172800S".to_owned())));
+    assert_eq!(parse("PT0.5S"), Some((DateTimeKind::Duration, "PT0.5S".to_owned())));
+    assert_eq!(parse("4h 18m 3s"), Some((DateTimeKind::Duration, "PT15483S".to_owned())));
+    assert_eq!(parse(" 1w 1D "), Some((DateTimeKind::Duration, "PT691200S".to_owned())));
+}
+
+#[test]
+fn invalid_values() {
+    for value in &["", "2011-13", "2011-02-30", "--02-30", "24:00", "14:54:39.1234",
+                   "2011-11-18T14:54+24:00", "011", "P", "PT", "P1M", "PT1.5H",
+                   "1h 2h", "5", "next tuesday"] {
+        assert_eq!(parse(value), None, "{:?} should be rejected", value);
+    }
+}
+
+#[test]
+fn non_ascii_time_zone_offsets_are_rejected() {
+    for value in &["+a\u{e9}1", "+12\u{e9}1", "-\u{e9}\u{e9}", "2011-11-18T14:54+a\u{e9}1"] {
+        assert_eq!(parse(value), None, "{:?} should be rejected", value);
+    }
+}
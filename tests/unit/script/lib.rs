@@ -13,7 +13,9 @@
 #[cfg(test)] mod textinput;
 #[cfg(test)] mod headers;
 #[cfg(test)] mod htmlareaelement;
+#[cfg(test)] mod htmlframesetelement;
 #[cfg(test)] mod htmlimageelement;
+#[cfg(test)] mod htmltimeelement;
 
 /**
 ```compile_fail,E0277
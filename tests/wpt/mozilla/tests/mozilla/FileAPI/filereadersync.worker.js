@@ -0,0 +1,59 @@
+importScripts("/resources/testharness.js");
+
+var reader = new FileReaderSync();
+
+test(function() {
+  var blob = new Blob(["hello, ", "servo"]);
+  assert_equals(reader.readAsText(blob), "hello, servo");
+}, "readAsText returns the blob contents");
+
+test(function() {
+  // "é" in windows-1252.
+  var bytes = new Uint8Array([0x63, 0x61, 0x66, 0xE9]);
+  assert_equals(reader.readAsText(new Blob([bytes]), "windows-1252"), "café");
+  assert_equals(reader.readAsText(new Blob([bytes], {type: "text/plain;charset=windows-1252"})), "café",
+                "the charset of the blob type is used without a label");
+  assert_equals(reader.readAsText(new Blob([bytes]), "not-an-encoding"), "caf�",
+                "an unknown label falls back to UTF-8");
+}, "readAsText picks the encoding from the label, then the blob type");
+
+test(function() {
+  var buffer = reader.readAsArrayBuffer(new Blob([new Uint8Array([1, 2, 255])]));
+  assert_true(buffer instanceof ArrayBuffer);
+  assert_array_equals(new Uint8Array(buffer), [1, 2, 255]);
+}, "readAsArrayBuffer returns a copy of the blob bytes");
+
+test(function() {
+  var blob = new Blob([new Uint8Array([0x61, 0x00, 0xE9, 0xFF])]);
+  assert_equals(reader.readAsBinaryString(blob), "a\u0000éÿ");
+}, "readAsBinaryString maps each byte to a code unit");
+
+test(function() {
+  assert_equals(reader.readAsDataURL(new Blob(["test"], {type: "text/plain"})),
+                "data:text/plain;base64,dGVzdA==");
+  assert_equals(reader.readAsDataURL(new Blob(["test"])), "data:base64,dGVzdA==");
+}, "readAsDataURL encodes the blob contents in base64");
+
+test(function() {
+  assert_equals(reader.readAsText(new Blob(["abcdef"]).slice(2, 4)), "cd");
+}, "A sliced blob is read synchronously");
+
+test(function() {
+  assert_throws(new TypeError(), function() {
+    reader.readAsText("not a blob");
+  });
+  assert_throws(new TypeError(), function() {
+    reader.readAsArrayBuffer();
+  });
+}, "Reading something other than a blob throws");
+
+test(function() {
+  var blob = new TestBinding().unreadableBlob();
+  ["readAsArrayBuffer", "readAsBinaryString", "readAsText", "readAsDataURL"].forEach(function(method) {
+    assert_throws("NotReadableError", function() {
+      reader[method](blob);
+    }, method);
+  });
+}, "Reading a blob whose file can't be read throws a NotReadableError");
+
+done();
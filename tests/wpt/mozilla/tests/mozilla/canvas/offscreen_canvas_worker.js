@@ -0,0 +1,13 @@
+onmessage = function(event) {
+  var canvas = event.data.canvas;
+  var ctx = canvas.getContext("2d");
+  ctx.fillStyle = "#00ff00";
+  ctx.fillRect(0, 0, canvas.width, canvas.height);
+  ctx.commit();
+  postMessage({
+    isOffscreenCanvas: canvas instanceof OffscreenCanvas,
+    width: canvas.width,
+    height: canvas.height,
+    sameContext: canvas.getContext("2d") === ctx,
+  });
+};
@@ -0,0 +1,11 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import time
+
+
+def main(request, response):
+    time.sleep(float(request.GET.first('delay', '0')))
+    headers = [('Content-Type', 'text/javascript')]
+    return 200, headers, 'log.push("%s");' % request.GET.first('name')
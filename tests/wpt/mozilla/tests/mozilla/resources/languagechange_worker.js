@@ -0,0 +1,8 @@
+onlanguagechange = function(event) {
+  postMessage({
+    type: event.type,
+    languages: navigator.languages,
+    language: navigator.language,
+  });
+};
+postMessage("ready");